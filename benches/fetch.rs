@@ -0,0 +1,135 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+// Measures the per-row overhead of duplicating fetched rows.
+//
+// Run this by `cargo bench --bench fetch`. The connection parameters are
+// taken from the environment variables used by tests.
+//
+// `Row`s returned by result sets are duplicated from the fetch buffer.
+// Their column values are copied to elements of a variable created per
+// fetch array size rows for each column. The baseline duplicates each column
+// value by `SqlValue::dup`, which creates a variable for each value as rows
+// were duplicated before. Tuple fetches, which convert values in the fetch
+// buffer directly without duplication, are shown as the lower bound.
+
+use oracle::sql_type::Timestamp;
+use oracle::{Connection, FromRowInPlace, Result, Row, SqlValue};
+use std::env;
+use std::time::{Duration, Instant};
+
+const NUM_ROWS: u32 = 100_000;
+const ITERATIONS: usize = 5;
+const SQL: &str = "select level, to_char(level), sysdate from dual connect by level <= :1";
+
+fn env_var_or(env_name: &str, default: &str) -> String {
+    env::var(env_name).unwrap_or_else(|_| default.to_string())
+}
+
+// Returns the shortest time of `ITERATIONS` runs.
+fn measure<F>(mut f: F) -> Result<Duration>
+where
+    F: FnMut() -> Result<u32>,
+{
+    let mut best = Duration::MAX;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let count = f()?;
+        let elapsed = start.elapsed();
+        assert_eq!(count, NUM_ROWS);
+        best = best.min(elapsed);
+    }
+    Ok(best)
+}
+
+// Column values duplicated one by one by SqlValue::dup
+struct DupValues<'a> {
+    conn: &'a Connection,
+    values: Vec<SqlValue>,
+}
+
+impl FromRowInPlace for DupValues<'_> {
+    fn set_from_row(&mut self, row: &Row) -> Result<()> {
+        self.values = row
+            .sql_values()
+            .iter()
+            .map(|val| val.dup(self.conn))
+            .collect::<Result<_>>()?;
+        Ok(())
+    }
+}
+
+fn main() -> Result<()> {
+    let conn = Connection::connect(
+        env_var_or("ODPIC_TEST_MAIN_USER", "odpic"),
+        env_var_or("ODPIC_TEST_MAIN_PASSWORD", "welcome"),
+        env_var_or("ODPIC_TEST_CONNECT_STRING", "localhost/orclpdb"),
+    )?;
+
+    let tuple = measure(|| {
+        let mut count = 0;
+        for row in conn.query_as::<(i64, String, Timestamp)>(SQL, &[&NUM_ROWS])? {
+            row?;
+            count += 1;
+        }
+        Ok(count)
+    })?;
+    let baseline = measure(|| {
+        let mut count = 0;
+        let mut stmt = conn.statement(SQL).build()?;
+        stmt.query(&[&NUM_ROWS])?;
+        let mut dup_values = DupValues {
+            conn: &conn,
+            values: Vec::new(),
+        };
+        while stmt.fetch_into(&mut dup_values)? {
+            let _: i64 = dup_values.values[0].get()?;
+            let _: String = dup_values.values[1].get()?;
+            let _: Timestamp = dup_values.values[2].get()?;
+            count += 1;
+        }
+        Ok(count)
+    })?;
+    let row = measure(|| {
+        let mut count = 0;
+        for row in conn.query(SQL, &[&NUM_ROWS])? {
+            let _: (i64, String, Timestamp) = row?.get_as()?;
+            count += 1;
+        }
+        Ok(count)
+    })?;
+
+    println!("fetch {} rows (best of {} runs)", NUM_ROWS, ITERATIONS);
+    println!(
+        "  tuple:               {:?} ({:?}/row)",
+        tuple,
+        tuple / NUM_ROWS
+    );
+    println!(
+        "  Row (dup per value): {:?} ({:?}/row)",
+        baseline,
+        baseline / NUM_ROWS
+    );
+    println!(
+        "  Row:                 {:?} ({:?}/row)",
+        row,
+        row / NUM_ROWS
+    );
+    println!(
+        "  speedup of Row over the baseline: {:.2}x",
+        baseline.as_secs_f64() / row.as_secs_f64()
+    );
+    Ok(())
+}
@@ -0,0 +1,123 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+use darling::ToTokens;
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    self, parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Fields, Lit, Meta, MetaList,
+    MetaNameValue, NestedMeta,
+};
+
+pub fn derive_into_object(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = parse_macro_input!(input);
+
+    let type_name = parse_attributes(&attrs, "type_name");
+    let fields: Vec<_> = if let Data::Struct(DataStruct {
+        fields: Fields::Named(named),
+        ..
+    }) = data
+    {
+        named
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                let name = Literal::string(
+                    &parse_attributes(&field.attrs, "rename")
+                        .unwrap_or_else(|| ident.to_string().to_uppercase()),
+                );
+                quote! {
+                    obj.set(#name, &self.#ident)?;
+                }
+            })
+            .collect()
+    } else {
+        panic!("Expected a structure with named fields only");
+    };
+
+    let object_type_name: TokenStream2 = match type_name {
+        Some(type_name) => {
+            let type_name = Literal::string(&type_name);
+            quote! {
+                fn object_type_name() -> ::std::option::Option<&'static str> {
+                    ::std::option::Option::Some(#type_name)
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let output = quote! {
+        impl oracle::sql_type::IntoObject for #ident {
+            #object_type_name
+
+            fn set_attributes(&self, obj: &mut oracle::sql_type::Object) -> oracle::Result<()> {
+                #(#fields)*
+                ::std::result::Result::Ok(())
+            }
+        }
+
+        impl oracle::sql_type::ToSqlNull for #ident {
+            fn oratype_for_null(conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+                <Self as oracle::sql_type::IntoObject>::object_oratype(conn)
+            }
+        }
+
+        impl oracle::sql_type::ToSql for #ident {
+            fn oratype(&self, conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+                <Self as oracle::sql_type::IntoObject>::object_oratype(conn)
+            }
+
+            fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+                oracle::sql_type::IntoObject::set_object_to(self, val)
+            }
+        }
+    };
+    output.into()
+}
+
+// Gets the value of `#[into_object(key = "value")]`.
+fn parse_attributes(attrs: &[Attribute], key: &str) -> Option<String> {
+    let mut value = None;
+    for option in attrs.iter() {
+        match option.parse_meta().unwrap() {
+            Meta::List(MetaList { path, nested, .. })
+                if path.to_token_stream().to_string() == "into_object" =>
+            {
+                for meta in nested.into_iter() {
+                    if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        ref path,
+                        lit: Lit::Str(ref lit),
+                        ..
+                    })) = meta
+                    {
+                        match path.to_token_stream().to_string().as_str() {
+                            attr if attr == key => value = Some(lit.value()),
+                            "type_name" | "rename" => {
+                                panic!("Unexpected attribute here: '{}'", path.to_token_stream())
+                            }
+                            attr => panic!("Unexpected attribute: '{}'", attr),
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    value
+}
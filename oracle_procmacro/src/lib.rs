@@ -21,6 +21,7 @@
 
 use proc_macro::TokenStream;
 
+mod derive_into_object;
 mod derive_row_value;
 mod remove_stmt_lifetime;
 
@@ -30,6 +31,12 @@ pub fn derive_row_value(input: TokenStream) -> TokenStream {
     derive_row_value::derive_row_value(input)
 }
 
+#[doc = include_str!("../docs/into_object.md")]
+#[proc_macro_derive(IntoObject, attributes(into_object))]
+pub fn derive_into_object(input: TokenStream) -> TokenStream {
+    derive_into_object::derive_into_object(input)
+}
+
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn remove_stmt_lifetime(_args: TokenStream, input: TokenStream) -> TokenStream {
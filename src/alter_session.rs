@@ -0,0 +1,200 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::Connection;
+use crate::DbError;
+use crate::Error;
+use crate::Result;
+use std::fmt;
+
+/// Optimizer mode set by [`AlterSession::optimizer_mode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptimizerMode {
+    /// `ALL_ROWS`
+    AllRows,
+    /// `FIRST_ROWS`
+    FirstRows,
+    /// `FIRST_ROWS_1`
+    FirstRows1,
+    /// `FIRST_ROWS_10`
+    FirstRows10,
+    /// `FIRST_ROWS_100`
+    FirstRows100,
+    /// `FIRST_ROWS_1000`
+    FirstRows1000,
+}
+
+impl fmt::Display for OptimizerMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OptimizerMode::AllRows => write!(f, "ALL_ROWS"),
+            OptimizerMode::FirstRows => write!(f, "FIRST_ROWS"),
+            OptimizerMode::FirstRows1 => write!(f, "FIRST_ROWS_1"),
+            OptimizerMode::FirstRows10 => write!(f, "FIRST_ROWS_10"),
+            OptimizerMode::FirstRows100 => write!(f, "FIRST_ROWS_100"),
+            OptimizerMode::FirstRows1000 => write!(f, "FIRST_ROWS_1000"),
+        }
+    }
+}
+
+/// A builder to change session parameters by an `ALTER SESSION` statement
+///
+/// This is created by [`Connection::alter_session`]. Parameters set by
+/// the builder are combined into one `ALTER SESSION SET ...` statement,
+/// which is executed by [`execute`](#method.execute).
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # use oracle::OptimizerMode;
+/// # let conn = test_util::connect()?;
+/// conn.alter_session()
+///     .nls_date_format("YYYY-MM-DD")
+///     .time_zone("+00:00")
+///     .optimizer_mode(OptimizerMode::FirstRows10)
+///     .execute()?;
+///
+/// let date = conn.query_row_as::<String>("select to_char(date '2012-03-04') from dual", &[])?;
+/// assert_eq!(date, "2012-03-04");
+/// # Ok::<(), Error>(())
+/// ```
+pub struct AlterSession<'conn> {
+    conn: &'conn Connection,
+    clauses: Vec<String>,
+}
+
+impl<'conn> AlterSession<'conn> {
+    pub(crate) fn new(conn: &'conn Connection) -> AlterSession<'conn> {
+        AlterSession {
+            conn,
+            clauses: Vec::new(),
+        }
+    }
+
+    fn set_literal(&mut self, name: &str, value: &str) -> &mut AlterSession<'conn> {
+        self.clauses
+            .push(format!("{} = '{}'", name, value.replace('\'', "''")));
+        self
+    }
+
+    /// Sets `NLS_DATE_FORMAT`.
+    pub fn nls_date_format(&mut self, format: &str) -> &mut AlterSession<'conn> {
+        self.set_literal("NLS_DATE_FORMAT", format)
+    }
+
+    /// Sets `NLS_TIMESTAMP_FORMAT`.
+    pub fn nls_timestamp_format(&mut self, format: &str) -> &mut AlterSession<'conn> {
+        self.set_literal("NLS_TIMESTAMP_FORMAT", format)
+    }
+
+    /// Sets `NLS_TIMESTAMP_TZ_FORMAT`.
+    pub fn nls_timestamp_tz_format(&mut self, format: &str) -> &mut AlterSession<'conn> {
+        self.set_literal("NLS_TIMESTAMP_TZ_FORMAT", format)
+    }
+
+    /// Sets `NLS_NUMERIC_CHARACTERS`, such as `".,"`.
+    pub fn nls_numeric_characters(&mut self, chars: &str) -> &mut AlterSession<'conn> {
+        self.set_literal("NLS_NUMERIC_CHARACTERS", chars)
+    }
+
+    /// Sets `TIME_ZONE`, such as `"+09:00"` and `"Asia/Tokyo"`.
+    pub fn time_zone(&mut self, time_zone: &str) -> &mut AlterSession<'conn> {
+        self.set_literal("TIME_ZONE", time_zone)
+    }
+
+    /// Sets `OPTIMIZER_MODE`.
+    pub fn optimizer_mode(&mut self, mode: OptimizerMode) -> &mut AlterSession<'conn> {
+        self.clauses.push(format!("OPTIMIZER_MODE = {}", mode));
+        self
+    }
+
+    /// Returns the `ALTER SESSION` statement to be executed.
+    ///
+    /// An empty string is returned when no parameters are set.
+    pub fn sql(&self) -> String {
+        if self.clauses.is_empty() {
+            String::new()
+        } else {
+            format!("ALTER SESSION SET {}", self.clauses.join(" "))
+        }
+    }
+
+    /// Executes the `ALTER SESSION` statement.
+    ///
+    /// Nothing is executed when no parameters are set.
+    ///
+    /// When the statement fails, clauses are executed one by one to find the
+    /// offending one, which is appended to the error message. Note that
+    /// clauses before it may have been applied in that case.
+    pub fn execute(&self) -> Result<()> {
+        if self.clauses.is_empty() {
+            return Ok(());
+        }
+        let err = match self.conn.execute(&self.sql(), &[]) {
+            Ok(_) => return Ok(()),
+            Err(err) => err,
+        };
+        if self.clauses.len() == 1 {
+            return Err(annotate_clause_error(err, &self.clauses[0]));
+        }
+        for clause in &self.clauses {
+            if let Err(err) = self
+                .conn
+                .execute(&format!("ALTER SESSION SET {}", clause), &[])
+            {
+                return Err(annotate_clause_error(err, clause));
+            }
+        }
+        Err(err)
+    }
+}
+
+impl fmt::Debug for AlterSession<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AlterSession")
+            .field("sql", &self.sql())
+            .finish()
+    }
+}
+
+fn annotate_clause_error(err: Error, clause: &str) -> Error {
+    let annotate_db_error = |err: DbError| {
+        DbError::new(
+            err.code(),
+            err.offset(),
+            format!("{} (in clause: {})", err.message(), clause),
+            err.fn_name().to_string(),
+            err.action().to_string(),
+        )
+    };
+    match err {
+        Error::OciError(err) => Error::OciError(annotate_db_error(err)),
+        Error::DpiError(err) => Error::DpiError(annotate_db_error(err)),
+        err => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optimizer_mode_display() {
+        assert_eq!(OptimizerMode::AllRows.to_string(), "ALL_ROWS");
+        assert_eq!(OptimizerMode::FirstRows100.to_string(), "FIRST_ROWS_100");
+    }
+}
@@ -67,6 +67,10 @@
 //! # Ok::<(), Error>(())
 //! ```
 //!
+//! ## JSON queue
+//!
+//! See [the example](Payload#impl-Payload-for-Value) of `Payload` for `serde_json::Value`.
+//!
 //! # Enqueue and dequeue more than one message in one call
 //!
 //! ```
@@ -108,10 +112,16 @@ use crate::sql_type::Object;
 use crate::sql_type::ObjectType;
 use crate::sql_type::OracleType;
 use crate::sql_type::Timestamp;
+#[cfg(feature = "serde_json")]
+use crate::sql_type::{base64_encode, number_to_json, IntervalDS, IntervalYM};
 use crate::to_odpi_str;
 use crate::to_rust_slice;
+#[cfg(feature = "serde_json")]
+use crate::to_rust_str;
 use crate::Connection;
 use crate::Context;
+#[cfg(feature = "serde_json")]
+use crate::DpiJson;
 use crate::DpiMsgProps;
 use crate::DpiQueue;
 use crate::Error;
@@ -131,6 +141,9 @@ pub trait Payload: ToOwned {
     fn payload_type(payload_type: &Self::TypeInfo) -> Result<Option<ObjectType>>;
     fn get(props: &MsgProps<Self>) -> Result<Self::Owned>;
     fn set(&self, props: &mut MsgProps<Self>) -> Result<()>;
+    fn is_json() -> bool {
+        false
+    }
 }
 
 impl Payload for [u8] {
@@ -196,6 +209,169 @@ impl Payload for Object {
     }
 }
 
+/// JSON payload, which is available when `serde_json` feature is enabled.
+///
+/// Numbers are converted to JSON numbers when they are converted without loss
+/// of precision. Otherwise, they are converted to strings. Scalar values
+/// which JSON doesn't have such as timestamps are converted to strings.
+///
+/// JSON payload queues are available since Oracle 21c.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # use oracle::aq;
+/// # use serde_json::json;
+/// # let conn = test_util::connect()?;
+/// # if !test_util::check_version(&conn, &test_util::VER21, &test_util::VER21)? {
+/// #     return Ok(());
+/// # }
+///
+/// // Create a queue
+/// let mut queue = aq::Queue::<serde_json::Value>::new(&conn, "JSON_QUEUE", &())?;
+///
+/// // Create a message
+/// let payload = json!({"order_id": 1001, "items": ["apple", "orange"], "paid": true});
+/// let mut msg = aq::MsgProps::<serde_json::Value>::new(&conn)?;
+/// msg.set_payload(&payload)?;
+///
+/// // Enqueue the message to the queue
+/// queue.enqueue(&msg)?;
+///
+/// // Dequeue a message from the queue
+/// let new_msg = queue.dequeue()?;
+/// let new_payload = new_msg.payload()?; // returns serde_json::Value
+///
+/// // Compare message payloads.
+/// assert_eq!(payload, new_payload);
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "serde_json")]
+impl Payload for serde_json::Value {
+    type TypeInfo = ();
+
+    fn payload_type(_payload_type: &Self::TypeInfo) -> Result<Option<ObjectType>> {
+        Ok(None)
+    }
+
+    fn get(props: &MsgProps<Self>) -> Result<serde_json::Value> {
+        let mut json = ptr::null_mut();
+        chkerr!(
+            props.ctxt(),
+            dpiMsgProps_getPayloadJson(props.handle.raw, &mut json)
+        );
+        if json.is_null() {
+            return Err(Error::NoDataFound);
+        }
+        let mut node = ptr::null_mut();
+        chkerr!(
+            props.ctxt(),
+            dpiJson_getValue(json, DPI_JSON_OPT_NUMBER_AS_STRING, &mut node)
+        );
+        unsafe { json_node_to_value(&*node) }
+    }
+
+    fn set(&self, props: &mut MsgProps<Self>) -> Result<()> {
+        let mut json = ptr::null_mut();
+        chkerr!(
+            props.ctxt(),
+            dpiConn_newJson(props.conn.handle.raw(), &mut json)
+        );
+        let json = DpiJson::new(json);
+        let text = self.to_string();
+        chkerr!(
+            props.ctxt(),
+            dpiJson_setFromText(
+                json.raw(),
+                text.as_ptr() as *const c_char,
+                text.len() as u64,
+                0
+            )
+        );
+        chkerr!(
+            props.ctxt(),
+            dpiMsgProps_setPayloadJson(props.handle.raw, json.raw())
+        );
+        props.payload_type = None;
+        Ok(())
+    }
+
+    fn is_json() -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "serde_json")]
+unsafe fn json_node_to_value(node: &dpiJsonNode) -> Result<serde_json::Value> {
+    use serde_json::Value;
+    let value = &*node.value;
+    Ok(match node.nativeTypeNum {
+        DPI_NATIVE_TYPE_NULL => Value::Null,
+        DPI_NATIVE_TYPE_BOOLEAN => Value::Bool(value.asBoolean != 0),
+        DPI_NATIVE_TYPE_DOUBLE | DPI_NATIVE_TYPE_FLOAT => {
+            let n = if node.nativeTypeNum == DPI_NATIVE_TYPE_DOUBLE {
+                value.asDouble
+            } else {
+                value.asFloat as f64
+            };
+            serde_json::Number::from_f64(n)
+                .map(Value::Number)
+                .unwrap_or_else(|| Value::String(n.to_string()))
+        }
+        DPI_NATIVE_TYPE_BYTES => {
+            let bytes = &value.asBytes;
+            match node.oracleTypeNum {
+                DPI_ORACLE_TYPE_NUMBER => number_to_json(&to_rust_str(bytes.ptr, bytes.length)),
+                DPI_ORACLE_TYPE_RAW => {
+                    Value::String(base64_encode(to_rust_slice(bytes.ptr, bytes.length)))
+                }
+                _ => Value::String(to_rust_str(bytes.ptr, bytes.length)),
+            }
+        }
+        DPI_NATIVE_TYPE_TIMESTAMP => {
+            let oratype = if node.oracleTypeNum == DPI_ORACLE_TYPE_DATE {
+                OracleType::Date
+            } else {
+                OracleType::Timestamp(9)
+            };
+            Value::String(Timestamp::from_dpi_timestamp(&value.asTimestamp, &oratype).to_string())
+        }
+        DPI_NATIVE_TYPE_INTERVAL_DS => Value::String(
+            IntervalDS::from_dpi_interval_ds(&value.asIntervalDS, &OracleType::IntervalDS(9, 9))
+                .to_string(),
+        ),
+        DPI_NATIVE_TYPE_INTERVAL_YM => Value::String(
+            IntervalYM::from_dpi_interval_ym(&value.asIntervalYM, &OracleType::IntervalYM(9))
+                .to_string(),
+        ),
+        DPI_NATIVE_TYPE_JSON_OBJECT => {
+            let obj = &value.asJsonObject;
+            let mut map = serde_json::Map::new();
+            for i in 0..obj.numFields as usize {
+                let name = to_rust_str(*obj.fieldNames.add(i), *obj.fieldNameLengths.add(i));
+                map.insert(name, json_node_to_value(&*obj.fields.add(i))?);
+            }
+            Value::Object(map)
+        }
+        DPI_NATIVE_TYPE_JSON_ARRAY => {
+            let ary = &value.asJsonArray;
+            let mut vec = Vec::with_capacity(ary.numElements as usize);
+            for i in 0..ary.numElements as usize {
+                vec.push(json_node_to_value(&*ary.elements.add(i))?);
+            }
+            Value::Array(vec)
+        }
+        _ => {
+            return Err(Error::InternalError(format!(
+                "Unknown JSON node type: {}",
+                node.nativeTypeNum
+            )))
+        }
+    })
+}
+
 /// Advanced Queueing (AQ) queue which may be used to enqueue and dequeue messages
 ///
 /// **Warning:** The type is unstable. It may be changed incompatibly by minor version upgrades.
@@ -237,10 +413,17 @@ where
             .as_ref()
             .map(|t| t.handle().raw)
             .unwrap_or(ptr::null_mut());
-        chkerr!(
-            conn.ctxt(),
-            dpiConn_newQueue(conn.handle(), name.ptr, name.len, objtype, &mut handle)
-        );
+        if T::is_json() {
+            chkerr!(
+                conn.ctxt(),
+                dpiConn_newJsonQueue(conn.handle(), name.ptr, name.len, &mut handle)
+            );
+        } else {
+            chkerr!(
+                conn.ctxt(),
+                dpiConn_newQueue(conn.handle(), name.ptr, name.len, objtype, &mut handle)
+            );
+        }
         Ok(Queue {
             conn: conn.conn.clone(),
             handle: DpiQueue::new(handle),
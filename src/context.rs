@@ -16,13 +16,146 @@
 use crate::binding::*;
 use crate::error;
 use crate::DbError;
+use crate::Error;
 use crate::Result;
 use lazy_static::lazy_static;
-use std::mem::MaybeUninit;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::mem::{self, MaybeUninit};
 use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
+//
+// InitParams
+//
+
+/// Parameters to initialize the Oracle client library explicitly by [`init`]
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::{Error, InitParams};
+/// oracle::init(
+///     InitParams::new()
+///         .oracle_client_lib_dir("/opt/oracle/instantclient_21_9")
+///         .config_dir("/opt/oracle/network/admin")
+///         .driver_name("my-app : 1.0.0"),
+/// )?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InitParams {
+    oracle_client_lib_dir: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
+    driver_name: Option<String>,
+}
+
+impl InitParams {
+    /// Creates a new parameter set with default values.
+    pub fn new() -> InitParams {
+        InitParams::default()
+    }
+
+    /// Sets the directory from which the Oracle client library is loaded.
+    ///
+    /// When it isn't set, the library is searched in the platform-specific
+    /// way such as `LD_LIBRARY_PATH` on Linux and `PATH` on Windows.
+    pub fn oracle_client_lib_dir<P>(&mut self, dir: P) -> &mut InitParams
+    where
+        P: AsRef<Path>,
+    {
+        self.oracle_client_lib_dir = Some(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the directory containing Oracle configuration files such as
+    /// `tnsnames.ora` and `sqlnet.ora`.
+    ///
+    /// When it isn't set, the `TNS_ADMIN` environment variable and the
+    /// default locations of the Oracle client library are used.
+    pub fn config_dir<P>(&mut self, dir: P) -> &mut InitParams
+    where
+        P: AsRef<Path>,
+    {
+        self.config_dir = Some(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the default driver name of connections, which is displayed in
+    /// `CLIENT_DRIVER` column of `V$SESSION_CONNECT_INFO`.
+    ///
+    /// The default value is `rust-oracle : <version>`.
+    /// [`Connector::driver_name`](crate::Connector::driver_name) overrides it
+    /// per connection.
+    pub fn driver_name<S>(&mut self, name: S) -> &mut InitParams
+    where
+        S: Into<String>,
+    {
+        self.driver_name = Some(name.into());
+        self
+    }
+}
+
+/// Initializes the Oracle client library explicitly with the specified parameters.
+///
+/// The library is initialized implicitly with the default parameters on
+/// first use, such as establishing a connection. This must be called
+/// before that. Otherwise [`Error::InvalidOperation`] is returned.
+/// It is also returned when this is called twice.
+///
+/// An error is returned when the Oracle client library cannot be loaded.
+///
+/// See [`InitParams`] for an example.
+pub fn init(params: &InitParams) -> Result<()> {
+    let c_params = CInitParams {
+        oracle_client_lib_dir: params
+            .oracle_client_lib_dir
+            .as_ref()
+            .map(|dir| to_cstring(&dir.to_string_lossy()))
+            .transpose()?,
+        config_dir: params
+            .config_dir
+            .as_ref()
+            .map(|dir| to_cstring(&dir.to_string_lossy()))
+            .transpose()?,
+        driver_name: params
+            .driver_name
+            .as_ref()
+            .map(|name| to_cstring(name))
+            .transpose()?,
+    };
+    {
+        let mut init_params = INIT_PARAMS.lock()?;
+        if CONTEXT_INITIALIZED.load(Ordering::SeqCst) {
+            return Err(Error::InvalidOperation(
+                "The Oracle client library has already been initialized".into(),
+            ));
+        }
+        *init_params = Some(c_params);
+    }
+    Context::new0().map(|_| ())
+}
+
+fn to_cstring(s: &str) -> Result<CString> {
+    CString::new(s)
+        .map_err(|_| Error::InvalidOperation(format!("{:?} contains a null character", s)))
+}
+
+// InitParams converted to null-terminated strings passed to ODPI-C.
+// They must not be freed after the context is created.
+struct CInitParams {
+    oracle_client_lib_dir: Option<CString>,
+    config_dir: Option<CString>,
+    driver_name: Option<CString>,
+}
+
+fn cstring_ptr(s: &Option<CString>) -> *const c_char {
+    s.as_ref().map_or(ptr::null(), |s| s.as_ptr())
+}
+
 //
 // Context
 //
@@ -30,7 +163,19 @@ use std::sync::{Arc, Mutex};
 #[derive(Clone)]
 pub(crate) struct Context {
     pub context: *mut dpiContext,
-    last_warning: Option<Arc<Mutex<Option<DbError>>>>,
+    last_warning: Option<Arc<Mutex<Warnings>>>,
+}
+
+// the maximum number of warnings kept until `take_warnings` is called
+const MAX_PENDING_WARNINGS: usize = 100;
+
+#[derive(Default)]
+struct Warnings {
+    // the warning of the last call, cleared by a call without warning
+    last: Option<DbError>,
+    // warnings accumulated until `take_warnings` is called.
+    // Older ones are discarded when it exceeds MAX_PENDING_WARNINGS.
+    pending: VecDeque<DbError>,
 }
 
 unsafe impl Sync for Context {}
@@ -44,15 +189,37 @@ enum ContextResult {
 unsafe impl Sync for ContextResult {}
 unsafe impl Send for ContextResult {}
 
+// true after the context is created
+static CONTEXT_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+// true when the default driver name is set by `init`
+static HAS_DEFAULT_DRIVER_NAME: AtomicBool = AtomicBool::new(false);
+
 lazy_static! {
+    static ref INIT_PARAMS: Mutex<Option<CInitParams>> = Mutex::new(None);
     static ref DPI_CONTEXT: ContextResult = {
+        let init_params = INIT_PARAMS.lock().unwrap_or_else(|err| err.into_inner());
+        CONTEXT_INITIALIZED.store(true, Ordering::SeqCst);
+        let mut params = dpiContextCreateParams {
+            defaultDriverName: ptr::null(),
+            defaultEncoding: ptr::null(),
+            loadErrorUrl: ptr::null(),
+            oracleClientLibDir: ptr::null(),
+            oracleClientConfigDir: ptr::null(),
+        };
+        if let Some(ref init_params) = *init_params {
+            params.oracleClientLibDir = cstring_ptr(&init_params.oracle_client_lib_dir);
+            params.oracleClientConfigDir = cstring_ptr(&init_params.config_dir);
+            params.defaultDriverName = cstring_ptr(&init_params.driver_name);
+            HAS_DEFAULT_DRIVER_NAME.store(init_params.driver_name.is_some(), Ordering::SeqCst);
+        }
         let mut ctxt = ptr::null_mut();
         let mut err = MaybeUninit::uninit();
         if unsafe {
             dpiContext_createWithParams(
                 DPI_MAJOR_VERSION,
                 DPI_MINOR_VERSION,
-                ptr::null_mut(),
+                &mut params,
                 &mut ctxt,
                 err.as_mut_ptr(),
             )
@@ -79,7 +246,7 @@ impl Context {
     pub fn new() -> Result<Context> {
         let ctxt = Context::new0()?;
         Ok(Context {
-            last_warning: Some(Arc::new(Mutex::new(None))),
+            last_warning: Some(Arc::new(Mutex::new(Warnings::default()))),
             ..ctxt
         })
     }
@@ -87,12 +254,27 @@ impl Context {
     pub fn last_warning(&self) -> Option<DbError> {
         self.last_warning
             .as_ref()
-            .and_then(|mutex| mutex.lock().unwrap().as_ref().cloned())
+            .and_then(|mutex| mutex.lock().unwrap().last.clone())
+    }
+
+    pub fn take_warnings(&self) -> Vec<DbError> {
+        self.last_warning
+            .as_ref()
+            .map(|mutex| mem::take(&mut mutex.lock().unwrap().pending).into())
+            .unwrap_or_default()
     }
 
     pub fn set_warning(&self) {
         if let Some(ref mutex) = self.last_warning {
-            *mutex.lock().unwrap() = error::warning(self);
+            let mut warnings = mutex.lock().unwrap();
+            warnings.last = error::warning(self);
+            if let Some(ref warning) = warnings.last {
+                let warning = warning.clone();
+                if warnings.pending.len() >= MAX_PENDING_WARNINGS {
+                    warnings.pending.pop_front();
+                }
+                warnings.pending.push_back(warning);
+            }
         }
     }
 
@@ -101,10 +283,14 @@ impl Context {
         unsafe {
             dpiContext_initCommonCreateParams(self.context, params.as_mut_ptr());
             let mut params = params.assume_init();
-            let driver_name: &'static str = concat!("rust-oracle : ", env!("CARGO_PKG_VERSION"));
             params.createMode |= DPI_MODE_CREATE_THREADED;
-            params.driverName = driver_name.as_ptr() as *const c_char;
-            params.driverNameLength = driver_name.len() as u32;
+            // The default driver name set by `init` is set to params by ODPI-C.
+            if !HAS_DEFAULT_DRIVER_NAME.load(Ordering::Relaxed) {
+                let driver_name: &'static str =
+                    concat!("rust-oracle : ", env!("CARGO_PKG_VERSION"));
+                params.driverName = driver_name.as_ptr() as *const c_char;
+                params.driverNameLength = driver_name.len() as u32;
+            }
             params
         }
     }
@@ -124,4 +310,12 @@ impl Context {
             params.assume_init()
         }
     }
+
+    pub fn subscr_create_params(&self) -> dpiSubscrCreateParams {
+        let mut params = MaybeUninit::uninit();
+        unsafe {
+            dpiContext_initSubscrCreateParams(self.context, params.as_mut_ptr());
+            params.assume_init()
+        }
+    }
 }
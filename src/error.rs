@@ -88,28 +88,136 @@ pub enum Error {
 impl AssertSend for Error {}
 impl AssertSync for Error {}
 
+impl Error {
+    /// Returns `true` when the error means that no connection was available
+    /// in a connection pool within the time allowed by the pool's get mode.
+    ///
+    /// This checks the following Oracle error codes.
+    ///
+    /// * ORA-24418: Cannot open further sessions. ([`GetMode::NoWait`])
+    /// * ORA-24457: OCISessionGet() could not find a free session in the specified timeout period. ([`GetMode::TimedWait`])
+    /// * ORA-24496: OCISessionGet() timed out waiting for a free connection.
+    ///
+    /// See also [`PoolBuilder::acquire_timeout`].
+    ///
+    /// [`GetMode::NoWait`]: crate::pool::GetMode::NoWait
+    /// [`GetMode::TimedWait`]: crate::pool::GetMode::TimedWait
+    /// [`PoolBuilder::acquire_timeout`]: crate::pool::PoolBuilder::acquire_timeout
+    pub fn is_pool_timeout(&self) -> bool {
+        matches!(self, Error::OciError(err) if matches!(err.code(), 24418 | 24457 | 24496))
+    }
+
+    /// Returns `true` when the error means that the connection is no longer usable
+    /// because it was disconnected or closed.
+    ///
+    /// This checks the following errors.
+    ///
+    /// * ORA-00028: your session has been killed
+    /// * ORA-01012: not logged on
+    /// * ORA-03113: end-of-file on communication channel
+    /// * ORA-03114: not connected to ORACLE
+    /// * ORA-03135: connection lost contact
+    /// * DPI-1010: not connected
+    /// * DPI-1080: connection was closed by ORA-%d
+    ///
+    /// See also [`ResilientConnection`](crate::ResilientConnection).
+    pub fn is_disconnected(&self) -> bool {
+        match self {
+            Error::OciError(err) => matches!(err.code(), 28 | 1012 | 3113 | 3114 | 3135),
+            Error::DpiError(err) => {
+                err.message().starts_with("DPI-1010:") || err.message().starts_with("DPI-1080:")
+            }
+            _ => false,
+        }
+    }
+}
+
 /// An error when parsing a string into an Oracle type fails.
 /// This appears only in boxed data associated with [`Error::ParseError`].
+///
+/// When the error is created by [`ParseOracleTypeError::with_position`],
+/// it also holds the byte offset where parsing failed and a description
+/// of what was expected there.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::sql_type::IntervalDS;
+/// let err = "1 02-03:04".parse::<IntervalDS>().unwrap_err();
+/// assert_eq!(err.position(), Some(4));
+/// assert_eq!(err.expected(), Some("':'"));
+/// assert_eq!(err.to_string(), "IntervalDS parse error: expected ':' at position 4");
+/// ```
 #[derive(Eq, PartialEq, Clone)]
 pub struct ParseOracleTypeError {
     typename: &'static str,
+    position: Option<usize>,
+    expected: Option<&'static str>,
 }
 
 impl ParseOracleTypeError {
     pub fn new(typename: &'static str) -> ParseOracleTypeError {
-        ParseOracleTypeError { typename }
+        ParseOracleTypeError {
+            typename,
+            position: None,
+            expected: None,
+        }
+    }
+
+    /// Creates an error with the byte offset where parsing failed and
+    /// a description of the expected token.
+    pub fn with_position(
+        typename: &'static str,
+        position: usize,
+        expected: &'static str,
+    ) -> ParseOracleTypeError {
+        ParseOracleTypeError {
+            typename,
+            position: Some(position),
+            expected: Some(expected),
+        }
+    }
+
+    /// Returns the name of the type failed to parse.
+    pub fn typename(&self) -> &'static str {
+        self.typename
+    }
+
+    /// Returns the byte offset in the input where parsing failed if available.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns a description of the expected token if available.
+    pub fn expected(&self) -> Option<&'static str> {
+        self.expected
     }
 }
 
 impl fmt::Display for ParseOracleTypeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} parse error", self.typename)
+        write!(f, "{} parse error", self.typename)?;
+        match (self.expected, self.position) {
+            (Some(expected), Some(position)) => {
+                write!(f, ": expected {} at position {}", expected, position)
+            }
+            (None, Some(position)) => write!(f, " at position {}", position),
+            _ => Ok(()),
+        }
     }
 }
 
 impl fmt::Debug for ParseOracleTypeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ParseOracleTypeError")
+        write!(f, "ParseOracleTypeError")?;
+        if let Some(position) = self.position {
+            write!(f, " {{ position: {}", position)?;
+            if let Some(expected) = self.expected {
+                write!(f, ", expected: {}", expected)?;
+            }
+            write!(f, " }}")?;
+        }
+        Ok(())
     }
 }
 
@@ -20,6 +20,7 @@ use std::ptr;
 use std::result;
 use std::slice;
 
+mod alter_session;
 #[cfg(feature = "aq_unstable")]
 pub mod aq;
 mod batch;
@@ -37,26 +38,41 @@ pub mod oci_attr;
 pub mod pool;
 #[cfg(doctest)]
 mod procmacro;
+mod resilient;
 mod row;
+pub mod soda;
 pub mod sql_type;
 mod sql_value;
 mod statement;
+mod subscription;
+mod transaction;
 mod util;
 mod version;
 
+pub use crate::alter_session::AlterSession;
+pub use crate::alter_session::OptimizerMode;
 pub use crate::batch::Batch;
 pub use crate::batch::BatchBindIndex;
 pub use crate::batch::BatchBuilder;
+pub use crate::connection::ColumnDef;
 pub use crate::connection::ConnStatus;
 pub use crate::connection::Connection;
 pub use crate::connection::Connector;
+pub use crate::connection::DatabaseInfo;
+pub use crate::connection::Feature;
+pub use crate::connection::PlanRow;
 pub use crate::connection::Privilege;
+pub use crate::connection::SessionInfo;
 pub use crate::connection::ShutdownMode;
 pub use crate::connection::StartupMode;
+pub use crate::context::init;
 use crate::context::Context;
+pub use crate::context::InitParams;
 pub use crate::error::DbError;
 pub use crate::error::Error;
 pub use crate::error::ParseOracleTypeError;
+pub use crate::resilient::ResilientConnection;
+pub use crate::row::FromRowInPlace;
 pub use crate::row::ResultSet;
 pub use crate::row::Row;
 pub use crate::row::RowValue;
@@ -64,10 +80,15 @@ pub use crate::sql_value::SqlValue;
 pub use crate::statement::BindIndex;
 pub use crate::statement::ColumnIndex;
 pub use crate::statement::ColumnInfo;
+pub use crate::statement::ExecuteOptions;
 pub use crate::statement::Statement;
 pub use crate::statement::StatementBuilder;
 pub use crate::statement::StatementType;
 pub use crate::statement::StmtParam;
+pub use crate::subscription::SubscriptionEvent;
+pub use crate::subscription::SubscriptionEventType;
+pub use crate::subscription::SubscriptionId;
+pub use crate::transaction::Transaction;
 pub use crate::version::Version;
 pub use oracle_procmacro::RowValue;
 
@@ -120,6 +141,10 @@ macro_rules! define_dpi_data_with_refcount {
 // define DpiConn wrapping *mut dpiConn.
 define_dpi_data_with_refcount!(Conn);
 
+// define DpiJson wrapping *mut dpiJson.
+#[cfg(all(feature = "aq_unstable", feature = "serde_json"))]
+define_dpi_data_with_refcount!(Json);
+
 // define DpiMsgProps wrapping *mut dpiMsgProps.
 define_dpi_data_with_refcount!(MsgProps);
 
@@ -135,6 +160,21 @@ define_dpi_data_with_refcount!(ObjectAttr);
 // define DpiQueue wrapping *mut dpiQueue.
 define_dpi_data_with_refcount!(Queue);
 
+// define DpiStmt wrapping *mut dpiStmt.
+define_dpi_data_with_refcount!(Stmt);
+
+// define DpiSodaColl wrapping *mut dpiSodaColl.
+define_dpi_data_with_refcount!(SodaColl);
+
+// define DpiSodaDb wrapping *mut dpiSodaDb.
+define_dpi_data_with_refcount!(SodaDb);
+
+// define DpiSodaDoc wrapping *mut dpiSodaDoc.
+define_dpi_data_with_refcount!(SodaDoc);
+
+// define DpiSodaDocCursor wrapping *mut dpiSodaDocCursor.
+define_dpi_data_with_refcount!(SodaDocCursor);
+
 trait AssertSend: Send {}
 trait AssertSync: Sync {}
 
@@ -208,11 +248,14 @@ mod private {
 
     pub trait Sealed {}
 
+    impl Sealed for i8 {}
     impl Sealed for u8 {}
     impl Sealed for u16 {}
     impl Sealed for u32 {}
     impl Sealed for u64 {}
     impl Sealed for usize {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
     impl Sealed for bool {}
     impl Sealed for str {}
     impl Sealed for [u8] {}
@@ -230,6 +273,7 @@ pub mod test_util {
     pub const VER11_2: Version = Version::new(11, 2, 0, 0, 0);
     pub const VER12_1: Version = Version::new(12, 1, 0, 0, 0);
     pub const VER18: Version = Version::new(18, 0, 0, 0, 0);
+    pub const VER21: Version = Version::new(21, 0, 0, 0, 0);
 
     fn env_var_or(env_name: &str, default: &str) -> String {
         match env::var_os(env_name) {
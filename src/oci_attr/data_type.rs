@@ -16,10 +16,10 @@
 //! The module defines types related to the associate type [`OciAttr::DataType`].
 use crate::binding::*;
 use crate::chkerr;
+use crate::connection::InnerConn;
 #[cfg(doc)]
 use crate::oci_attr::OciAttr;
 use crate::to_rust_str;
-use crate::Connection;
 use crate::Context;
 use crate::Error;
 use crate::Result;
@@ -44,10 +44,10 @@ pub struct AttrValue {
 }
 
 impl AttrValue {
-    pub(crate) fn from_conn(conn: &Connection, handle_type: u32, attr_num: u32) -> AttrValue {
+    pub(crate) fn from_conn(conn: &InnerConn, handle_type: u32, attr_num: u32) -> AttrValue {
         AttrValue {
             ctxt: conn.ctxt().clone(),
-            handle: Handle::Conn(conn.handle(), handle_type),
+            handle: Handle::Conn(conn.handle.raw(), handle_type),
             attr_num,
         }
     }
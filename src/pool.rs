@@ -20,8 +20,10 @@
 //! [Session Pooling in OCI]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-F9662FFB-EAEF-495C-96FC-49C6D1D9625C
 use crate::binding::*;
 use crate::chkerr;
+use crate::conn;
 use crate::conn::Purity;
 use crate::connection::CommonCreateParamsBuilder;
+use crate::connection::SessionInitCallback;
 use crate::to_odpi_str;
 use crate::AssertSend;
 use crate::AssertSync;
@@ -31,10 +33,14 @@ use crate::DpiPool;
 use crate::Error;
 use crate::Privilege;
 use crate::Result;
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt;
 use std::ptr;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::sync::{Mutex, RwLock, TryLockError};
+use std::time::{Duration, Instant};
 
 /// The mode to use when closing pools
 ///
@@ -129,6 +135,8 @@ pub enum PoolType {
     Heterogeneous,
 }
 
+const DEFAULT_VALIDATION_QUERY: &str = "SELECT 1 FROM dual";
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 struct I32Seconds(i32);
 
@@ -294,8 +302,12 @@ pub struct PoolBuilder {
     get_mode: Option<GetMode>,
     timeout: Option<U32Seconds>,
     max_lifetime_connection: Option<U32Seconds>,
+    max_lifetime: Option<Duration>,
     plsql_fixup_callback: Option<String>,
     max_connections_per_shard: Option<u32>,
+    validate_on_get: bool,
+    validation_query: Option<String>,
+    session_init: Option<SessionInitCallback>,
     common_params: CommonCreateParamsBuilder,
 }
 
@@ -321,8 +333,12 @@ impl PoolBuilder {
             get_mode: None,
             timeout: None,
             max_lifetime_connection: None,
+            max_lifetime: None,
             plsql_fixup_callback: None,
             max_connections_per_shard: None,
+            validate_on_get: false,
+            validation_query: None,
+            session_init: None,
             common_params: Default::default(),
         }
     }
@@ -407,6 +423,43 @@ impl PoolBuilder {
         self
     }
 
+    /// Specifies the maximum length of time to wait for a free connection
+    /// when all connections in the pool are busy.
+    ///
+    /// This is same with `get_mode(GetMode::TimedWait(dur))`. When no connection
+    /// becomes free within `dur`, [`Pool::get`] returns an error whose
+    /// [`Error::is_pool_timeout`] is `true`, instead of blocking forever as
+    /// [`GetMode::Wait`] does.
+    ///
+    /// See also [`Pool::get_nowait`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # use std::time::Duration;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .max_connections(1)
+    ///     .acquire_timeout(Duration::from_millis(100))?
+    ///     .build()?;
+    /// let conn = pool.get()?;
+    /// // No connections are available.
+    /// let err = pool.get().unwrap_err();
+    /// assert!(err.is_pool_timeout());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn acquire_timeout(&mut self, dur: Duration) -> Result<&mut PoolBuilder> {
+        let mode = GetMode::TimedWait(dur);
+        mode.to_wait_timeout()?;
+        self.get_mode = Some(mode);
+        Ok(self)
+    }
+
     /// Specifies the length of time after which idle connections in the
     /// pool are terminated. Note that termination only occurs when the pool is
     /// accessed. The default value is [`Duration::ZERO`] which means that no idle connections are
@@ -431,6 +484,46 @@ impl PoolBuilder {
         Ok(self)
     }
 
+    /// Specifies the maximum length of time a pooled connection may be reused
+    /// since it was created.
+    ///
+    /// When [`Pool::get`] and [`Pool::get_with_options`] acquire a connection
+    /// older than `dur`, it is closed and another connection is acquired until
+    /// a young one or a new one is got. This is useful to rotate sessions so
+    /// that they pick up database failover and patching.
+    ///
+    /// Unlike [`PoolBuilder::max_lifetime_connection`], which is enforced by
+    /// the Oracle client library 18.1 or later in seconds, this is checked by
+    /// this crate when connections are acquired. Connections in use are
+    /// never closed. The creation time of each pooled session is tracked
+    /// by the pool.
+    ///
+    /// The default is no limit. [`Duration::ZERO`] also means no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # use std::time::Duration;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .max_lifetime(Duration::from_secs(3600))
+    ///     .build()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn max_lifetime(&mut self, dur: Duration) -> &mut PoolBuilder {
+        self.max_lifetime = if dur == Duration::ZERO {
+            None
+        } else {
+            Some(dur)
+        };
+        self
+    }
+
     /// Specifies the name of a PL/SQL procedure in the format
     /// *schema.package.callback_proc* which will be called when a connection is
     /// checked out from the pool and the requested tag doesn't match the actual
@@ -461,6 +554,97 @@ impl PoolBuilder {
         self
     }
 
+    /// Specifies whether a validation query is executed on connections
+    /// acquired by [`Pool::get`] and [`Pool::get_with_options`].
+    /// The default value is `false`.
+    ///
+    /// [`PoolBuilder::ping_interval`] uses a protocol-level round trip, which
+    /// some proxies and load balancers answer without reaching the database.
+    /// When this is enabled, the query specified by [`PoolBuilder::validation_query`],
+    /// `SELECT 1 FROM dual` by default, is executed instead. If it fails, the
+    /// connection is dropped from the pool and another one is acquired and
+    /// validated. The error is returned when the query fails again.
+    ///
+    /// Note that this adds one round trip to the database to every get.
+    pub fn validate_on_get(&mut self, b: bool) -> &mut PoolBuilder {
+        self.validate_on_get = b;
+        self
+    }
+
+    /// Specifies the SQL statement to validate connections acquired from the pool
+    /// and enables validation. The statement must be a query.
+    ///
+    /// See [`PoolBuilder::validate_on_get`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .validation_query("select 1 from dual")
+    ///     .build()?;
+    /// // The query is executed before the connection is returned.
+    /// let conn = pool.get()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn validation_query<S>(&mut self, sql: S) -> &mut PoolBuilder
+    where
+        S: Into<String>,
+    {
+        self.validate_on_get = true;
+        self.validation_query = Some(sql.into());
+        self
+    }
+
+    /// Sets a callback to initialize session state such as NLS parameters
+    /// and package variables.
+    ///
+    /// The callback is called once per session created by the pool, when
+    /// a connection whose [`is_new_connection`](Connection::is_new_connection)
+    /// is true is got from the pool. It isn't called for sessions reused from the
+    /// pool, which keep the state set by the callback. When it returns an error,
+    /// the session is dropped from the pool and the error is returned by [`Pool::get`].
+    ///
+    /// This is a Rust alternative of [`plsql_fixup_callback`](PoolBuilder::plsql_fixup_callback),
+    /// which doesn't depend on session tags. To use [proxy authentication][],
+    /// pass `user[proxy_user]` as the username. Then the callback initializes
+    /// sessions of `proxy_user`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .session_init(|conn| {
+    ///         conn.execute("alter session set nls_date_format = 'YYYY-MM-DD'", &[])?;
+    ///         Ok(())
+    ///     })
+    ///     .build()?;
+    /// let conn = pool.get()?;
+    /// let val = conn.query_row_as::<String>("select to_char(date '2021-02-03') from dual", &[])?;
+    /// assert_eq!(val, "2021-02-03");
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [proxy authentication]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-D77D0D4A-7483-423A-9767-CBB5854A15CC
+    pub fn session_init<F>(&mut self, f: F) -> &mut PoolBuilder
+    where
+        F: Fn(&Connection) -> Result<()> + Send + Sync + 'static,
+    {
+        self.session_init = Some(SessionInitCallback::new(f));
+        self
+    }
+
     fn to_dpi_pool_create_params(&self, ctxt: &Context) -> Result<dpiPoolCreateParams> {
         let mut pool_params = ctxt.pool_create_params();
 
@@ -575,9 +759,24 @@ impl PoolBuilder {
                 &mut handle
             )
         );
+        let validation_query = if self.validate_on_get {
+            Some(Arc::from(
+                self.validation_query
+                    .as_deref()
+                    .unwrap_or(DEFAULT_VALIDATION_QUERY),
+            ))
+        } else {
+            None
+        };
         Ok(Pool {
             ctxt,
             handle: DpiPool::new(handle),
+            max_connections: Arc::new(AtomicU32::new(pool_params.maxSessions)),
+            validation_query,
+            max_lifetime: self.max_lifetime,
+            session_created_at: Arc::new(Mutex::new(HashMap::new())),
+            get_mode_lock: Arc::new(RwLock::new(())),
+            session_init: self.session_init.clone(),
         })
     }
 }
@@ -701,6 +900,15 @@ impl PoolBuilder {
 pub struct Pool {
     ctxt: Context,
     handle: DpiPool,
+    max_connections: Arc<AtomicU32>,
+    validation_query: Option<Arc<str>>,
+    max_lifetime: Option<Duration>,
+    // creation time of unexpired pooled sessions keyed by OCI session handles
+    session_created_at: Arc<Mutex<HashMap<usize, Instant>>>,
+    // held for reading while acquiring connections and for writing while
+    // the get mode is changed temporarily
+    get_mode_lock: Arc<RwLock<()>>,
+    session_init: Option<SessionInitCallback>,
 }
 
 impl Pool {
@@ -725,15 +933,117 @@ impl Pool {
         self.get_with_options(&PoolOptions::new())
     }
 
+    /// Gets a connection from the pool without waiting for a busy one.
+    ///
+    /// This gets a connection as [`Pool::get`] does with [`GetMode::NoWait`]
+    /// regardless of the pool's get mode. It returns `Ok(None)` immediately
+    /// when no connection is available, including when another thread is
+    /// acquiring a connection from the pool at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .max_connections(1)
+    ///     .build()?;
+    /// let conn = pool.get_nowait()?;
+    /// assert!(conn.is_some());
+    /// // The pool is saturated.
+    /// assert!(pool.get_nowait()?.is_none());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_nowait(&self) -> Result<Option<Connection>> {
+        // The get mode is an attribute of the pool. Other threads must not
+        // acquire connections while it is changed.
+        let _guard = match self.get_mode_lock.try_write() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => return Ok(None),
+            Err(TryLockError::Poisoned(err)) => return Err(err.into()),
+        };
+        let get_mode = self.get_mode()?;
+        let result = if get_mode == GetMode::NoWait {
+            self.get_connection(&PoolOptions::new(), true)
+        } else {
+            self.set_dpi_get_mode(&GetMode::NoWait)?;
+            let result = self.get_connection(&PoolOptions::new(), true);
+            let restored = self.set_dpi_get_mode(&get_mode);
+            result.and_then(|conn| restored.map(|_| conn))
+        };
+        match result {
+            Ok(conn) => Ok(Some(conn)),
+            Err(err) if err.is_pool_timeout() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Acquires a connection from the specified connection pool.
     ///
     /// See also [`Pool::get`].
     pub fn get_with_options(&self, options: &PoolOptions) -> Result<Connection> {
+        self.get_connection(options, false)
+    }
+
+    // `locked` is true when the caller holds the write lock of `get_mode_lock`.
+    fn get_connection(&self, options: &PoolOptions, locked: bool) -> Result<Connection> {
+        let conn = self.acquire_connection(options, locked)?;
+        if let Some(ref sql) = self.validation_query {
+            if validate_connection(&conn, sql).is_err() {
+                // Discard the broken connection and try another one.
+                conn.close_with_mode(conn::CloseMode::Drop)?;
+                let conn = self.acquire_connection(options, locked)?;
+                if let Err(err) = validate_connection(&conn, sql) {
+                    let _ = conn.close_with_mode(conn::CloseMode::Drop);
+                    return Err(err);
+                }
+                return Ok(conn);
+            }
+        }
+        Ok(conn)
+    }
+
+    fn acquire_connection(&self, options: &PoolOptions, locked: bool) -> Result<Connection> {
+        let max_lifetime = match self.max_lifetime {
+            Some(max_lifetime) => max_lifetime,
+            None => return self.acquire_pooled_connection(options, locked),
+        };
+        loop {
+            let conn = self.acquire_pooled_connection(options, locked)?;
+            let session = conn.session_handle()?;
+            let now = Instant::now();
+            let mut session_created_at = self.session_created_at.lock()?;
+            // Remove expired sessions including ones closed by the pool itself.
+            session_created_at
+                .retain(|_, created_at| now.duration_since(*created_at) < max_lifetime);
+            if conn.is_new_connection() {
+                session_created_at.insert(session, now);
+                return Ok(conn);
+            }
+            if session_created_at.contains_key(&session) {
+                return Ok(conn);
+            }
+            // The session is too old. Close it and try another one.
+            drop(session_created_at);
+            conn.close_with_mode(conn::CloseMode::Drop)?;
+        }
+    }
+
+    fn acquire_pooled_connection(&self, options: &PoolOptions, locked: bool) -> Result<Connection> {
         let ctxt = Context::new()?;
         let username = to_odpi_str(&options.username);
         let password = to_odpi_str(&options.password);
         let mut conn_params = options.to_dpi_conn_create_params(&ctxt);
         let mut handle = ptr::null_mut();
+        let guard = if locked {
+            None
+        } else {
+            Some(self.get_mode_lock.read()?)
+        };
         chkerr!(
             &ctxt,
             dpiPool_acquireConnection(
@@ -746,8 +1056,18 @@ impl Pool {
                 &mut handle
             )
         );
+        drop(guard);
         ctxt.set_warning();
-        Ok(Connection::from_dpi_handle(ctxt, handle, &conn_params))
+        let conn = Connection::from_dpi_handle(ctxt, handle, &conn_params, Some(self.clone()));
+        if let Some(ref session_init) = self.session_init {
+            if conn.is_new_connection() {
+                if let Err(err) = session_init.call(&conn) {
+                    let _ = conn.close_with_mode(conn::CloseMode::Drop);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(conn)
     }
 
     /// Closes the pool and makes it unusable for further activity.
@@ -814,6 +1134,10 @@ impl Pool {
     ///
     /// See also [`PoolBuilder::get_mode`] and [`Pool::get_mode`].
     pub fn set_get_mode(&mut self, mode: &GetMode) -> Result<()> {
+        self.set_dpi_get_mode(mode)
+    }
+
+    fn set_dpi_get_mode(&self, mode: &GetMode) -> Result<()> {
         let get_mode = mode.to_dpi_value();
         let wait_timeout = mode.to_wait_timeout()?;
         chkerr!(self.ctxt(), dpiPool_setGetMode(self.handle(), get_mode));
@@ -926,6 +1250,8 @@ impl Pool {
                 connection_increment
             )
         );
+        self.max_connections
+            .store(max_connections, Ordering::Relaxed);
         Ok(())
     }
 
@@ -1015,6 +1341,14 @@ impl Pool {
     }
 }
 
+fn validate_connection(conn: &Connection, sql: &str) -> Result<()> {
+    let mut rows = conn.query(sql, &[])?;
+    if let Some(row) = rows.next() {
+        row?;
+    }
+    Ok(())
+}
+
 impl fmt::Debug for Pool {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Poll {{ handle: {:?}", self.handle())
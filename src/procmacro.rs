@@ -18,3 +18,6 @@
 
 #[doc = include_str!("../oracle_procmacro/docs/row_value.md")]
 struct RowValue;
+
+#[doc = include_str!("../oracle_procmacro/docs/into_object.md")]
+struct IntoObject;
@@ -0,0 +1,325 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::ToSql;
+use crate::Connection;
+use crate::Connector;
+use crate::Error;
+use crate::Result;
+use crate::RowValue;
+use crate::Statement;
+use std::fmt;
+use std::result;
+use std::time::{Duration, Instant};
+
+/// Connection wrapper which reconnects automatically when the connection is lost
+///
+/// When a statement fails by an error meaning disconnection such as
+/// ORA-03113 and ORA-03114 (see [`Error::is_disconnected`]), this
+/// reconnects to the database using the stored [`Connector`] and
+/// retries the statement once if it is safe to retry.
+///
+/// # Safety boundary
+///
+/// A statement is retried only when it is a query or
+/// [`retry_non_idempotent(true)`](#method.retry_non_idempotent) is set.
+///
+/// DML statements, PL/SQL blocks and DDL statements aren't retried by default
+/// because they may have been executed on the server before the connection
+/// was lost. The error is returned as it is in that case.
+/// When the statement cannot be prepared because of disconnection, whether
+/// it is a query is decided by the first keyword, `SELECT` or `WITH`.
+///
+/// Note that the reconnected connection is a new session. Session settings
+/// changed by `ALTER SESSION`, package states and so on are lost on reconnection
+/// even when the failed statement is a query. Don't use this when such session
+/// state matters. [Autocommit mode](Connection::autocommit) is restored on
+/// reconnection.
+///
+/// # Uncommitted transactions
+///
+/// When DML statements or PL/SQL blocks have been executed by this wrapper
+/// after the last [`commit`](#method.commit) or [`rollback`](#method.rollback)
+/// without autocommit, the connection isn't reconnected automatically because
+/// the uncommitted changes are lost on reconnection. The disconnection error is
+/// returned as it is instead. Call [`reconnect`](#method.reconnect) explicitly
+/// to discard the lost transaction and use the wrapper again.
+///
+/// Commit or roll back transactions by methods of this wrapper. Transactions
+/// ended through [`connection`](#method.connection) aren't tracked.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # use oracle::{Connector, ResilientConnection};
+/// # use std::time::Duration;
+/// let connector = Connector::new(
+///     test_util::main_user(),
+///     test_util::main_password(),
+///     test_util::connect_string(),
+/// );
+/// let mut conn = ResilientConnection::new(connector)?;
+/// conn.ping_interval(Some(Duration::from_secs(60)));
+///
+/// let val = conn.query_row_as::<i32>("select 1 from dual", &[])?;
+/// assert_eq!(val, 1);
+/// # Ok::<(), Error>(())
+/// ```
+pub struct ResilientConnection {
+    connector: Connector,
+    conn: Connection,
+    retry_non_idempotent: bool,
+    ping_interval: Option<Duration>,
+    last_used: Instant,
+    reconnect_count: u64,
+    retry_count: u64,
+    last_error: Option<Error>,
+    in_transaction: bool,
+}
+
+impl ResilientConnection {
+    /// Connects to the database using `connector`, which is also
+    /// used on reconnection.
+    pub fn new(connector: Connector) -> Result<ResilientConnection> {
+        let conn = connector.connect()?;
+        Ok(ResilientConnection {
+            connector,
+            conn,
+            retry_non_idempotent: false,
+            ping_interval: None,
+            last_used: Instant::now(),
+            reconnect_count: 0,
+            retry_count: 0,
+            last_error: None,
+            in_transaction: false,
+        })
+    }
+
+    /// Sets whether statements other than queries are retried on reconnection.
+    /// The default value is `false`.
+    ///
+    /// Set `true` only when all DML statements and PL/SQL blocks executed by
+    /// this connection are idempotent. Otherwise they may be executed twice.
+    pub fn retry_non_idempotent(&mut self, retry: bool) -> &mut ResilientConnection {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Sets the interval to check the connection before executing a statement.
+    /// The default value is `None`, which means that the connection isn't checked.
+    ///
+    /// When the connection has been idle for longer than the interval,
+    /// it is checked by [`Connection::ping`] and reconnected if it was lost.
+    /// This is useful to avoid errors on the first statement after the connection
+    /// was disconnected by firewalls or idle timeouts.
+    pub fn ping_interval(&mut self, interval: Option<Duration>) -> &mut ResilientConnection {
+        self.ping_interval = interval;
+        self
+    }
+
+    /// Gets the underlying connection.
+    ///
+    /// Note that the connection may be replaced on reconnection.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Reconnects to the database regardless of the connection status.
+    ///
+    /// Uncommitted changes in the current session are discarded.
+    pub fn reconnect(&mut self) -> Result<()> {
+        let mut conn = self.connector.connect()?;
+        conn.set_autocommit(self.conn.autocommit());
+        self.conn = conn;
+        self.reconnect_count += 1;
+        self.in_transaction = false;
+        Ok(())
+    }
+
+    /// Commits the current active transaction
+    ///
+    /// See also [`Connection::commit`].
+    pub fn commit(&mut self) -> Result<()> {
+        self.conn.commit()?;
+        self.in_transaction = false;
+        Ok(())
+    }
+
+    /// Rolls back the current active transaction
+    ///
+    /// See also [`Connection::rollback`].
+    pub fn rollback(&mut self) -> Result<()> {
+        self.conn.rollback()?;
+        self.in_transaction = false;
+        Ok(())
+    }
+
+    /// Returns `true` when DML statements or PL/SQL blocks have been executed
+    /// after the last commit or rollback without autocommit.
+    ///
+    /// The connection isn't reconnected automatically while this is `true`.
+    pub fn in_transaction(&self) -> bool {
+        self.in_transaction
+    }
+
+    /// Gets the number of reconnections since this wrapper was created.
+    ///
+    /// This includes reconnections by [`reconnect`](#method.reconnect).
+    pub fn reconnect_count(&self) -> u64 {
+        self.reconnect_count
+    }
+
+    /// Gets the number of statements retried after reconnection since this
+    /// wrapper was created.
+    pub fn retry_count(&self) -> u64 {
+        self.retry_count
+    }
+
+    /// Gets the last disconnection error which caused automatic reconnection.
+    ///
+    /// Errors returned to the caller aren't kept here.
+    pub fn last_error(&self) -> Option<&Error> {
+        self.last_error.as_ref()
+    }
+
+    /// Executes a statement and returns the number of affected rows.
+    ///
+    /// See also [`Connection::execute`].
+    pub fn execute(&mut self, sql: &str, params: &[&dyn ToSql]) -> Result<u64> {
+        self.run(sql, |stmt| {
+            stmt.execute(params)?;
+            stmt.row_count()
+        })
+    }
+
+    /// Executes a query and returns all rows.
+    ///
+    /// Rows are fetched in advance so that the query can be retried
+    /// when the connection is lost during fetching rows.
+    ///
+    /// See also [`Connection::query_as`].
+    pub fn query_as<T>(&mut self, sql: &str, params: &[&dyn ToSql]) -> Result<Vec<T>>
+    where
+        T: RowValue,
+    {
+        self.run(sql, |stmt| stmt.query_as::<T>(params)?.collect())
+    }
+
+    /// Gets one row from a query.
+    ///
+    /// See also [`Connection::query_row_as`].
+    pub fn query_row_as<T>(&mut self, sql: &str, params: &[&dyn ToSql]) -> Result<T>
+    where
+        T: RowValue,
+    {
+        self.run(sql, |stmt| stmt.query_row_as::<T>(params))
+    }
+
+    fn run<T, F>(&mut self, sql: &str, mut f: F) -> Result<T>
+    where
+        F: FnMut(&mut Statement) -> Result<T>,
+    {
+        self.ping_if_idle()?;
+        let result = match self.run_once(sql, &mut f) {
+            Err((err, true)) if err.is_disconnected() && !self.in_transaction => {
+                self.last_error = Some(err);
+                self.reconnect()?;
+                self.retry_count += 1;
+                self.run_once(sql, &mut f).map_err(|(err, _)| err)
+            }
+            result => result.map_err(|(err, _)| err),
+        };
+        self.last_used = Instant::now();
+        result
+    }
+
+    // The second element of the error is whether the statement is safe to retry.
+    fn run_once<T, F>(&mut self, sql: &str, f: &mut F) -> result::Result<T, (Error, bool)>
+    where
+        F: FnMut(&mut Statement) -> Result<T>,
+    {
+        let mut stmt = match self.conn.statement(sql).build() {
+            Ok(stmt) => stmt,
+            Err(err) => return Err((err, self.retry_non_idempotent || starts_with_query(sql))),
+        };
+        let retryable = self.retry_non_idempotent || stmt.is_query();
+        let result = f(&mut stmt).map_err(|err| (err, retryable))?;
+        if stmt.is_ddl() {
+            // DDL statements commit the current transaction implicitly.
+            self.in_transaction = false;
+        } else if (stmt.is_dml() || stmt.is_plsql()) && !self.conn.autocommit() {
+            self.in_transaction = true;
+        }
+        Ok(result)
+    }
+
+    fn ping_if_idle(&mut self) -> Result<()> {
+        if let Some(interval) = self.ping_interval {
+            if self.last_used.elapsed() >= interval {
+                match self.conn.ping() {
+                    Err(err) if err.is_disconnected() && !self.in_transaction => {
+                        self.last_error = Some(err);
+                        self.reconnect()?;
+                    }
+                    result => result?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn starts_with_query(sql: &str) -> bool {
+    let sql = sql.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
+    let keyword = sql
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or("");
+    keyword.eq_ignore_ascii_case("select") || keyword.eq_ignore_ascii_case("with")
+}
+
+impl fmt::Debug for ResilientConnection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ResilientConnection")
+            .field("conn", &self.conn)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("ping_interval", &self.ping_interval)
+            .field("reconnect_count", &self.reconnect_count)
+            .field("retry_count", &self.retry_count)
+            .field("in_transaction", &self.in_transaction)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_with_query() {
+        assert!(starts_with_query("select 1 from dual"));
+        assert!(starts_with_query("  SELECT * from dual"));
+        assert!(starts_with_query("\n(select 1 from dual)"));
+        assert!(starts_with_query(
+            "with t as (select 1 from dual) select * from t"
+        ));
+        assert!(!starts_with_query("insert into t select 1 from dual"));
+        assert!(!starts_with_query("selectx"));
+        assert!(!starts_with_query("begin null; end;"));
+        assert!(!starts_with_query(""));
+    }
+}
@@ -17,22 +17,40 @@ use std::fmt;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use crate::sql_type::ColumnValue;
 use crate::sql_type::FromSql;
+use crate::statement::find_column_indices;
 use crate::statement::Stmt;
 use crate::ColumnIndex;
 use crate::ColumnInfo;
+use crate::Error;
 use crate::Result;
 use crate::SqlValue;
 
 #[allow(unused_imports)] // for links in doc comments
 use crate::Connection;
 
+static DEBUG_VALUES: AtomicBool = AtomicBool::new(false);
+
 pub struct RowSharedData {
     column_names: Vec<String>,
 }
 
 /// Row in a result set of a select statement
+///
+/// The [`Debug`](fmt::Debug) output contains column names and types.
+/// Column values are redacted unless [`Row::set_debug_values`] enables them.
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let row = conn.query_row("select 'secret' password from dual", &[])?;
+/// assert_eq!(format!("{:?}", row), "Row { PASSWORD: CHAR(6) = <redacted> }");
+/// # Ok::<(), Error>(())
+/// ```
 pub struct Row {
     pub(crate) shared: Rc<RowSharedData>,
     pub(crate) column_values: Vec<SqlValue>,
@@ -48,6 +66,11 @@ impl Row {
     }
 
     /// Gets the column value at the specified index.
+    ///
+    /// When a column name is specified, the first column with the name is used.
+    /// A column name matched exactly takes precedence over ones matched
+    /// case-insensitively. Use [`Row::get_all_by_name`] to get all columns
+    /// with the same name such as `a.id` and `b.id` in a join.
     pub fn get<I, T>(&self, colidx: I) -> Result<T>
     where
         I: ColumnIndex,
@@ -57,11 +80,152 @@ impl Row {
         self.column_values[pos].get()
     }
 
+    /// Gets the values of all columns with the specified name in the column order.
+    ///
+    /// A query may return more than one column with the same name, for example
+    /// when it selects columns with the same name from joined tables.
+    /// [`Row::get`] returns the first one in the case. This returns all of them.
+    /// Columns are matched by the same rule with [`Row::get`]. This returns
+    /// `Err(Error::InvalidColumnName(...))` when no column matches.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let row = conn.query_row("select 1 id, 2 id from dual", &[])?;
+    /// assert_eq!(row.get::<_, i32>("id")?, 1);
+    /// assert_eq!(row.get_all_by_name::<i32>("id")?, vec![1, 2]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_all_by_name<T>(&self, name: &str) -> Result<Vec<T>>
+    where
+        T: FromSql,
+    {
+        let indices = find_column_indices(&self.shared.column_names, name);
+        if indices.is_empty() {
+            return Err(Error::InvalidColumnName(name.to_string()));
+        }
+        indices
+            .into_iter()
+            .map(|pos| self.column_values[pos].get())
+            .collect()
+    }
+
+    /// Gets the column value at the specified index as a string into `buf`.
+    ///
+    /// The contents of `buf` are replaced with the column value. Its allocated
+    /// memory is reused when the capacity is enough to hold the value. This is
+    /// useful to implement [`FromRowInPlace`].
+    pub fn get_string_into<I>(&self, colidx: I, buf: &mut String) -> Result<()>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.shared.column_names)?;
+        self.column_values[pos].to_string_into(buf)
+    }
+
+    /// Gets the `RAW` column value at the specified index as a slice borrowing
+    /// the buffer of the row.
+    ///
+    /// This doesn't copy the value unlike `row.get::<_, Vec<u8>>(colidx)`.
+    /// Note that a row returned by [`ResultSet`] holds its own copy of the
+    /// fetched values. So the slice doesn't refer to the fetch buffer of the
+    /// statement and is valid as long as the row is.
+    ///
+    /// This returns `Err(Error::InvalidTypeConversion(...))` for columns
+    /// other than `RAW` and `Err(Error::NullValue)` for null values.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let row = conn.query_row("select hextoraw('DEADBEEF') from dual", &[])?;
+    /// assert_eq!(row.get_bytes(0)?, &[0xde, 0xad, 0xbe, 0xef]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_bytes<I>(&self, colidx: I) -> Result<&[u8]>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.shared.column_names)?;
+        self.column_values[pos].as_raw_slice()
+    }
+
+    /// Returns `Ok(true)` when the column value at the specified index is null.
+    ///
+    /// This checks the null indicator of the fetched value without
+    /// converting it to a rust type.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let row = conn.query_row("select NULL, 1 from dual", &[])?;
+    /// assert_eq!(row.is_null(0)?, true);
+    /// assert_eq!(row.is_null(1)?, false);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn is_null<I>(&self, colidx: I) -> Result<bool>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.shared.column_names)?;
+        self.column_values[pos].is_null()
+    }
+
+    /// Returns `Ok(true)` when the column value with the specified name is null.
+    ///
+    /// This is same with `row.is_null(name)`. The column name is compared
+    /// case-insensitively.
+    pub fn is_null_by_name(&self, name: &str) -> Result<bool> {
+        self.is_null(name)
+    }
+
     /// Returns column values as a vector of SqlValue
     pub fn sql_values(&self) -> &[SqlValue] {
         &self.column_values
     }
 
+    /// Returns column values as a vector of [`ColumnValue`], whose variants
+    /// are decided by the column types at run time.
+    pub fn column_values(&self) -> Result<Vec<ColumnValue>> {
+        self.column_values
+            .iter()
+            .map(ColumnValue::from_sql)
+            .collect()
+    }
+
+    /// Consumes the row and returns column values as a vector of [`ColumnValue`].
+    ///
+    /// This is same with [`Row::column_values`] except that the row is consumed.
+    /// The returned values own their data and are usable after the statement
+    /// and the connection are dropped. LOB columns are read entirely even
+    /// when they are fetched as locators by [`StatementBuilder::lob_locator`].
+    /// Use [`Row::get`] with [`Clob`], [`Nclob`] or [`Blob`] instead to keep
+    /// large LOBs as locators.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::sql_type::ColumnValue;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let rows = conn
+    ///     .query("select IntCol, StringCol from TestStrings where IntCol <= 2 order by IntCol", &[])?
+    ///     .map(|row| row?.into_values())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// drop(conn);
+    /// assert_eq!(rows[1][1], ColumnValue::String("String 2".into()));
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`StatementBuilder::lob_locator`]: crate::StatementBuilder::lob_locator
+    /// [`Clob`]: crate::sql_type::Clob
+    /// [`Nclob`]: crate::sql_type::Nclob
+    /// [`Blob`]: crate::sql_type::Blob
+    pub fn into_values(self) -> Result<Vec<ColumnValue>> {
+        self.column_values()
+    }
+
     /// Gets column values as specified type.
     ///
     /// Type inference for the return type doesn't work. You need to specify
@@ -87,15 +251,40 @@ impl Row {
     {
         <T>::get(self)
     }
+
+    /// Enables or disables column values in the [`Debug`](fmt::Debug) output of rows.
+    ///
+    /// This affects all rows in the process. It is disabled by default
+    /// not to leak sensitive data to logs.
+    pub fn set_debug_values(enabled: bool) {
+        DEBUG_VALUES.store(enabled, Ordering::Relaxed);
+    }
 }
 
 impl fmt::Debug for Row {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Row {{ ")?;
-        for (name, value) in self.shared.column_names.iter().zip(&self.column_values) {
-            write!(f, "{}: {:?} ", name, value)?;
+        if DEBUG_VALUES.load(Ordering::Relaxed) {
+            write!(f, "Row {{ ")?;
+            for (name, value) in self.shared.column_names.iter().zip(&self.column_values) {
+                write!(f, "{}: {:?} ", name, value)?;
+            }
+            return write!(f, "}}");
+        }
+        write!(f, "Row {{")?;
+        for (i, (name, value)) in self
+            .shared
+            .column_names
+            .iter()
+            .zip(&self.column_values)
+            .enumerate()
+        {
+            let sep = if i == 0 { " " } else { ", " };
+            match value.oracle_type() {
+                Ok(oratype) => write!(f, "{}{}: {} = <redacted>", sep, name, oratype)?,
+                Err(_) => write!(f, "{}{}: <redacted>", sep, name)?,
+            }
         }
-        write!(f, "}}")
+        write!(f, " }}")
     }
 }
 
@@ -140,9 +329,20 @@ where
         }
     }
 
+    /// Returns column information in the order of the select list.
+    ///
+    /// Columns with the same name, which are selected from joined tables
+    /// for example, are kept as they are.
     pub fn column_info(&self) -> &[ColumnInfo] {
         &self.stmt().column_info
     }
+
+    /// Returns the zero-based index of the column whose name is `name`.
+    ///
+    /// See [`Statement::column_index`](crate::Statement::column_index) for details.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.stmt().column_index(name)
+    }
 }
 
 impl<'stmt, T> Iterator for ResultSet<'stmt, T>
@@ -222,12 +422,50 @@ pub trait RowValue: Sized {
     fn get(row: &Row) -> Result<Self>;
 }
 
+/// A trait to overwrite an existing value by a row
+///
+/// This is used by [`Statement::fetch_into`] to fetch rows without allocating
+/// a new value for each row. Use [`Row::get_string_into`] to reuse memory
+/// allocated by `String` fields.
+///
+/// ```no_run
+/// # use oracle::{Connection, Error, FromRowInPlace, Result, Row};
+/// #[derive(Default)]
+/// struct Emp {
+///     empno: i32,
+///     ename: String,
+/// }
+///
+/// impl FromRowInPlace for Emp {
+///     fn set_from_row(&mut self, row: &Row) -> Result<()> {
+///         self.empno = row.get("empno")?;
+///         row.get_string_into("ename", &mut self.ename)
+///     }
+/// }
+///
+/// let conn = Connection::connect("scott", "tiger", "")?;
+/// let mut stmt = conn.statement("select * from emp").build()?;
+/// stmt.query(&[])?;
+///
+/// let mut emp = Emp::default();
+/// while stmt.fetch_into(&mut emp)? {
+///     println!("{},{}", emp.empno, emp.ename);
+/// }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Statement::fetch_into`]: crate::Statement::fetch_into
+pub trait FromRowInPlace {
+    /// Overwrites `self` by column values in the row.
+    fn set_from_row(&mut self, row: &Row) -> Result<()>;
+}
+
 impl RowValue for Row {
     fn get(row: &Row) -> Result<Row> {
         let num_cols = row.column_values.len();
         let mut column_values = Vec::with_capacity(num_cols);
         for val in &row.column_values {
-            column_values.push(val.dup_by_handle()?);
+            column_values.push(val.dup_column_value()?);
         }
         Ok(Row {
             shared: row.shared.clone(),
@@ -0,0 +1,668 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Simple Oracle Document Access (SODA)
+//!
+//! SODA lets you use Oracle Database as a JSON document store.
+//! Documents are stored in collections and are accessed by keys or
+//! by query-by-example filters without writing SQL.
+//!
+//! SODA requires Oracle client 18.3 or later and Oracle database 18.1 or later.
+//! The database user must be granted the `SODA_APP` role.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use oracle::Error;
+//! # use oracle::test_util;
+//! # let conn = test_util::connect()?;
+//! let soda = conn.soda_database()?;
+//! let coll = soda.create_collection("mycollection")?;
+//!
+//! // Insert a document
+//! let doc = coll.insert_one(r#"{"name": "John", "age": 30}"#)?;
+//! let key = doc.key().to_string();
+//!
+//! // Get the document by key
+//! let doc = coll.find().key(&key).get_one()?.unwrap();
+//! assert_eq!(doc.content_as_str()?, r#"{"name": "John", "age": 30}"#);
+//!
+//! // Get documents by a query-by-example filter
+//! let docs = coll.find().filter(r#"{"age": {"$gt": 20}}"#).get_documents()?;
+//! assert_eq!(docs.len(), 1);
+//!
+//! // Remove the document
+//! assert_eq!(coll.find().key(&key).remove()?, 1);
+//!
+//! coll.drop_collection()?;
+//! # Ok::<(), Error>(())
+//! ```
+
+use crate::binding::*;
+use crate::chkerr;
+use crate::connection::Conn;
+use crate::new_odpi_str;
+use crate::to_odpi_str;
+use crate::to_rust_slice;
+use crate::to_rust_str;
+use crate::Context;
+use crate::DpiSodaColl;
+use crate::DpiSodaDb;
+use crate::DpiSodaDoc;
+use crate::DpiSodaDocCursor;
+use crate::Error;
+use crate::Result;
+use std::fmt;
+use std::os::raw::c_char;
+use std::ptr;
+use std::str;
+
+fn soda_flags(conn: &Conn) -> u32 {
+    if conn.autocommit() {
+        DPI_SODA_FLAGS_ATOMIC_COMMIT
+    } else {
+        DPI_SODA_FLAGS_DEFAULT
+    }
+}
+
+/// SODA database, which is the entry point to SODA collections
+///
+/// This is created by [`Connection::soda_database`](crate::Connection::soda_database).
+#[derive(Clone)]
+pub struct SodaDatabase {
+    conn: Conn,
+    handle: DpiSodaDb,
+}
+
+impl SodaDatabase {
+    pub(crate) fn new(conn: Conn, handle: DpiSodaDb) -> SodaDatabase {
+        SodaDatabase { conn, handle }
+    }
+
+    fn ctxt(&self) -> &Context {
+        self.conn.ctxt()
+    }
+
+    /// Creates a collection with default metadata.
+    ///
+    /// When a collection with the same name already exists, it is opened.
+    pub fn create_collection(&self, name: &str) -> Result<SodaCollection> {
+        self.create_collection_internal(name, None)
+    }
+
+    /// Creates a collection with the specified [collection metadata] in JSON.
+    ///
+    /// [collection metadata]: https://docs.oracle.com/en/database/oracle/simple-oracle-document-access/adsdi/soda-collection-metadata-components-reference.html
+    pub fn create_collection_with_metadata(
+        &self,
+        name: &str,
+        metadata: &str,
+    ) -> Result<SodaCollection> {
+        self.create_collection_internal(name, Some(metadata))
+    }
+
+    fn create_collection_internal(
+        &self,
+        name: &str,
+        metadata: Option<&str>,
+    ) -> Result<SodaCollection> {
+        let name = to_odpi_str(name);
+        let metadata = metadata.map(to_odpi_str).unwrap_or_else(new_odpi_str);
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_createCollection(
+                self.handle.raw(),
+                name.ptr,
+                name.len,
+                metadata.ptr,
+                metadata.len,
+                soda_flags(&self.conn),
+                &mut handle
+            )
+        );
+        SodaCollection::new(self.clone(), DpiSodaColl::new(handle))
+    }
+
+    /// Opens an existing collection. `None` is returned when it doesn't exist.
+    pub fn open_collection(&self, name: &str) -> Result<Option<SodaCollection>> {
+        let name = to_odpi_str(name);
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_openCollection(
+                self.handle.raw(),
+                name.ptr,
+                name.len,
+                soda_flags(&self.conn),
+                &mut handle
+            )
+        );
+        if handle.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(SodaCollection::new(
+                self.clone(),
+                DpiSodaColl::new(handle),
+            )?))
+        }
+    }
+
+    /// Gets the names of all collections in the database, sorted by name.
+    pub fn collection_names(&self) -> Result<Vec<String>> {
+        let mut names = dpiSodaCollNames::default();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_getCollectionNames(
+                self.handle.raw(),
+                ptr::null(),
+                0,
+                0,
+                DPI_SODA_FLAGS_DEFAULT,
+                &mut names
+            )
+        );
+        let num_names = names.numNames as usize;
+        let mut vec = Vec::with_capacity(num_names);
+        for i in 0..num_names {
+            let (name, len) = unsafe { (*names.names.add(i), *names.nameLengths.add(i)) };
+            vec.push(to_rust_str(name, len));
+        }
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_freeCollectionNames(self.handle.raw(), &mut names)
+        );
+        Ok(vec)
+    }
+
+    fn create_document(&self, content: &[u8]) -> Result<DpiSodaDoc> {
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_createDocument(
+                self.handle.raw(),
+                ptr::null(),
+                0,
+                content.as_ptr() as *const c_char,
+                content.len() as u32,
+                ptr::null(),
+                0,
+                DPI_SODA_FLAGS_DEFAULT,
+                &mut handle
+            )
+        );
+        Ok(DpiSodaDoc::new(handle))
+    }
+}
+
+impl fmt::Debug for SodaDatabase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SodaDatabase")
+    }
+}
+
+/// SODA collection, which stores JSON documents
+pub struct SodaCollection {
+    db: SodaDatabase,
+    handle: DpiSodaColl,
+    name: String,
+}
+
+impl SodaCollection {
+    fn new(db: SodaDatabase, handle: DpiSodaColl) -> Result<SodaCollection> {
+        let mut name = new_odpi_str();
+        chkerr!(
+            db.ctxt(),
+            dpiSodaColl_getName(handle.raw(), &mut name.ptr, &mut name.len)
+        );
+        Ok(SodaCollection {
+            name: name.to_string(),
+            db,
+            handle,
+        })
+    }
+
+    fn ctxt(&self) -> &Context {
+        self.db.ctxt()
+    }
+
+    fn flags(&self) -> u32 {
+        soda_flags(&self.db.conn)
+    }
+
+    /// Gets the collection name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the collection metadata in JSON
+    pub fn metadata(&self) -> Result<String> {
+        let mut metadata = new_odpi_str();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_getMetadata(self.handle.raw(), &mut metadata.ptr, &mut metadata.len)
+        );
+        Ok(metadata.to_string())
+    }
+
+    /// Inserts a JSON document into the collection and returns the inserted
+    /// document, which has the generated key but doesn't have the content.
+    ///
+    /// The insertion is committed when [autocommit mode](crate::Connection::autocommit)
+    /// is enabled.
+    pub fn insert_one(&self, content: &str) -> Result<SodaDocument> {
+        self.insert_one_bytes(content.as_bytes())
+    }
+
+    /// Inserts a [`serde_json::Value`] as a document into the collection.
+    ///
+    /// This is available when `serde_json` feature is enabled.
+    ///
+    /// [`serde_json::Value`]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+    #[cfg(feature = "serde_json")]
+    pub fn insert_one_json(&self, value: &serde_json::Value) -> Result<SodaDocument> {
+        self.insert_one_bytes(value.to_string().as_bytes())
+    }
+
+    fn insert_one_bytes(&self, content: &[u8]) -> Result<SodaDocument> {
+        let doc = self.db.create_document(content)?;
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_insertOne(self.handle.raw(), doc.raw(), self.flags(), &mut handle)
+        );
+        SodaDocument::new(self.ctxt(), DpiSodaDoc::new(handle))
+    }
+
+    /// Creates an operation to find, count, replace or remove documents.
+    ///
+    /// All documents in the collection are targeted unless the operation is
+    /// narrowed by [`SodaOperation::key`], [`SodaOperation::keys`] or
+    /// [`SodaOperation::filter`].
+    pub fn find(&self) -> SodaOperation<'_> {
+        SodaOperation {
+            coll: self,
+            key: None,
+            keys: Vec::new(),
+            version: None,
+            filter: None,
+            skip: 0,
+            limit: 0,
+        }
+    }
+
+    /// Removes all documents in the collection.
+    pub fn truncate(&self) -> Result<()> {
+        chkerr!(self.ctxt(), dpiSodaColl_truncate(self.handle.raw()));
+        Ok(())
+    }
+
+    /// Drops the collection. `false` is returned when the collection
+    /// has already been dropped.
+    pub fn drop_collection(&self) -> Result<bool> {
+        let mut is_dropped = 0;
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_drop(self.handle.raw(), self.flags(), &mut is_dropped)
+        );
+        Ok(is_dropped != 0)
+    }
+}
+
+impl fmt::Debug for SodaCollection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SodaCollection {{ name: {:?} }}", self.name)
+    }
+}
+
+/// Operation on documents in a SODA collection
+///
+/// This is created by [`SodaCollection::find`].
+/// Set conditions by `key`, `keys`, `filter` and so on and
+/// then call a terminal method such as `get_documents` or `remove`.
+///
+/// ```no_run
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// # let coll = conn.soda_database()?.create_collection("mycollection")?;
+/// let docs = coll
+///     .find()
+///     .filter(r#"{"name": {"$startsWith": "J"}}"#)
+///     .skip(10)
+///     .limit(10)
+///     .get_documents()?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug)]
+pub struct SodaOperation<'a> {
+    coll: &'a SodaCollection,
+    key: Option<String>,
+    keys: Vec<String>,
+    version: Option<String>,
+    filter: Option<String>,
+    skip: u32,
+    limit: u32,
+}
+
+impl SodaOperation<'_> {
+    /// Targets the document having the specified key.
+    pub fn key(&mut self, key: &str) -> &mut Self {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    /// Targets documents having one of the specified keys.
+    pub fn keys<I, S>(&mut self, keys: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.keys = keys
+            .into_iter()
+            .map(|key| key.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Targets the document having the specified version.
+    /// This is used with [`key`](#method.key) for optimistic locking.
+    pub fn version(&mut self, version: &str) -> &mut Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Targets documents matching the [query-by-example (QBE) filter] in JSON.
+    ///
+    /// [query-by-example (QBE) filter]: https://docs.oracle.com/en/database/oracle/simple-oracle-document-access/adsdi/overview-soda-filter-specifications-qbes.html
+    pub fn filter(&mut self, filter: &str) -> &mut Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Skips the specified number of documents. This is used only by
+    /// [`get_documents`](#method.get_documents).
+    pub fn skip(&mut self, skip: u32) -> &mut Self {
+        self.skip = skip;
+        self
+    }
+
+    /// Limits the number of documents. Zero means no limit.
+    /// This is used only by [`get_documents`](#method.get_documents).
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    fn ctxt(&self) -> &Context {
+        self.coll.ctxt()
+    }
+
+    fn with_options<T, F>(&self, f: F) -> T
+    where
+        F: FnOnce(&dpiSodaOperOptions) -> T,
+    {
+        let key_ptrs: Vec<_> = self
+            .keys
+            .iter()
+            .map(|key| key.as_ptr() as *const c_char)
+            .collect();
+        let key_lens: Vec<_> = self.keys.iter().map(|key| key.len() as u32).collect();
+        let key = self
+            .key
+            .as_deref()
+            .map(to_odpi_str)
+            .unwrap_or_else(new_odpi_str);
+        let version = self
+            .version
+            .as_deref()
+            .map(to_odpi_str)
+            .unwrap_or_else(new_odpi_str);
+        let filter = self
+            .filter
+            .as_deref()
+            .map(to_odpi_str)
+            .unwrap_or_else(new_odpi_str);
+        let options = dpiSodaOperOptions {
+            numKeys: self.keys.len() as u32,
+            keys: key_ptrs.as_ptr() as *mut *const c_char,
+            keyLengths: key_lens.as_ptr() as *mut u32,
+            key: key.ptr,
+            keyLength: key.len,
+            version: version.ptr,
+            versionLength: version.len,
+            filter: filter.ptr,
+            filterLength: filter.len,
+            skip: self.skip,
+            limit: self.limit,
+            fetchArraySize: 0,
+            hint: ptr::null(),
+            hintLength: 0,
+        };
+        f(&options)
+    }
+
+    /// Gets documents matching the conditions.
+    pub fn get_documents(&self) -> Result<Vec<SodaDocument>> {
+        let cursor = self.with_options(|options| {
+            let mut handle = ptr::null_mut();
+            chkerr!(
+                self.ctxt(),
+                dpiSodaColl_find(
+                    self.coll.handle.raw(),
+                    options,
+                    self.coll.flags(),
+                    &mut handle
+                )
+            );
+            Ok(DpiSodaDocCursor::new(handle))
+        })?;
+        let mut docs = Vec::new();
+        loop {
+            let mut handle = ptr::null_mut();
+            chkerr!(
+                self.ctxt(),
+                dpiSodaDocCursor_getNext(cursor.raw(), DPI_SODA_FLAGS_DEFAULT, &mut handle)
+            );
+            if handle.is_null() {
+                break;
+            }
+            docs.push(SodaDocument::new(self.ctxt(), DpiSodaDoc::new(handle))?);
+        }
+        Ok(docs)
+    }
+
+    /// Gets the first document matching the conditions. `None` is returned
+    /// when no documents match.
+    pub fn get_one(&self) -> Result<Option<SodaDocument>> {
+        self.with_options(|options| {
+            let mut handle = ptr::null_mut();
+            chkerr!(
+                self.ctxt(),
+                dpiSodaColl_findOne(
+                    self.coll.handle.raw(),
+                    options,
+                    self.coll.flags(),
+                    &mut handle
+                )
+            );
+            if handle.is_null() {
+                Ok(None)
+            } else {
+                Ok(Some(SodaDocument::new(
+                    self.ctxt(),
+                    DpiSodaDoc::new(handle),
+                )?))
+            }
+        })
+    }
+
+    /// Gets the number of documents matching the conditions.
+    pub fn count(&self) -> Result<u64> {
+        self.with_options(|options| {
+            let mut count = 0;
+            chkerr!(
+                self.ctxt(),
+                dpiSodaColl_getDocCount(
+                    self.coll.handle.raw(),
+                    options,
+                    self.coll.flags(),
+                    &mut count
+                )
+            );
+            Ok(count)
+        })
+    }
+
+    /// Replaces the document matching the conditions with the specified content.
+    /// `false` is returned when no documents match.
+    ///
+    /// The document must be targeted by [`key`](#method.key).
+    pub fn replace_one(&self, content: &str) -> Result<bool> {
+        let doc = self.coll.db.create_document(content.as_bytes())?;
+        self.with_options(|options| {
+            let mut replaced = 0;
+            chkerr!(
+                self.ctxt(),
+                dpiSodaColl_replaceOne(
+                    self.coll.handle.raw(),
+                    options,
+                    doc.raw(),
+                    self.coll.flags(),
+                    &mut replaced,
+                    ptr::null_mut()
+                )
+            );
+            Ok(replaced != 0)
+        })
+    }
+
+    /// Removes documents matching the conditions and returns the number of
+    /// removed documents.
+    pub fn remove(&self) -> Result<u64> {
+        self.with_options(|options| {
+            let mut count = 0;
+            chkerr!(
+                self.ctxt(),
+                dpiSodaColl_remove(
+                    self.coll.handle.raw(),
+                    options,
+                    self.coll.flags(),
+                    &mut count
+                )
+            );
+            Ok(count)
+        })
+    }
+}
+
+/// Document in a SODA collection
+#[derive(Clone, Debug, PartialEq)]
+pub struct SodaDocument {
+    key: String,
+    version: String,
+    created_on: String,
+    last_modified: String,
+    media_type: String,
+    content: Vec<u8>,
+}
+
+impl SodaDocument {
+    fn new(ctxt: &Context, handle: DpiSodaDoc) -> Result<SodaDocument> {
+        let mut key = new_odpi_str();
+        let mut version = new_odpi_str();
+        let mut created_on = new_odpi_str();
+        let mut last_modified = new_odpi_str();
+        let mut media_type = new_odpi_str();
+        let mut content = new_odpi_str();
+        let mut encoding = ptr::null();
+        let handle = handle.raw();
+        chkerr!(ctxt, dpiSodaDoc_getKey(handle, &mut key.ptr, &mut key.len));
+        chkerr!(
+            ctxt,
+            dpiSodaDoc_getVersion(handle, &mut version.ptr, &mut version.len)
+        );
+        chkerr!(
+            ctxt,
+            dpiSodaDoc_getCreatedOn(handle, &mut created_on.ptr, &mut created_on.len)
+        );
+        chkerr!(
+            ctxt,
+            dpiSodaDoc_getLastModified(handle, &mut last_modified.ptr, &mut last_modified.len)
+        );
+        chkerr!(
+            ctxt,
+            dpiSodaDoc_getMediaType(handle, &mut media_type.ptr, &mut media_type.len)
+        );
+        chkerr!(
+            ctxt,
+            dpiSodaDoc_getContent(handle, &mut content.ptr, &mut content.len, &mut encoding)
+        );
+        Ok(SodaDocument {
+            key: key.to_string(),
+            version: version.to_string(),
+            created_on: created_on.to_string(),
+            last_modified: last_modified.to_string(),
+            media_type: media_type.to_string(),
+            content: to_rust_slice(content.ptr, content.len).to_vec(),
+        })
+    }
+
+    /// Gets the document key
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Gets the document version
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Gets the creation timestamp in ISO 8601 format
+    pub fn created_on(&self) -> &str {
+        &self.created_on
+    }
+
+    /// Gets the last modified timestamp in ISO 8601 format
+    pub fn last_modified(&self) -> &str {
+        &self.last_modified
+    }
+
+    /// Gets the media type such as `application/json`
+    pub fn media_type(&self) -> &str {
+        &self.media_type
+    }
+
+    /// Gets the document content. It is empty for documents returned by
+    /// [`SodaCollection::insert_one`].
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// Gets the document content as a string
+    pub fn content_as_str(&self) -> Result<&str> {
+        str::from_utf8(&self.content).map_err(|err| Error::ParseError(Box::new(err)))
+    }
+
+    /// Gets the document content as a [`serde_json::Value`].
+    ///
+    /// This is available when `serde_json` feature is enabled.
+    ///
+    /// [`serde_json::Value`]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+    #[cfg(feature = "serde_json")]
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        serde_json::from_slice(&self.content).map_err(|err| Error::ParseError(Box::new(err)))
+    }
+}
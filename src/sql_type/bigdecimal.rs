@@ -0,0 +1,60 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use bigdecimal::BigDecimal;
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use std::str::FromStr;
+
+/// The maximum number of significant decimal digits in Oracle NUMBER.
+const MAX_NUMBER_DIGITS: u64 = 38;
+
+impl FromSql for BigDecimal {
+    fn from_sql(val: &SqlValue) -> Result<BigDecimal> {
+        let s = val.to_string()?;
+        BigDecimal::from_str(&s).map_err(|err| Error::ParseError(Box::new(err)))
+    }
+}
+
+impl ToSqlNull for BigDecimal {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+}
+
+impl ToSql for BigDecimal {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        // Trailing zeros in the integer part aren't significant digits.
+        let digits = self.normalized().digits();
+        if digits > MAX_NUMBER_DIGITS {
+            return Err(Error::OutOfRange(format!(
+                "{} has {} significant digits, which exceeds the maximum {} of Oracle NUMBER",
+                self, digits, MAX_NUMBER_DIGITS
+            )));
+        }
+        val.set_string(&self.to_string())
+    }
+}
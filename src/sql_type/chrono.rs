@@ -29,6 +29,8 @@ use chrono::naive::NaiveDate;
 use chrono::naive::NaiveDateTime;
 use chrono::offset::LocalResult;
 use chrono::Duration;
+use std::cmp::Ordering;
+use std::convert::{TryFrom, TryInto};
 
 fn fixed_offset_from_sql(ts: &Timestamp) -> Result<FixedOffset> {
     FixedOffset::east_opt(ts.tz_offset())
@@ -286,44 +288,44 @@ impl ToSql for NaiveDate {
 // chrono::Duration
 //
 
-impl FromSql for Duration {
-    fn from_sql(val: &SqlValue) -> Result<Duration> {
-        let err = |it: IntervalDS| Error::OutOfRange(format!("Duration overflow: {}", it));
-        let it = val.to_interval_ds()?;
+/// Converts `IntervalDS` to `chrono::Duration`.
+///
+/// This fails with [`Error::OutOfRange`] when the interval exceeds the range of `Duration`.
+impl TryFrom<IntervalDS> for Duration {
+    type Error = Error;
+
+    fn try_from(it: IntervalDS) -> Result<Duration> {
+        let err = || Error::OutOfRange(format!("Duration overflow: {}", it));
         let d = Duration::milliseconds(0);
         let d = d
             .checked_add(&Duration::days(it.days() as i64))
-            .ok_or_else(|| err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::hours(it.hours() as i64))
-            .ok_or_else(|| err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::minutes(it.minutes() as i64))
-            .ok_or_else(|| err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::seconds(it.seconds() as i64))
-            .ok_or_else(|| err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::nanoseconds(it.nanoseconds() as i64))
-            .ok_or_else(|| err(it))?;
+            .ok_or_else(err)?;
         Ok(d)
     }
 }
 
-impl ToSqlNull for Duration {
-    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
-        Ok(OracleType::IntervalDS(9, 9))
-    }
-}
-
-impl ToSql for Duration {
-    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
-        Ok(OracleType::IntervalDS(9, 9))
-    }
+/// Converts `chrono::Duration` to `IntervalDS`.
+///
+/// This fails with [`Error::OutOfRange`] when the number of days
+/// exceeds the range of `INTERVAL DAY(9) TO SECOND(9)`.
+impl TryFrom<Duration> for IntervalDS {
+    type Error = Error;
 
-    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
-        let secs = self.num_seconds();
-        let nsecs = (*self - Duration::seconds(secs)).num_nanoseconds().unwrap();
+    fn try_from(d: Duration) -> Result<IntervalDS> {
+        let secs = d.num_seconds();
+        let nsecs = (d - Duration::seconds(secs)).num_nanoseconds().unwrap();
         let days = secs / (24 * 60 * 60);
         let secs = secs % (24 * 60 * 60);
         let hours = secs / (60 * 60);
@@ -331,15 +333,106 @@ impl ToSql for Duration {
         let minutes = secs / 60;
         let secs = secs % 60;
         if days.abs() >= 1000000000 {
-            return Err(Error::OutOfRange(format!("too large days: {}", self)));
+            return Err(Error::OutOfRange(format!("too large days: {}", d)));
         }
-        let it = IntervalDS::new(
+        Ok(IntervalDS::new(
             days as i32,
             hours as i32,
             minutes as i32,
             secs as i32,
             nsecs as i32,
-        );
-        val.set_interval_ds(&it)
+        ))
+    }
+}
+
+fn duration_total_nanoseconds(d: &Duration) -> i128 {
+    let secs = d.num_seconds();
+    let nsecs = (*d - Duration::seconds(secs)).num_nanoseconds().unwrap();
+    secs as i128 * 1_000_000_000 + nsecs as i128
+}
+
+/// Compares the total nanoseconds of `IntervalDS` and `chrono::Duration`.
+///
+/// ```
+/// # use oracle::sql_type::IntervalDS;
+/// use chrono::Duration;
+/// let it = IntervalDS::new(0, 1, 30, 0, 0);
+/// assert_eq!(it, Duration::minutes(90));
+/// assert!(it < Duration::hours(2));
+/// ```
+impl PartialEq<Duration> for IntervalDS {
+    fn eq(&self, other: &Duration) -> bool {
+        self.total_nanoseconds() == duration_total_nanoseconds(other)
+    }
+}
+
+impl PartialEq<IntervalDS> for Duration {
+    fn eq(&self, other: &IntervalDS) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Duration> for IntervalDS {
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        Some(
+            self.total_nanoseconds()
+                .cmp(&duration_total_nanoseconds(other)),
+        )
+    }
+}
+
+impl PartialOrd<IntervalDS> for Duration {
+    fn partial_cmp(&self, other: &IntervalDS) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl FromSql for Duration {
+    fn from_sql(val: &SqlValue) -> Result<Duration> {
+        val.to_interval_ds()?.try_into()
+    }
+}
+
+impl ToSqlNull for Duration {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for Duration {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_interval_ds(&IntervalDS::try_from(*self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_interval_ds_with_duration() {
+        let it = IntervalDS::new(1, 2, 3, 4, 500000000);
+        let d = Duration::days(1)
+            + Duration::hours(2)
+            + Duration::minutes(3)
+            + Duration::seconds(4)
+            + Duration::milliseconds(500);
+        assert_eq!(it, d);
+        assert_eq!(d, it);
+        assert_eq!(it.partial_cmp(&d), Some(Ordering::Equal));
+
+        let larger = d + Duration::nanoseconds(1);
+        assert_ne!(it, larger);
+        assert!(it < larger);
+        assert!(larger > it);
+
+        let it = IntervalDS::new(-1, -2, -3, -4, -500000000);
+        assert_eq!(it, -d);
+        assert!(it < Duration::zero());
+        assert!(it > -larger);
     }
 }
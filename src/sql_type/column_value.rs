@@ -0,0 +1,294 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::Result;
+use crate::SqlValue;
+use std::fmt;
+
+/// A column value whose Rust type is decided at run time by the Oracle type
+///
+/// This is useful for generic tools which render any column without
+/// knowing column types at compile time.
+///
+/// | Oracle Type | Variant |
+/// | --- | --- |
+/// | null values | `Null` |
+/// | `number`, `float` | `Number` (decimal text to preserve precision) |
+/// | `binary_float`, `binary_double` | `BinaryDouble` |
+/// | character data types, `long`, `clob`, `nclob`, `rowid` | `String` |
+/// | `raw`, `long raw`, `blob` | `Raw` |
+/// | `date` and timestamp data types | `Timestamp` |
+/// | `interval day to second` | `IntervalDS` |
+/// | `interval year to month` | `IntervalYM` |
+/// | `boolean` | `Boolean` |
+/// | other types | `Other` (string representation of the value) |
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::sql_type::ColumnValue;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let row = conn.query_row("select 1.5, 'foo', null from dual", &[])?;
+/// let values = row.column_values()?;
+/// assert_eq!(values[0], ColumnValue::Number("1.5".into()));
+/// assert_eq!(values[1], ColumnValue::String("foo".into()));
+/// assert_eq!(values[2], ColumnValue::Null);
+/// let text = values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+/// assert_eq!(text, "1.5, foo, NULL");
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnValue {
+    /// null value
+    Null,
+    /// `number` or `float` value as decimal text
+    Number(String),
+    /// `binary_float` or `binary_double` value
+    BinaryDouble(f64),
+    /// character data
+    String(String),
+    /// binary data
+    Raw(Vec<u8>),
+    /// `date` or timestamp value
+    Timestamp(Timestamp),
+    /// `interval day to second` value
+    IntervalDS(IntervalDS),
+    /// `interval year to month` value
+    IntervalYM(IntervalYM),
+    /// `boolean` value
+    Boolean(bool),
+    /// string representation of other types
+    Other(String),
+}
+
+impl FromSql for ColumnValue {
+    fn from_sql(val: &SqlValue) -> Result<ColumnValue> {
+        if val.is_null()? {
+            return Ok(ColumnValue::Null);
+        }
+        Ok(match val.oracle_type()? {
+            OracleType::Number(_, _)
+            | OracleType::Float(_)
+            | OracleType::Int64
+            | OracleType::UInt64 => ColumnValue::Number(val.to_string()?),
+            OracleType::BinaryFloat | OracleType::BinaryDouble => {
+                ColumnValue::BinaryDouble(val.to_f64()?)
+            }
+            OracleType::Varchar2(_)
+            | OracleType::NVarchar2(_)
+            | OracleType::Char(_)
+            | OracleType::NChar(_)
+            | OracleType::Rowid
+            | OracleType::Long
+            | OracleType::CLOB
+            | OracleType::NCLOB => ColumnValue::String(val.to_string()?),
+            OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB => {
+                ColumnValue::Raw(val.to_bytes()?)
+            }
+            OracleType::Date
+            | OracleType::Timestamp(_)
+            | OracleType::TimestampTZ(_)
+            | OracleType::TimestampLTZ(_) => ColumnValue::Timestamp(val.to_timestamp()?),
+            OracleType::IntervalDS(_, _) => ColumnValue::IntervalDS(val.to_interval_ds()?),
+            OracleType::IntervalYM(_) => ColumnValue::IntervalYM(val.to_interval_ym()?),
+            OracleType::Boolean => ColumnValue::Boolean(val.to_bool()?),
+            _ => ColumnValue::Other(val.to_string()?),
+        })
+    }
+}
+
+/// Renders the value as text.
+///
+/// `Null` is rendered as `NULL` and `Raw` as uppercase hexadecimal digits
+/// as Oracle does. Other variants are rendered by `Display` of the
+/// contained values.
+impl fmt::Display for ColumnValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnValue::Null => write!(f, "NULL"),
+            ColumnValue::Number(s) | ColumnValue::String(s) | ColumnValue::Other(s) => {
+                write!(f, "{}", s)
+            }
+            ColumnValue::BinaryDouble(n) => write!(f, "{}", n),
+            ColumnValue::Raw(bytes) => {
+                for b in bytes {
+                    write!(f, "{:02X}", b)?;
+                }
+                Ok(())
+            }
+            ColumnValue::Timestamp(ts) => write!(f, "{}", ts),
+            ColumnValue::IntervalDS(it) => write!(f, "{}", it),
+            ColumnValue::IntervalYM(it) => write!(f, "{}", it),
+            ColumnValue::Boolean(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl ColumnValue {
+    /// Converts the value to [`serde_json::Value`].
+    ///
+    /// | Variant | JSON value |
+    /// | --- | --- |
+    /// | `Null` | `null` |
+    /// | `Number` | number when it is converted without loss of precision. Otherwise, string |
+    /// | `BinaryDouble` | number. `NaN` and infinity are converted to string. |
+    /// | `Boolean` | `true` or `false` |
+    /// | `Raw` | base64-encoded string |
+    /// | others | string same with `Display` |
+    ///
+    /// This is available when `serde_json` feature is enabled.
+    ///
+    /// [`serde_json::Value`]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value;
+        match self {
+            ColumnValue::Null => Value::Null,
+            ColumnValue::Number(s) => number_to_json(s),
+            ColumnValue::BinaryDouble(n) => serde_json::Number::from_f64(*n)
+                .map(Value::Number)
+                .unwrap_or_else(|| Value::String(n.to_string())),
+            ColumnValue::Boolean(b) => Value::Bool(*b),
+            ColumnValue::Raw(bytes) => Value::String(base64_encode(bytes)),
+            _ => Value::String(self.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+pub(crate) fn number_to_json(s: &str) -> serde_json::Value {
+    use serde_json::Value;
+    if let Ok(n) = s.parse::<i64>() {
+        return Value::from(n);
+    }
+    if let Ok(n) = s.parse::<u64>() {
+        return Value::from(n);
+    }
+    if let Ok(n) = s.parse::<f64>() {
+        // Use a number only when it is converted back to the same text.
+        if n.to_string() == s {
+            if let Some(n) = serde_json::Number::from_f64(n) {
+                return Value::Number(n);
+            }
+        }
+    }
+    Value::String(s.to_string())
+}
+
+#[cfg(feature = "serde_json")]
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut s = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(TABLE[(n >> (18 - i * 6) & 0x3f) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(ColumnValue::Null.to_string(), "NULL");
+        assert_eq!(
+            ColumnValue::Number("123.4500000000000000001".into()).to_string(),
+            "123.4500000000000000001"
+        );
+        assert_eq!(ColumnValue::BinaryDouble(1.5).to_string(), "1.5");
+        assert_eq!(ColumnValue::String("foo".into()).to_string(), "foo");
+        assert_eq!(ColumnValue::Raw(vec![0x01, 0xab]).to_string(), "01AB");
+        assert_eq!(
+            ColumnValue::Timestamp(Timestamp::new(2012, 3, 4, 5, 6, 7, 0).and_prec(0)).to_string(),
+            "2012-03-04 05:06:07"
+        );
+        assert_eq!(
+            ColumnValue::IntervalDS(IntervalDS::new(1, 2, 3, 4, 0).and_prec(2, 0)).to_string(),
+            "+01 02:03:04"
+        );
+        assert_eq!(
+            ColumnValue::IntervalYM(IntervalYM::new(1, 2).and_prec(2)).to_string(),
+            "+01-02"
+        );
+        assert_eq!(ColumnValue::Boolean(true).to_string(), "true");
+        assert_eq!(ColumnValue::Other("xyz".into()).to_string(), "xyz");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn to_json() {
+        use serde_json::json;
+        assert_eq!(ColumnValue::Null.to_json(), json!(null));
+        assert_eq!(ColumnValue::Number("123".into()).to_json(), json!(123));
+        assert_eq!(ColumnValue::Number("-1.5".into()).to_json(), json!(-1.5));
+        assert_eq!(
+            ColumnValue::Number("18446744073709551615".into()).to_json(),
+            json!(18446744073709551615u64)
+        );
+        // precision which f64 cannot hold
+        assert_eq!(
+            ColumnValue::Number("123.4500000000000000001".into()).to_json(),
+            json!("123.4500000000000000001")
+        );
+        assert_eq!(ColumnValue::BinaryDouble(0.25).to_json(), json!(0.25));
+        assert_eq!(
+            ColumnValue::BinaryDouble(f64::INFINITY).to_json(),
+            json!("inf")
+        );
+        assert_eq!(ColumnValue::String("foo".into()).to_json(), json!("foo"));
+        assert_eq!(ColumnValue::Raw(vec![]).to_json(), json!(""));
+        assert_eq!(ColumnValue::Raw(b"f".to_vec()).to_json(), json!("Zg=="));
+        assert_eq!(ColumnValue::Raw(b"fo".to_vec()).to_json(), json!("Zm8="));
+        assert_eq!(ColumnValue::Raw(b"foo".to_vec()).to_json(), json!("Zm9v"));
+        assert_eq!(
+            ColumnValue::Raw(b"foobar".to_vec()).to_json(),
+            json!("Zm9vYmFy")
+        );
+        assert_eq!(
+            ColumnValue::Timestamp(Timestamp::new(2012, 3, 4, 5, 6, 7, 0).and_prec(0)).to_json(),
+            json!("2012-03-04 05:06:07")
+        );
+        assert_eq!(
+            ColumnValue::IntervalDS(IntervalDS::new(1, 2, 3, 4, 0).and_prec(2, 0)).to_json(),
+            json!("+01 02:03:04")
+        );
+        assert_eq!(
+            ColumnValue::IntervalYM(IntervalYM::new(1, 2).and_prec(2)).to_json(),
+            json!("+01-02")
+        );
+        assert_eq!(ColumnValue::Boolean(false).to_json(), json!(false));
+        assert_eq!(ColumnValue::Other("xyz".into()).to_json(), json!("xyz"));
+    }
+}
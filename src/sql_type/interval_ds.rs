@@ -14,21 +14,33 @@
 //-----------------------------------------------------------------------------
 
 use std::cmp::{self, Ordering};
-use std::fmt;
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::iter::Sum;
+use std::ops::{Add, Sub};
 use std::str;
 
 use crate::binding::dpiIntervalDS;
 use crate::sql_type::OracleType;
 use crate::util::Scanner;
+use crate::Error;
 use crate::ParseOracleTypeError;
 
+const NANOSECS_PER_SEC: i128 = 1_000_000_000;
+const NANOSECS_PER_MIN: i128 = 60 * NANOSECS_PER_SEC;
+const NANOSECS_PER_HOUR: i128 = 60 * NANOSECS_PER_MIN;
+const NANOSECS_PER_DAY: i128 = 24 * NANOSECS_PER_HOUR;
+const MAX_DAYS: i128 = 999_999_999;
+const MAX_TOTAL_NANOSECS: i128 = (MAX_DAYS + 1) * NANOSECS_PER_DAY - 1;
+
 /// Oracle-specific [Interval Day to Second][INTVL_DS] data type.
 ///
 /// [INTVL_DS]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-FD8C41B7-8CDC-4D02-8E6B-5250416BC17D
 ///
-/// This struct doesn't have arithmetic methods and they won't be added to avoid
-/// reinventing the wheel. If you need methods such as adding an interval to a
-/// timestamp, enable `chrono` feature and use [chrono::Duration][] instead.
+/// This struct supports only addition and subtraction of intervals and
+/// summation by [`Iterator::sum`]. Other arithmetic methods won't be added to
+/// avoid reinventing the wheel. If you need methods such as adding an interval
+/// to a timestamp, enable `chrono` feature and use [chrono::Duration][] instead.
 ///
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
 ///
@@ -117,6 +129,16 @@ impl IntervalDS {
         }
     }
 
+    pub(crate) fn to_dpi_interval_ds(self) -> dpiIntervalDS {
+        dpiIntervalDS {
+            days: self.days,
+            hours: self.hours,
+            minutes: self.minutes,
+            seconds: self.seconds,
+            fseconds: self.nanoseconds,
+        }
+    }
+
     /// Creates a new IntervalDS.
     ///
     /// Valid values are:
@@ -143,6 +165,59 @@ impl IntervalDS {
         }
     }
 
+    /// Creates a new IntervalDS from non-negative magnitudes and a direction.
+    ///
+    /// The sign is applied to all components uniformly so that intervals with
+    /// mixed signs aren't created. Valid values are:
+    ///
+    /// | argument | valid values |
+    /// |---|---|
+    /// | `days` | 0 to 999999999 |
+    /// | `hours` | 0 to 23 |
+    /// | `minutes` | 0 to 59 |
+    /// | `seconds` | 0 to 59 |
+    /// | `nanoseconds` | 0 to 999999999 |
+    ///
+    /// This returns [`Error::OutOfRange`] when any argument is out of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// // 2 days 3 hours, negative
+    /// let it = IntervalDS::from_magnitude(2, 3, 0, 0, 0, true)?;
+    /// assert_eq!(it, IntervalDS::new(-2, -3, 0, 0, 0));
+    ///
+    /// assert!(IntervalDS::from_magnitude(2, -3, 0, 0, 0, false).is_err());
+    /// # Ok::<(), oracle::Error>(())
+    /// ```
+    pub fn from_magnitude(
+        days: i32,
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+        nanoseconds: i32,
+        negative: bool,
+    ) -> Result<IntervalDS, Error> {
+        let check = |name: &str, value: i32, max: i32| {
+            if (0..=max).contains(&value) {
+                Ok(if negative { -value } else { value })
+            } else {
+                Err(Error::OutOfRange(format!(
+                    "{} must be between 0 and {}: {}",
+                    name, max, value
+                )))
+            }
+        };
+        Ok(IntervalDS::new(
+            check("days", days, 999999999)?,
+            check("hours", hours, 23)?,
+            check("minutes", minutes, 59)?,
+            check("seconds", seconds, 59)?,
+            check("nanoseconds", nanoseconds, 999999999)?,
+        ))
+    }
+
     /// Creates a new IntervalDS with precisions.
     ///
     /// `lfprec` and `fsprec` are leading field precision and fractional second
@@ -191,70 +266,240 @@ impl IntervalDS {
     pub fn fsprec(&self) -> u8 {
         self.fsprec
     }
-}
 
-impl cmp::PartialEq for IntervalDS {
-    fn eq(&self, other: &Self) -> bool {
-        self.days == other.days
-            && self.hours == other.hours
-            && self.minutes == other.minutes
-            && self.seconds == other.seconds
-            && self.nanoseconds == other.nanoseconds
+    /// Returns a copy whose components are within their valid ranges.
+    ///
+    /// Out-of-range components are carried into larger units so that hours
+    /// are between -23 and 23, minutes and seconds are between -59 and 59 and
+    /// all components have the same sign. The precisions are kept.
+    /// When the total is out of the range of Oracle intervals, it saturates
+    /// as [`Add`] does.
+    ///
+    /// [`Display`](fmt::Display) and comparison use the normalized value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let it = IntervalDS::new(0, 25, 70, 0, 0).normalized();
+    /// assert_eq!(it.days(), 1);
+    /// assert_eq!(it.hours(), 2);
+    /// assert_eq!(it.minutes(), 10);
+    ///
+    /// let it = IntervalDS::new(1, -1, 0, 0, 0).normalized();
+    /// assert_eq!(it.days(), 0);
+    /// assert_eq!(it.hours(), 23);
+    /// ```
+    pub fn normalized(&self) -> IntervalDS {
+        IntervalDS::from_total_nanoseconds_saturating(
+            self.total_nanoseconds(),
+            self.lfprec,
+            self.fsprec,
+        )
     }
-}
 
-impl fmt::Display for IntervalDS {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.days < 0
-            || self.hours < 0
-            || self.minutes < 0
-            || self.seconds < 0
-            || self.nanoseconds < 0
-        {
-            write!(f, "-")?;
+    /// Splits the interval into whole days and the time of day.
+    ///
+    /// The second element has zero days and the remaining hours, minutes,
+    /// seconds and nanoseconds. Both parts have the same sign as the
+    /// interval. Out-of-range components are normalized as [`normalized`](#method.normalized)
+    /// does before splitting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let (days, time) = IntervalDS::new(3, 4, 5, 6, 7).split_days();
+    /// assert_eq!(days, 3);
+    /// assert_eq!(time, IntervalDS::new(0, 4, 5, 6, 7));
+    ///
+    /// let (days, time) = IntervalDS::new(-1, -12, 0, 0, 0).split_days();
+    /// assert_eq!(days, -1);
+    /// assert_eq!(time, IntervalDS::new(0, -12, 0, 0, 0));
+    /// ```
+    pub fn split_days(&self) -> (i64, IntervalDS) {
+        let it = self.normalized();
+        (it.days as i64, IntervalDS { days: 0, ..it })
+    }
+
+    /// Adds two intervals and returns `None` when the result is out of the range
+    /// of Oracle intervals, `-999999999 23:59:59.999999999` to `+999999999 23:59:59.999999999`.
+    ///
+    /// The result is normalized as [`Add`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+    /// let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+    /// assert_eq!(max.checked_add(&IntervalDS::new(-1, 0, 0, 0, 0)), Some(IntervalDS::new(999999998, 23, 59, 59, 999999999)));
+    /// assert_eq!(max.checked_add(&one_day), None);
+    /// ```
+    pub fn checked_add(&self, other: &IntervalDS) -> Option<IntervalDS> {
+        let nsecs = self.total_nanoseconds() + other.total_nanoseconds();
+        if (-MAX_TOTAL_NANOSECS..=MAX_TOTAL_NANOSECS).contains(&nsecs) {
+            Some(IntervalDS::from_total_nanoseconds_saturating(
+                nsecs,
+                cmp::max(self.lfprec, other.lfprec),
+                cmp::max(self.fsprec, other.fsprec),
+            ))
         } else {
-            write!(f, "+")?;
-        };
-        let days = self.days.abs();
-        match self.lfprec {
-            2 => write!(f, "{:02}", days)?,
-            3 => write!(f, "{:03}", days)?,
-            4 => write!(f, "{:04}", days)?,
-            5 => write!(f, "{:05}", days)?,
-            6 => write!(f, "{:06}", days)?,
-            7 => write!(f, "{:07}", days)?,
-            8 => write!(f, "{:08}", days)?,
-            9 => write!(f, "{:09}", days)?,
-            _ => write!(f, "{}", days)?,
-        };
-        write!(
-            f,
-            " {:02}:{:02}:{:02}",
-            self.hours.abs(),
-            self.minutes.abs(),
-            self.seconds.abs()
-        )?;
-        let nsec = self.nanoseconds.abs();
-        match self.fsprec {
-            1 => write!(f, ".{:01}", nsec / 100000000),
-            2 => write!(f, ".{:02}", nsec / 10000000),
-            3 => write!(f, ".{:03}", nsec / 1000000),
-            4 => write!(f, ".{:04}", nsec / 100000),
-            5 => write!(f, ".{:05}", nsec / 10000),
-            6 => write!(f, ".{:06}", nsec / 1000),
-            7 => write!(f, ".{:07}", nsec / 100),
-            8 => write!(f, ".{:08}", nsec / 10),
-            9 => write!(f, ".{:09}", nsec),
-            _ => Ok(()),
+            None
         }
     }
-}
 
-impl str::FromStr for IntervalDS {
-    type Err = ParseOracleTypeError;
+    /// Adds two intervals, saturating at the bounds of Oracle intervals
+    /// instead of overflowing.
+    ///
+    /// This is same with the [`Add`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+    /// let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+    /// assert_eq!(max.saturating_add(&one_day), max);
+    /// ```
+    pub fn saturating_add(&self, other: &IntervalDS) -> IntervalDS {
+        IntervalDS::from_total_nanoseconds_saturating(
+            self.total_nanoseconds() + other.total_nanoseconds(),
+            cmp::max(self.lfprec, other.lfprec),
+            cmp::max(self.fsprec, other.fsprec),
+        )
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || ParseOracleTypeError::new("IntervalDS");
+    /// Subtracts `other` from `self` and returns `None` when the result is out of
+    /// the range of Oracle intervals, `-999999999 23:59:59.999999999` to
+    /// `+999999999 23:59:59.999999999`.
+    ///
+    /// The result is normalized as [`Sub`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+    /// let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+    /// assert_eq!(min.checked_sub(&IntervalDS::new(-1, 0, 0, 0, 0)), Some(IntervalDS::new(-999999998, -23, -59, -59, -999999999)));
+    /// assert_eq!(min.checked_sub(&one_day), None);
+    /// ```
+    pub fn checked_sub(&self, other: &IntervalDS) -> Option<IntervalDS> {
+        let nsecs = self.total_nanoseconds() - other.total_nanoseconds();
+        if (-MAX_TOTAL_NANOSECS..=MAX_TOTAL_NANOSECS).contains(&nsecs) {
+            Some(IntervalDS::from_total_nanoseconds_saturating(
+                nsecs,
+                cmp::max(self.lfprec, other.lfprec),
+                cmp::max(self.fsprec, other.fsprec),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts `other` from `self`, saturating at the bounds of Oracle intervals
+    /// instead of overflowing.
+    ///
+    /// This is same with the [`Sub`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+    /// let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+    /// assert_eq!(min.saturating_sub(&one_day), min);
+    /// ```
+    pub fn saturating_sub(&self, other: &IntervalDS) -> IntervalDS {
+        IntervalDS::from_total_nanoseconds_saturating(
+            self.total_nanoseconds() - other.total_nanoseconds(),
+            cmp::max(self.lfprec, other.lfprec),
+            cmp::max(self.fsprec, other.fsprec),
+        )
+    }
+
+    /// Returns a human-readable representation such as
+    /// `"1 day, 2 hours, 3 minutes, 4.5 seconds"`, which is suitable for log output.
+    ///
+    /// Zero components are omitted and sub-second parts are rendered as fractional
+    /// seconds. Negative intervals are prefixed with `"negative "`.
+    /// A zero interval is rendered as `"0 seconds"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let it = IntervalDS::new(1, 2, 3, 4, 500000000);
+    /// assert_eq!(it.format_pretty(), "1 day, 2 hours, 3 minutes, 4.5 seconds");
+    ///
+    /// let it = IntervalDS::new(0, -2, 0, 0, 0);
+    /// assert_eq!(it.format_pretty(), "negative 2 hours");
+    /// ```
+    pub fn format_pretty(&self) -> String {
+        fn unit(parts: &mut Vec<String>, val: i32, name: &str) {
+            match val.abs() {
+                0 => (),
+                1 => parts.push(format!("1 {}", name)),
+                n => parts.push(format!("{} {}s", n, name)),
+            }
+        }
+        let it = self.normalized();
+        let mut parts = Vec::new();
+        unit(&mut parts, it.days, "day");
+        unit(&mut parts, it.hours, "hour");
+        unit(&mut parts, it.minutes, "minute");
+        let nsec = it.nanoseconds.abs();
+        if nsec != 0 {
+            let frac = format!("{:09}", nsec);
+            parts.push(format!(
+                "{}.{} seconds",
+                it.seconds.abs(),
+                frac.trim_end_matches('0')
+            ));
+        } else {
+            unit(&mut parts, it.seconds, "second");
+        }
+        if parts.is_empty() {
+            return "0 seconds".to_string();
+        }
+        let s = parts.join(", ");
+        if it.is_negative() {
+            format!("negative {}", s)
+        } else {
+            s
+        }
+    }
+
+    /// Parses a string more leniently than [`FromStr`](str::FromStr).
+    ///
+    /// In addition to the strict format `[+-]D HH:MI:SS[.FF]`, this accepts
+    /// strings without seconds such as `1 02:03` and strings without days
+    /// such as `02:03:04` and `02:03`. Omitted seconds and days are zero.
+    /// When days are omitted, the leading field precision is 2, which is
+    /// the default of Oracle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::IntervalDS;
+    /// let it = IntervalDS::parse_lenient("1 02:03")?;
+    /// assert_eq!(it, IntervalDS::new(1, 2, 3, 0, 0));
+    ///
+    /// let it = IntervalDS::parse_lenient("-02:03:04.5")?;
+    /// assert_eq!(it, IntervalDS::new(0, -2, -3, -4, -500000000));
+    ///
+    /// assert!("02:03:04".parse::<IntervalDS>().is_err());
+    /// # Ok::<(), oracle::ParseOracleTypeError>(())
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<IntervalDS, ParseOracleTypeError> {
+        IntervalDS::parse(s, true)
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<IntervalDS, ParseOracleTypeError> {
+        let err = |s: &Scanner, expected| {
+            ParseOracleTypeError::with_position("IntervalDS", s.position(), expected)
+        };
         let mut s = Scanner::new(s);
         let minus = match s.char() {
             Some('+') => {
@@ -267,44 +512,64 @@ impl str::FromStr for IntervalDS {
             }
             _ => false,
         };
-        let days = s.read_digits().ok_or_else(err)? as i32;
-        let lfprec = s.ndigits();
-        if let Some(' ') = s.char() {
-            s.next();
-        } else {
-            return Err(err());
-        }
-        let hours = s.read_digits().ok_or_else(err)? as i32;
-        if let Some(':') = s.char() {
-            s.next();
+        let (days, lfprec, hours) = if lenient {
+            let num = s.read_digits().ok_or_else(|| err(&s, "days or hours"))? as i32;
+            let ndigits = s.ndigits();
+            match s.char() {
+                Some(' ') => {
+                    s.next();
+                    let hours = s.read_digits().ok_or_else(|| err(&s, "hours"))? as i32;
+                    (num, ndigits, hours)
+                }
+                Some(':') => (0, 2, num),
+                _ => return Err(err(&s, "' ' or ':'")),
+            }
         } else {
-            return Err(err());
-        }
-        let minutes = s.read_digits().ok_or_else(err)? as i32;
+            let days = s.read_digits().ok_or_else(|| err(&s, "days"))? as i32;
+            let lfprec = s.ndigits();
+            if let Some(' ') = s.char() {
+                s.next();
+            } else {
+                return Err(err(&s, "' '"));
+            }
+            let hours = s.read_digits().ok_or_else(|| err(&s, "hours"))? as i32;
+            (days, lfprec, hours)
+        };
         if let Some(':') = s.char() {
             s.next();
         } else {
-            return Err(err());
+            return Err(err(&s, "':'"));
         }
-        let seconds = s.read_digits().ok_or_else(err)? as i32;
+        let minutes = s.read_digits().ok_or_else(|| err(&s, "minutes"))? as i32;
+        let mut seconds = 0;
         let mut nsecs = 0;
         let mut fsprec = 0;
-        if let Some('.') = s.char() {
-            s.next();
-            nsecs = s.read_digits().ok_or_else(err)? as i32;
-            let ndigit = s.ndigits();
-            fsprec = ndigit;
-            match ndigit.cmp(&9) {
-                Ordering::Less => nsecs *= 10i32.pow(9 - ndigit),
-                Ordering::Equal => (),
-                Ordering::Greater => {
-                    nsecs /= 10i32.pow(ndigit - 9);
-                    fsprec = 9;
+        if !(lenient && s.char().is_none()) {
+            if let Some(':') = s.char() {
+                s.next();
+            } else {
+                return Err(err(&s, "':'"));
+            }
+            seconds = s.read_digits().ok_or_else(|| err(&s, "seconds"))? as i32;
+            if let Some('.') = s.char() {
+                s.next();
+                nsecs = s
+                    .read_digits()
+                    .ok_or_else(|| err(&s, "fractional seconds"))? as i32;
+                let ndigit = s.ndigits();
+                fsprec = ndigit;
+                match ndigit.cmp(&9) {
+                    Ordering::Less => nsecs *= 10i32.pow(9 - ndigit),
+                    Ordering::Equal => (),
+                    Ordering::Greater => {
+                        nsecs /= 10i32.pow(ndigit - 9);
+                        fsprec = 9;
+                    }
                 }
             }
         }
         if s.char().is_some() {
-            return Err(err());
+            return Err(err(&s, "end of string"));
         }
         Ok(IntervalDS {
             days: if minus { -days } else { days },
@@ -316,6 +581,267 @@ impl str::FromStr for IntervalDS {
             fsprec: fsprec as u8,
         })
     }
+
+    fn is_negative(&self) -> bool {
+        self.days < 0
+            || self.hours < 0
+            || self.minutes < 0
+            || self.seconds < 0
+            || self.nanoseconds < 0
+    }
+
+    pub(crate) fn total_nanoseconds(&self) -> i128 {
+        self.days as i128 * NANOSECS_PER_DAY
+            + self.hours as i128 * NANOSECS_PER_HOUR
+            + self.minutes as i128 * NANOSECS_PER_MIN
+            + self.seconds as i128 * NANOSECS_PER_SEC
+            + self.nanoseconds as i128
+    }
+
+    /// Creates a normalized interval from total nanoseconds.
+    /// The value is saturated when it is out of the range of Oracle intervals.
+    fn from_total_nanoseconds_saturating(nsecs: i128, lfprec: u8, fsprec: u8) -> IntervalDS {
+        let nsecs = nsecs.clamp(-MAX_TOTAL_NANOSECS, MAX_TOTAL_NANOSECS);
+        IntervalDS {
+            days: (nsecs / NANOSECS_PER_DAY) as i32,
+            hours: (nsecs % NANOSECS_PER_DAY / NANOSECS_PER_HOUR) as i32,
+            minutes: (nsecs % NANOSECS_PER_HOUR / NANOSECS_PER_MIN) as i32,
+            seconds: (nsecs % NANOSECS_PER_MIN / NANOSECS_PER_SEC) as i32,
+            nanoseconds: (nsecs % NANOSECS_PER_SEC) as i32,
+            lfprec,
+            fsprec,
+        }
+    }
+}
+
+impl cmp::PartialEq for IntervalDS {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized().total_nanoseconds() == other.normalized().total_nanoseconds()
+    }
+}
+
+/// Adds two intervals.
+///
+/// The result is normalized so that hours, minutes, seconds and nanoseconds
+/// are within their valid ranges. The precisions of the result are the larger
+/// ones of the two. When the result is out of the range of Oracle intervals,
+/// it saturates at `+999999999 23:59:59.999999999` or `-999999999 23:59:59.999999999`.
+///
+/// ```
+/// # use oracle::sql_type::IntervalDS;
+/// let intvl = IntervalDS::new(1, 20, 30, 40, 0) + IntervalDS::new(0, 5, 40, 30, 500000000);
+/// assert_eq!(intvl, IntervalDS::new(2, 2, 11, 10, 500000000));
+/// ```
+impl Add for IntervalDS {
+    type Output = IntervalDS;
+
+    fn add(self, other: IntervalDS) -> IntervalDS {
+        self.saturating_add(&other)
+    }
+}
+
+/// Subtracts an interval from another.
+///
+/// The result is normalized and its precisions are decided as [`Add`] does.
+/// When the result is out of the range of Oracle intervals, it saturates at
+/// `+999999999 23:59:59.999999999` or `-999999999 23:59:59.999999999`.
+/// Use [`IntervalDS::checked_sub`] to detect overflow.
+///
+/// ```
+/// # use oracle::sql_type::IntervalDS;
+/// let intvl = IntervalDS::new(2, 2, 11, 10, 500000000) - IntervalDS::new(0, 5, 40, 30, 500000000);
+/// assert_eq!(intvl, IntervalDS::new(1, 20, 30, 40, 0));
+/// ```
+impl Sub for IntervalDS {
+    type Output = IntervalDS;
+
+    fn sub(self, other: IntervalDS) -> IntervalDS {
+        self.saturating_sub(&other)
+    }
+}
+
+/// Sums up intervals.
+///
+/// The sum of an empty iterator is zero. Overflow saturates as [`Add`] does.
+///
+/// ```
+/// # use oracle::sql_type::IntervalDS;
+/// let intervals = vec![
+///     IntervalDS::new(0, 10, 0, 0, 0),
+///     IntervalDS::new(0, 10, 0, 0, 0),
+///     IntervalDS::new(0, 10, 0, 0, 0),
+/// ];
+/// let total = intervals.iter().copied().sum::<IntervalDS>();
+/// assert_eq!(total, IntervalDS::new(1, 6, 0, 0, 0));
+/// ```
+impl Sum for IntervalDS {
+    fn sum<I>(iter: I) -> IntervalDS
+    where
+        I: Iterator<Item = IntervalDS>,
+    {
+        iter.fold(IntervalDS::new(0, 0, 0, 0, 0).and_prec(0, 0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a IntervalDS> for IntervalDS {
+    fn sum<I>(iter: I) -> IntervalDS
+    where
+        I: Iterator<Item = &'a IntervalDS>,
+    {
+        iter.copied().sum()
+    }
+}
+
+/// Creates an interval from a pair of total seconds and a sub-second
+/// nanosecond count, which is the representation used by [`std::time::Duration`].
+///
+/// The interval is `seconds + nanoseconds / 1_000_000_000` seconds. So a negative
+/// interval such as -1.5 seconds is `(-2, 500_000_000)`.
+/// The nanosecond count must be less than 1,000,000,000.
+/// An error is returned when it isn't or the result is out of the range of
+/// Oracle intervals, `-999999999 23:59:59.999999999` to `+999999999 23:59:59.999999999`.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::sql_type::IntervalDS;
+/// # use std::convert::TryFrom;
+/// let it = IntervalDS::try_from((93784, 500_000_000))?;
+/// assert_eq!(it, IntervalDS::new(1, 2, 3, 4, 500_000_000));
+///
+/// let it = IntervalDS::try_from((-2, 500_000_000))?;
+/// assert_eq!(it, IntervalDS::new(0, 0, 0, -1, -500_000_000));
+/// # Ok::<(), oracle::Error>(())
+/// ```
+impl TryFrom<(i64, u32)> for IntervalDS {
+    type Error = Error;
+
+    fn try_from((seconds, nanoseconds): (i64, u32)) -> Result<IntervalDS, Error> {
+        if nanoseconds as i128 >= NANOSECS_PER_SEC {
+            return Err(Error::OutOfRange(format!(
+                "too large nanoseconds {} for IntervalDS",
+                nanoseconds
+            )));
+        }
+        let nsecs = seconds as i128 * NANOSECS_PER_SEC + nanoseconds as i128;
+        if !(-MAX_TOTAL_NANOSECS..=MAX_TOTAL_NANOSECS).contains(&nsecs) {
+            return Err(Error::OutOfRange(format!(
+                "({}, {}) is out of the range of IntervalDS",
+                seconds, nanoseconds
+            )));
+        }
+        Ok(IntervalDS::from_total_nanoseconds_saturating(nsecs, 9, 9))
+    }
+}
+
+/// Formats the interval in the Oracle format such as `+000000001 02:03:04.500000000`.
+///
+/// The alternate flag `{:#}` formats it in a compact form such as `1 02:03:04.5`.
+/// The `+` sign, leading zeros of days and trailing zeros of fractional seconds
+/// are dropped.
+///
+/// Width, fill and alignment such as `{:>20}` are applied to the whole output.
+/// Precision is ignored.
+impl fmt::Display for IntervalDS {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let it = self.normalized();
+        // Formatted into a string first to respect width, fill and alignment.
+        let mut s = String::new();
+        if f.alternate() {
+            it.write_compact(&mut s)?;
+        } else {
+            it.write_sql(&mut s)?;
+        }
+        pad(f, &s)
+    }
+}
+
+// Same with `f.pad()` except that precision is ignored instead of truncating `s`.
+fn pad(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl IntervalDS {
+    fn write_sql(&self, w: &mut String) -> fmt::Result {
+        if self.is_negative() {
+            write!(w, "-")?;
+        } else {
+            write!(w, "+")?;
+        };
+        let days = self.days.abs();
+        match self.lfprec {
+            2 => write!(w, "{:02}", days)?,
+            3 => write!(w, "{:03}", days)?,
+            4 => write!(w, "{:04}", days)?,
+            5 => write!(w, "{:05}", days)?,
+            6 => write!(w, "{:06}", days)?,
+            7 => write!(w, "{:07}", days)?,
+            8 => write!(w, "{:08}", days)?,
+            9 => write!(w, "{:09}", days)?,
+            _ => write!(w, "{}", days)?,
+        };
+        write!(
+            w,
+            " {:02}:{:02}:{:02}",
+            self.hours.abs(),
+            self.minutes.abs(),
+            self.seconds.abs()
+        )?;
+        let nsec = self.nanoseconds.abs();
+        match self.fsprec {
+            1 => write!(w, ".{:01}", nsec / 100000000),
+            2 => write!(w, ".{:02}", nsec / 10000000),
+            3 => write!(w, ".{:03}", nsec / 1000000),
+            4 => write!(w, ".{:04}", nsec / 100000),
+            5 => write!(w, ".{:05}", nsec / 10000),
+            6 => write!(w, ".{:06}", nsec / 1000),
+            7 => write!(w, ".{:07}", nsec / 100),
+            8 => write!(w, ".{:08}", nsec / 10),
+            9 => write!(w, ".{:09}", nsec),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_compact(&self, w: &mut String) -> fmt::Result {
+        write!(
+            w,
+            "{}{} {:02}:{:02}:{:02}",
+            if self.is_negative() { "-" } else { "" },
+            self.days.abs(),
+            self.hours.abs(),
+            self.minutes.abs(),
+            self.seconds.abs()
+        )?;
+        let fsprec = cmp::min(self.fsprec, 9) as usize;
+        let frac = format!("{:09}", self.nanoseconds.abs());
+        let frac = frac[..fsprec].trim_end_matches('0');
+        if !frac.is_empty() {
+            write!(w, ".{}", frac)?;
+        }
+        Ok(())
+    }
+}
+
+impl str::FromStr for IntervalDS {
+    type Err = ParseOracleTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IntervalDS::parse(s, false)
+    }
 }
 
 #[cfg(test)]
@@ -390,6 +916,414 @@ mod tests {
         assert_eq!(it.to_string(), "-000000001 02:03:04.123456789");
     }
 
+    #[test]
+    fn to_string_alternate() {
+        let it = IntervalDS::new(1, 2, 3, 4, 500000000);
+        assert_eq!(format!("{}", it), "+000000001 02:03:04.500000000");
+        assert_eq!(format!("{:#}", it), "1 02:03:04.5");
+
+        let it = IntervalDS::new(-1, -2, -3, -4, -500000000);
+        assert_eq!(format!("{}", it), "-000000001 02:03:04.500000000");
+        assert_eq!(format!("{:#}", it), "-1 02:03:04.5");
+
+        let it = IntervalDS::new(123, 0, 0, 5, 0).and_prec(3, 6);
+        assert_eq!(format!("{}", it), "+123 00:00:05.000000");
+        assert_eq!(format!("{:#}", it), "123 00:00:05");
+
+        // digits beyond the fractional second precision aren't displayed.
+        let it = IntervalDS::new(0, 0, 0, 0, 120456789).and_prec(2, 3);
+        assert_eq!(format!("{}", it), "+00 00:00:00.120");
+        assert_eq!(format!("{:#}", it), "0 00:00:00.12");
+
+        let it = IntervalDS::new(0, 0, 0, 0, 0);
+        assert_eq!(format!("{:#}", it), "0 00:00:00");
+    }
+
+    #[test]
+    fn to_string_padding() {
+        let it = IntervalDS::new(1, 2, 3, 4, 500000000).and_prec(2, 3);
+        assert_eq!(format!("{:>20}", it), "    +01 02:03:04.500");
+        assert_eq!(format!("{:<20}|", it), "+01 02:03:04.500    |");
+        assert_eq!(format!("{:*^20}", it), "**+01 02:03:04.500**");
+        assert_eq!(format!("{:>#20}", it), "        1 02:03:04.5");
+        // shorter than the output
+        assert_eq!(format!("{:>5}", it), "+01 02:03:04.500");
+        // precision is ignored
+        assert_eq!(format!("{:.3}", it), "+01 02:03:04.500");
+        assert_eq!(format!("{:>20.3}", it), "    +01 02:03:04.500");
+    }
+
+    #[test]
+    fn add() {
+        let it = IntervalDS::new(1, 23, 59, 59, 999999999) + IntervalDS::new(0, 0, 0, 0, 1);
+        assert_eq!(it, IntervalDS::new(2, 0, 0, 0, 0));
+        let it = IntervalDS::new(1, 0, 0, 0, 0) + IntervalDS::new(-2, -3, 0, 0, 0);
+        assert_eq!(it, IntervalDS::new(-1, -3, 0, 0, 0));
+        let it = IntervalDS::new(1, 2, 3, 4, 5).and_prec(2, 3)
+            + IntervalDS::new(-1, -2, -3, -4, -5).and_prec(4, 1);
+        assert_eq!(it, IntervalDS::new(0, 0, 0, 0, 0));
+        assert_eq!(it.lfprec(), 4);
+        assert_eq!(it.fsprec(), 3);
+
+        // saturation
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+        let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+        assert_eq!(max + IntervalDS::new(0, 0, 0, 0, 1), max);
+        assert_eq!(max + max, max);
+        assert_eq!(min + IntervalDS::new(0, 0, 0, 0, -1), min);
+        assert_eq!(min + min, min);
+    }
+
+    #[test]
+    fn sum() {
+        let intervals = [
+            IntervalDS::new(1, 2, 3, 4, 500000000),
+            IntervalDS::new(0, 23, 59, 59, 600000000),
+            IntervalDS::new(-3, 0, -30, 0, 0),
+            IntervalDS::new(10, 10, 10, 10, 10),
+        ];
+        let expected = intervals
+            .iter()
+            .fold(IntervalDS::new(0, 0, 0, 0, 0), |acc, x| acc + *x);
+        assert_eq!(intervals.iter().copied().sum::<IntervalDS>(), expected);
+        assert_eq!(intervals.iter().sum::<IntervalDS>(), expected);
+        assert_eq!(expected, IntervalDS::new(9, 11, 43, 14, 100000010));
+
+        let empty: Vec<IntervalDS> = vec![];
+        assert_eq!(
+            empty.iter().sum::<IntervalDS>(),
+            IntervalDS::new(0, 0, 0, 0, 0)
+        );
+
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+        let saturated = [max, max, IntervalDS::new(-1, 0, 0, 0, 0)];
+        assert_eq!(
+            saturated.iter().sum::<IntervalDS>(),
+            IntervalDS::new(999999998, 23, 59, 59, 999999999)
+        );
+    }
+
+    #[test]
+    fn checked_add() {
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+        let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+        let minus_one_day = IntervalDS::new(-1, 0, 0, 0, 0);
+        let minus_one_nsec = IntervalDS::new(0, 0, 0, 0, -1);
+        let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+        let one_nsec = IntervalDS::new(0, 0, 0, 0, 1);
+
+        // positive boundary
+        assert_eq!(
+            IntervalDS::new(999999998, 23, 59, 59, 999999999).checked_add(&one_day),
+            Some(max)
+        );
+        assert_eq!(max.checked_add(&one_nsec), None);
+        assert_eq!(max.checked_add(&one_day), None);
+        assert_eq!(max.checked_add(&max), None);
+        assert_eq!(
+            max.checked_add(&minus_one_nsec),
+            Some(IntervalDS::new(999999999, 23, 59, 59, 999999998))
+        );
+
+        // negative boundary
+        assert_eq!(
+            IntervalDS::new(-999999998, -23, -59, -59, -999999999).checked_add(&minus_one_day),
+            Some(min)
+        );
+        assert_eq!(min.checked_add(&minus_one_nsec), None);
+        assert_eq!(min.checked_add(&min), None);
+        assert_eq!(min.checked_add(&max), Some(IntervalDS::new(0, 0, 0, 0, 0)));
+
+        // normalized
+        assert_eq!(
+            IntervalDS::new(0, 23, 59, 59, 999999999).checked_add(&one_nsec),
+            Some(one_day)
+        );
+    }
+
+    #[test]
+    fn saturating_add() {
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+        let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+        let minus_one_day = IntervalDS::new(-1, 0, 0, 0, 0);
+        let minus_one_nsec = IntervalDS::new(0, 0, 0, 0, -1);
+        let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+        let one_nsec = IntervalDS::new(0, 0, 0, 0, 1);
+
+        // positive boundary
+        assert_eq!(max.saturating_add(&one_nsec), max);
+        assert_eq!(max.saturating_add(&max), max);
+        assert_eq!(
+            IntervalDS::new(999999998, 23, 59, 59, 999999999).saturating_add(&one_day),
+            max
+        );
+        assert_eq!(
+            max.saturating_add(&minus_one_day),
+            IntervalDS::new(999999998, 23, 59, 59, 999999999)
+        );
+
+        // negative boundary
+        assert_eq!(min.saturating_add(&minus_one_nsec), min);
+        assert_eq!(min.saturating_add(&min), min);
+        assert_eq!(
+            min.saturating_add(&one_day),
+            IntervalDS::new(-999999998, -23, -59, -59, -999999999)
+        );
+
+        // precisions
+        let it = IntervalDS::new(1, 0, 0, 0, 0).and_prec(2, 3);
+        let it = it.saturating_add(&IntervalDS::new(1, 0, 0, 0, 0).and_prec(4, 1));
+        assert_eq!((it.lfprec(), it.fsprec()), (4, 3));
+    }
+
+    #[test]
+    fn checked_sub() {
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+        let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+        let minus_one_day = IntervalDS::new(-1, 0, 0, 0, 0);
+        let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+        let one_nsec = IntervalDS::new(0, 0, 0, 0, 1);
+
+        // positive boundary
+        assert_eq!(
+            IntervalDS::new(999999998, 23, 59, 59, 999999999).checked_sub(&minus_one_day),
+            Some(max)
+        );
+        assert_eq!(max.checked_sub(&minus_one_day), None);
+        assert_eq!(max.checked_sub(&min), None);
+        assert_eq!(max.checked_sub(&max), Some(IntervalDS::new(0, 0, 0, 0, 0)));
+
+        // negative boundary
+        assert_eq!(
+            IntervalDS::new(-999999998, -23, -59, -59, -999999999).checked_sub(&one_day),
+            Some(min)
+        );
+        assert_eq!(min.checked_sub(&one_nsec), None);
+        assert_eq!(min.checked_sub(&max), None);
+        assert_eq!(min.checked_sub(&min), Some(IntervalDS::new(0, 0, 0, 0, 0)));
+
+        // normalized
+        assert_eq!(
+            one_day.checked_sub(&one_nsec),
+            Some(IntervalDS::new(0, 23, 59, 59, 999999999))
+        );
+    }
+
+    #[test]
+    fn sub() {
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999);
+        let min = IntervalDS::new(-999999999, -23, -59, -59, -999999999);
+        let one_day = IntervalDS::new(1, 0, 0, 0, 0);
+        let one_nsec = IntervalDS::new(0, 0, 0, 0, 1);
+
+        // saturating at the boundaries
+        assert_eq!(max - min, max);
+        assert_eq!(min - max, min);
+        assert_eq!(min - one_nsec, min);
+        assert_eq!(max.saturating_sub(&min), max);
+        assert_eq!(min.saturating_sub(&one_day), min);
+        assert_eq!(
+            max - one_day,
+            IntervalDS::new(999999998, 23, 59, 59, 999999999)
+        );
+        // consistent with checked_sub when it doesn't overflow
+        assert_eq!(Some(max - max), max.checked_sub(&max));
+
+        // precisions
+        let it = IntervalDS::new(1, 0, 0, 0, 0).and_prec(2, 3)
+            - IntervalDS::new(1, 0, 0, 0, 0).and_prec(4, 1);
+        assert_eq!((it.lfprec(), it.fsprec()), (4, 3));
+    }
+
+    #[test]
+    fn try_from_seconds_and_nanoseconds() {
+        assert_eq!(
+            IntervalDS::try_from((0, 0)).unwrap(),
+            IntervalDS::new(0, 0, 0, 0, 0)
+        );
+        // positive
+        assert_eq!(
+            IntervalDS::try_from((93784, 500_000_000)).unwrap(),
+            IntervalDS::new(1, 2, 3, 4, 500_000_000)
+        );
+        assert_eq!(
+            IntervalDS::try_from((0, 1)).unwrap(),
+            IntervalDS::new(0, 0, 0, 0, 1)
+        );
+        // negative
+        assert_eq!(
+            IntervalDS::try_from((-93785, 500_000_000)).unwrap(),
+            IntervalDS::new(-1, -2, -3, -4, -500_000_000)
+        );
+        assert_eq!(
+            IntervalDS::try_from((-1, 0)).unwrap(),
+            IntervalDS::new(0, 0, 0, -1, 0)
+        );
+        assert_eq!(
+            IntervalDS::try_from((-1, 999_999_999)).unwrap(),
+            IntervalDS::new(0, 0, 0, 0, -1)
+        );
+        // boundaries
+        let max_secs = 999999999 * 86400 + 86399;
+        assert_eq!(
+            IntervalDS::try_from((max_secs, 999_999_999)).unwrap(),
+            IntervalDS::new(999999999, 23, 59, 59, 999999999)
+        );
+        assert_eq!(
+            IntervalDS::try_from((-max_secs - 1, 1)).unwrap(),
+            IntervalDS::new(-999999999, -23, -59, -59, -999999999)
+        );
+        // overflow
+        assert!(matches!(
+            IntervalDS::try_from((max_secs + 1, 0)),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalDS::try_from((-max_secs - 1, 0)),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalDS::try_from((i64::MAX, 0)),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalDS::try_from((0, 1_000_000_000)),
+            Err(Error::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn dpi_interval_ds_round_trip() {
+        let oratype = OracleType::IntervalDS(9, 9);
+        for it in &[
+            IntervalDS::new(0, 0, 0, 0, 0),
+            IntervalDS::new(1, 2, 3, 4, 5),
+            IntervalDS::new(-1, -2, -3, -4, -5),
+            IntervalDS::new(999_999_999, 23, 59, 59, 999_999_999),
+            IntervalDS::new(-999_999_999, -23, -59, -59, -999_999_999),
+        ] {
+            let it = it.and_prec(9, 9);
+            let dpi = it.to_dpi_interval_ds();
+            assert_eq!(
+                (dpi.days, dpi.hours, dpi.minutes, dpi.seconds, dpi.fseconds),
+                (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds)
+            );
+            let it2 = IntervalDS::from_dpi_interval_ds(&dpi, &oratype);
+            assert_eq!(
+                (
+                    it2.days,
+                    it2.hours,
+                    it2.minutes,
+                    it2.seconds,
+                    it2.nanoseconds
+                ),
+                (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds)
+            );
+            assert_eq!((it2.lfprec, it2.fsprec), (9, 9));
+        }
+    }
+
+    #[test]
+    fn split_days() {
+        let (days, time) = IntervalDS::new(5, 25, 30, 15, 123).split_days();
+        assert_eq!(days, 6);
+        assert_eq!(
+            (
+                time.days,
+                time.hours,
+                time.minutes,
+                time.seconds,
+                time.nanoseconds
+            ),
+            (0, 1, 30, 15, 123)
+        );
+
+        let (days, time) = IntervalDS::new(-2, -3, -4, -5, -6).split_days();
+        assert_eq!(days, -2);
+        assert_eq!(
+            (
+                time.days,
+                time.hours,
+                time.minutes,
+                time.seconds,
+                time.nanoseconds
+            ),
+            (0, -3, -4, -5, -6)
+        );
+
+        let (days, time) = IntervalDS::new(0, -10, 0, 0, 0).split_days();
+        assert_eq!(days, 0);
+        assert_eq!(time, IntervalDS::new(0, -10, 0, 0, 0));
+    }
+
+    #[test]
+    fn normalized() {
+        let it = IntervalDS::new(0, 0, 70, 0, 0).normalized();
+        assert_eq!(
+            (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds),
+            (0, 1, 10, 0, 0)
+        );
+        let it = IntervalDS::new(0, 47, 59, 59, 1_999_999_999).normalized();
+        assert_eq!(
+            (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds),
+            (2, 0, 0, 0, 999_999_999)
+        );
+        let it = IntervalDS::new(-1, 0, 0, 90, 0).normalized();
+        assert_eq!(
+            (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds),
+            (0, -23, -58, -30, 0)
+        );
+        let it = IntervalDS::new(999_999_999, 24, 0, 0, 0).normalized();
+        assert_eq!(it, IntervalDS::new(999_999_999, 23, 59, 59, 999_999_999));
+
+        // The precisions are kept.
+        let it = IntervalDS::new(0, 0, 70, 0, 0).and_prec(2, 3).normalized();
+        assert_eq!((it.lfprec, it.fsprec), (2, 3));
+
+        // Display and comparison use the normalized value.
+        let it = IntervalDS::new(0, 0, 70, 0, 0).and_prec(2, 0);
+        assert_eq!(it.to_string(), "+00 01:10:00");
+        assert_eq!(it, IntervalDS::new(0, 1, 10, 0, 0));
+        assert_ne!(it, IntervalDS::new(0, 1, 11, 0, 0));
+        assert_eq!(
+            IntervalDS::new(0, 0, 0, 61, 0).format_pretty(),
+            "1 minute, 1 second"
+        );
+    }
+
+    #[test]
+    fn format_pretty() {
+        assert_eq!(IntervalDS::new(0, 0, 0, 0, 0).format_pretty(), "0 seconds");
+        assert_eq!(
+            IntervalDS::new(1, 2, 3, 4, 500000000).format_pretty(),
+            "1 day, 2 hours, 3 minutes, 4.5 seconds"
+        );
+        assert_eq!(
+            IntervalDS::new(2, 1, 0, 0, 0).format_pretty(),
+            "2 days, 1 hour"
+        );
+        assert_eq!(
+            IntervalDS::new(0, 0, 1, 1, 0).format_pretty(),
+            "1 minute, 1 second"
+        );
+        assert_eq!(
+            IntervalDS::new(3, 0, 0, 30, 0).format_pretty(),
+            "3 days, 30 seconds"
+        );
+        assert_eq!(
+            IntervalDS::new(0, 0, 0, 0, 1000).format_pretty(),
+            "0.000001 seconds"
+        );
+        assert_eq!(
+            IntervalDS::new(0, -2, 0, 0, 0).format_pretty(),
+            "negative 2 hours"
+        );
+        assert_eq!(
+            IntervalDS::new(-1, -2, 0, -4, -250000000).format_pretty(),
+            "negative 1 day, 2 hours, 4.25 seconds"
+        );
+    }
+
     #[test]
     fn parse() {
         let mut it = IntervalDS::new(1, 2, 3, 4, 0);
@@ -451,4 +1385,109 @@ mod tests {
         it.nanoseconds = -123456789;
         assert_eq!("-1 02:03:04.123456789".parse(), Ok(it));
     }
+
+    #[test]
+    fn parse_error() {
+        let check = |s: &str, position, expected| {
+            let err = s.parse::<IntervalDS>().unwrap_err();
+            assert_eq!(err.position(), Some(position), "{}", s);
+            assert_eq!(err.expected(), Some(expected), "{}", s);
+        };
+        check("", 0, "days");
+        check("x", 0, "days");
+        check("-", 1, "days");
+        check("1", 1, "' '");
+        check("1 ", 2, "hours");
+        check("1 02-03:04", 4, "':'");
+        check("1 02:03", 7, "':'");
+        check("1 02:03:", 8, "seconds");
+        check("1 02:03:04.", 11, "fractional seconds");
+        check("1 02:03:04 ", 10, "end of string");
+        // multibyte character
+        check("1 02:03:04\u{00e9}", 10, "end of string");
+        check("1\u{00e9}02:03:04", 1, "' '");
+
+        let err = "1 02-03:04".parse::<IntervalDS>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "IntervalDS parse error: expected ':' at position 4"
+        );
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let parse = IntervalDS::parse_lenient;
+        let mut it = IntervalDS::new(1, 2, 3, 0, 0);
+        it.lfprec = 1;
+        it.fsprec = 0;
+        assert_eq!(parse("1 02:03"), Ok(it));
+        assert_eq!(parse("1 2:3"), Ok(it));
+        it.seconds = 4;
+        assert_eq!(parse("1 02:03:04"), Ok(it));
+
+        let mut it = IntervalDS::new(0, 2, 3, 4, 0);
+        it.lfprec = 2;
+        it.fsprec = 0;
+        assert_eq!(parse("02:03:04"), Ok(it));
+        assert_eq!(parse("+2:3:4"), Ok(it));
+        it.fsprec = 1;
+        it.nanoseconds = 500000000;
+        assert_eq!(parse("02:03:04.5"), Ok(it));
+        let mut it = IntervalDS::new(0, -2, -3, 0, 0);
+        it.lfprec = 2;
+        it.fsprec = 0;
+        assert_eq!(parse("-02:03"), Ok(it));
+
+        let check = |s: &str, position, expected| {
+            let err = parse(s).unwrap_err();
+            assert_eq!(err.position(), Some(position), "{}", s);
+            assert_eq!(err.expected(), Some(expected), "{}", s);
+        };
+        check("", 0, "days or hours");
+        check("1", 1, "' ' or ':'");
+        check("1 02", 4, "':'");
+        check("02:", 3, "minutes");
+        check("02:03:", 6, "seconds");
+        check("1 02:03 ", 7, "':'");
+
+        // the strict parser still rejects them.
+        for s in &["1 02:03", "02:03:04", "02:03", "-02:03:04.5"] {
+            assert!(s.parse::<IntervalDS>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_magnitude() {
+        let it = IntervalDS::from_magnitude(2, 3, 4, 5, 6, false).unwrap();
+        assert_eq!(
+            (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds),
+            (2, 3, 4, 5, 6)
+        );
+        let it = IntervalDS::from_magnitude(2, 3, 4, 5, 6, true).unwrap();
+        assert_eq!(
+            (it.days, it.hours, it.minutes, it.seconds, it.nanoseconds),
+            (-2, -3, -4, -5, -6)
+        );
+        let it = IntervalDS::from_magnitude(0, 0, 0, 0, 0, true).unwrap();
+        assert_eq!(it, IntervalDS::new(0, 0, 0, 0, 0));
+
+        // negative magnitudes
+        assert!(matches!(
+            IntervalDS::from_magnitude(-2, 0, 0, 0, 0, false),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalDS::from_magnitude(0, 0, 0, 0, -1, true),
+            Err(Error::OutOfRange(_))
+        ));
+        // too large magnitudes
+        assert!(matches!(
+            IntervalDS::from_magnitude(0, 24, 0, 0, 0, false),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            IntervalDS::from_magnitude(1000000000, 0, 0, 0, 0, false),
+            Err(Error::OutOfRange(_))
+        ));
+    }
 }
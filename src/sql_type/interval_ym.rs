@@ -15,6 +15,8 @@
 
 use std::cmp;
 use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
 use std::str;
 
 use crate::binding::dpiIntervalYM;
@@ -22,6 +24,8 @@ use crate::sql_type::OracleType;
 use crate::util::Scanner;
 use crate::ParseOracleTypeError;
 
+const MAX_TOTAL_MONTHS: i64 = 999_999_999 * 12 + 11;
+
 /// Oracle-specific [Interval Year to Month][INTVL_YM] data type.
 ///
 /// [INTVL_YM]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-517CEB46-C6FA-4B94-9299-5BBB5A58CF7B
@@ -142,6 +146,21 @@ impl IntervalYM {
     pub fn precision(&self) -> u8 {
         self.precision
     }
+
+    fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+
+    /// Creates a normalized interval from total months.
+    /// The value is saturated when it is out of the range of Oracle intervals.
+    fn from_total_months_saturating(months: i64, precision: u8) -> IntervalYM {
+        let months = months.clamp(-MAX_TOTAL_MONTHS, MAX_TOTAL_MONTHS);
+        IntervalYM {
+            years: (months / 12) as i32,
+            months: (months % 12) as i32,
+            precision,
+        }
+    }
 }
 
 impl cmp::PartialEq for IntervalYM {
@@ -150,6 +169,56 @@ impl cmp::PartialEq for IntervalYM {
     }
 }
 
+/// Adds two intervals.
+///
+/// The result is normalized so that months are within -11 to 11.
+/// The precision of the result is the larger one of the two. When the result
+/// is out of the range of Oracle intervals, it saturates at `+999999999-11`
+/// or `-999999999-11`.
+///
+/// ```
+/// # use oracle::sql_type::IntervalYM;
+/// let intvl = IntervalYM::new(1, 10) + IntervalYM::new(0, 5);
+/// assert_eq!(intvl, IntervalYM::new(2, 3));
+/// ```
+impl Add for IntervalYM {
+    type Output = IntervalYM;
+
+    fn add(self, other: IntervalYM) -> IntervalYM {
+        IntervalYM::from_total_months_saturating(
+            self.total_months() + other.total_months(),
+            cmp::max(self.precision, other.precision),
+        )
+    }
+}
+
+/// Sums up intervals.
+///
+/// The sum of an empty iterator is zero. Overflow saturates as [`Add`] does.
+///
+/// ```
+/// # use oracle::sql_type::IntervalYM;
+/// let intervals = vec![IntervalYM::new(0, 7), IntervalYM::new(1, 7)];
+/// assert_eq!(intervals.iter().sum::<IntervalYM>(), IntervalYM::new(2, 2));
+/// ```
+impl Sum for IntervalYM {
+    fn sum<I>(iter: I) -> IntervalYM
+    where
+        I: Iterator<Item = IntervalYM>,
+    {
+        iter.fold(IntervalYM::new(0, 0).and_prec(0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a IntervalYM> for IntervalYM {
+    fn sum<I>(iter: I) -> IntervalYM
+    where
+        I: Iterator<Item = &'a IntervalYM>,
+    {
+        iter.copied().sum()
+    }
+}
+
 impl fmt::Display for IntervalYM {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.years < 0 || self.months < 0 {
@@ -157,7 +226,7 @@ impl fmt::Display for IntervalYM {
         } else {
             write!(f, "+")?;
         }
-        let years = self.years.abs();
+        let years = self.years.unsigned_abs();
         match self.precision {
             2 => write!(f, "{:02}", years)?,
             3 => write!(f, "{:03}", years)?,
@@ -169,7 +238,7 @@ impl fmt::Display for IntervalYM {
             9 => write!(f, "{:09}", years)?,
             _ => write!(f, "{}", years)?,
         };
-        write!(f, "-{:02}", self.months.abs())
+        write!(f, "-{:02}", self.months.unsigned_abs())
     }
 }
 
@@ -177,7 +246,9 @@ impl str::FromStr for IntervalYM {
     type Err = ParseOracleTypeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || ParseOracleTypeError::new("IntervalYM");
+        let err = |s: &Scanner, expected| {
+            ParseOracleTypeError::with_position("IntervalYM", s.position(), expected)
+        };
         let mut s = Scanner::new(s);
         let minus = match s.char() {
             Some('+') => {
@@ -190,16 +261,33 @@ impl str::FromStr for IntervalYM {
             }
             _ => false,
         };
-        let years = s.read_digits().ok_or_else(err)? as i32;
+        let years_pos = s.position();
+        let years = s.read_digits().ok_or_else(|| err(&s, "years"))?;
         let precision = s.ndigits();
+        if precision > 9 || years > 999_999_999 {
+            return Err(ParseOracleTypeError::with_position(
+                "IntervalYM",
+                years_pos,
+                "years up to 9 digits",
+            ));
+        }
         if let Some('-') = s.char() {
             s.next();
         } else {
-            return Err(err());
+            return Err(err(&s, "'-'"));
+        }
+        let months_pos = s.position();
+        let months = s.read_digits().ok_or_else(|| err(&s, "months"))?;
+        if months > 11 {
+            return Err(ParseOracleTypeError::with_position(
+                "IntervalYM",
+                months_pos,
+                "months between 0 and 11",
+            ));
         }
-        let months = s.read_digits().ok_or_else(err)? as i32;
+        let (years, months) = (years as i32, months as i32);
         if s.char().is_some() {
-            return Err(err());
+            return Err(err(&s, "end of string"));
         }
         Ok(IntervalYM {
             years: if minus { -years } else { years },
@@ -260,6 +348,36 @@ mod tests {
         assert_eq!(it.to_string(), "-000000001-02");
     }
 
+    #[test]
+    fn sum() {
+        let intervals = [
+            IntervalYM::new(1, 11),
+            IntervalYM::new(0, 2),
+            IntervalYM::new(-5, -6),
+            IntervalYM::new(2, 0),
+        ];
+        let expected = intervals
+            .iter()
+            .fold(IntervalYM::new(0, 0), |acc, x| acc + *x);
+        assert_eq!(intervals.iter().copied().sum::<IntervalYM>(), expected);
+        assert_eq!(intervals.iter().sum::<IntervalYM>(), expected);
+        assert_eq!(expected, IntervalYM::new(-1, -5));
+        let intervals = [
+            IntervalYM::new(1, 0).and_prec(2),
+            IntervalYM::new(1, 0).and_prec(3),
+        ];
+        assert_eq!(intervals.iter().sum::<IntervalYM>().precision(), 3);
+
+        let empty: Vec<IntervalYM> = vec![];
+        assert_eq!(empty.iter().sum::<IntervalYM>(), IntervalYM::new(0, 0));
+
+        // saturation
+        let max = IntervalYM::new(999999999, 11);
+        let min = IntervalYM::new(-999999999, -11);
+        assert_eq!([max, max].iter().sum::<IntervalYM>(), max);
+        assert_eq!([min, min].iter().sum::<IntervalYM>(), min);
+    }
+
     #[test]
     fn parse() {
         let mut it = IntervalYM::new(1, 2);
@@ -286,4 +404,98 @@ mod tests {
         let it = IntervalYM::new(-1, -2);
         assert_eq!("-000000001-02".parse(), Ok(it));
     }
+
+    #[test]
+    fn parse_error() {
+        let check = |s: &str, position, expected| {
+            let err = s.parse::<IntervalYM>().unwrap_err();
+            assert_eq!(err.position(), Some(position), "{}", s);
+            assert_eq!(err.expected(), Some(expected), "{}", s);
+        };
+        check("", 0, "years");
+        check("+", 1, "years");
+        check("1", 1, "'-'");
+        check("1 02", 1, "'-'");
+        check("1-", 2, "months");
+        check("1-02x", 4, "end of string");
+        assert_eq!(
+            "1:02".parse::<IntervalYM>().unwrap_err().to_string(),
+            "IntervalYM parse error: expected '-' at position 1"
+        );
+    }
+
+    #[test]
+    fn parse_months_range() {
+        assert_eq!("+1-11".parse(), Ok(IntervalYM::new(1, 11)));
+        assert_eq!("+1-0".parse(), Ok(IntervalYM::new(1, 0)));
+        let err = "+1-13".parse::<IntervalYM>().unwrap_err();
+        assert_eq!(err.position(), Some(3));
+        assert_eq!(err.expected(), Some("months between 0 and 11"));
+        assert_eq!(
+            err.to_string(),
+            "IntervalYM parse error: expected months between 0 and 11 at position 3"
+        );
+        assert!("-1-12".parse::<IntervalYM>().is_err());
+        assert!("+1-0011".parse::<IntervalYM>().is_ok());
+        assert!("+1-99999999999999999999999".parse::<IntervalYM>().is_err());
+    }
+
+    #[test]
+    fn parse_years_range() {
+        let max = IntervalYM::new(999999999, 11);
+        let min = IntervalYM::new(-999999999, -11);
+        assert_eq!("+999999999-11".parse(), Ok(max));
+        assert_eq!("-999999999-11".parse(), Ok(min));
+        for s in &[
+            "+1000000000-00",
+            "-1000000000-00",
+            // precision is up to 9 digits.
+            "+0000000001-00",
+            // too many digits must not overflow.
+            "99999999999999999999999-00",
+        ] {
+            let err = s.parse::<IntervalYM>().unwrap_err();
+            let pos = if s.starts_with(|c| c == '+' || c == '-') {
+                1
+            } else {
+                0
+            };
+            assert_eq!(err.position(), Some(pos), "{}", s);
+            assert_eq!(err.expected(), Some("years up to 9 digits"), "{}", s);
+        }
+    }
+
+    #[test]
+    fn parse_sign() {
+        assert_eq!("1-02".parse(), Ok(IntervalYM::new(1, 2).and_prec(1)));
+        assert_eq!("+1-02".parse(), Ok(IntervalYM::new(1, 2).and_prec(1)));
+        assert_eq!("-1-02".parse(), Ok(IntervalYM::new(-1, -2).and_prec(1)));
+        // The sign applies to months when years is zero.
+        let it: IntervalYM = "-0-05".parse().unwrap();
+        assert_eq!(it, IntervalYM::new(0, -5));
+        assert_eq!(it.to_string(), "-0-05");
+        let it: IntervalYM = "+00-00".parse().unwrap();
+        assert_eq!(it.to_string(), "+00-00");
+        // Only one leading sign is allowed.
+        for (s, pos, expected) in &[
+            ("--1-02", 1, "years"),
+            ("+-1-02", 1, "years"),
+            ("1--02", 2, "months"),
+            ("1-+02", 2, "months"),
+            (" +1-02", 0, "years"),
+        ] {
+            let err = s.parse::<IntervalYM>().unwrap_err();
+            assert_eq!(err.position(), Some(*pos), "{}", s);
+            assert_eq!(err.expected(), Some(*expected), "{}", s);
+        }
+        // round trip
+        for it in &[
+            IntervalYM::new(999999999, 11),
+            IntervalYM::new(-999999999, -11),
+            IntervalYM::new(0, -1),
+            IntervalYM::new(12, 0).and_prec(2),
+        ] {
+            assert_eq!(it.to_string().parse::<IntervalYM>(), Ok(*it));
+        }
+    }
 }
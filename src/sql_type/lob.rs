@@ -391,26 +391,48 @@ pub trait Lob {
 /// A reference to Oracle data type `BFILE`
 ///
 /// This struct implements [`Read`], and [`Seek`] to
-/// read and write bytes; and seek to a position in a LOB.
+/// read bytes; and seek to a position in a LOB.
+///
+/// The content of a `BFILE` is a file in a directory object on the server
+/// side. The file must be opened by [`Lob::open_resource`] before reading
+/// and closed by [`Lob::close_resource`] after that. Writing to it isn't
+/// supported. See also [`Connection::read_bfile`] to read the whole content
+/// of a file.
+///
+/// A `BFILE` column is fetched as `Bfile` only when the statement is built
+/// with [`StatementBuilder::lob_locator`](crate::StatementBuilder::lob_locator).
 ///
 /// # Examples
 ///
-/// ```ignore
+/// ```
 /// # use oracle::test_util;
 /// use oracle::sql_type::Bfile;
 /// # let conn = test_util::connect()?;
-/// conn.execute(
-///     "insert into TestBFILEs values (1, BFILENAME('odpic_dir', 'non-existing-file'))",
-///     &[],
-/// )?;
 ///
-/// let sql = "select BFILECol from TestBFILES where IntCol = 1";
+/// let sql = "select BFILENAME('ODPIC_DIR', 'non-existing-file') from dual";
 /// let mut stmt = conn.statement(sql).lob_locator().build()?;
 /// let bfile = stmt.query_row_as::<Bfile>(&[])?;
-/// let bfilename = bfile.directory_and_file_name()?;
-/// assert_eq!(bfilename.0, "odpic_dir");
-/// assert_eq!(bfilename.1, "non-existing-file");
-/// assert_eq!(bfile.file_exists()?, false);
+/// let (directory_alias, file_name) = bfile.bfile_name()?;
+/// assert_eq!(directory_alias, "ODPIC_DIR");
+/// assert_eq!(file_name, "non-existing-file");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Read a file.
+///
+/// ```no_run
+/// # use oracle::test_util;
+/// use oracle::sql_type::{Bfile, Lob};
+/// use std::io::Read;
+/// # let conn = test_util::connect()?;
+///
+/// let sql = "select BFILENAME('DATA_DIR', 'image.png') from dual";
+/// let mut stmt = conn.statement(sql).lob_locator().build()?;
+/// let mut bfile = stmt.query_row_as::<Bfile>(&[])?;
+/// let mut buf = Vec::new();
+/// bfile.open_resource()?;
+/// bfile.read_to_end(&mut buf)?;
+/// bfile.close_resource()?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(Clone, Debug)]
@@ -418,7 +440,6 @@ pub struct Bfile {
     pub(crate) lob: LobLocator,
 }
 
-#[allow(dead_code)] // TODO: remove this
 impl Bfile {
     pub(crate) fn from_raw(ctxt: &Context, handle: *mut dpiLob) -> Result<Bfile> {
         Ok(Bfile {
@@ -426,17 +447,6 @@ impl Bfile {
         })
     }
 
-    /// Returns a reference to a new temporary LOB which may subsequently be
-    /// written and bound to a statement.
-    pub fn new(conn: &Connection) -> Result<Bfile> {
-        let mut handle = ptr::null_mut();
-        chkerr!(
-            conn.ctxt(),
-            dpiConn_newTempLob(conn.handle(), DPI_ORACLE_TYPE_BLOB, &mut handle)
-        );
-        Bfile::from_raw(conn.ctxt(), handle)
-    }
-
     /// Closes the LOB.
     pub fn close(&mut self) -> Result<()> {
         self.lob.close()
@@ -447,6 +457,12 @@ impl Bfile {
         self.lob.directory_and_file_name()
     }
 
+    /// Returns the directory alias name and file name, which are the arguments
+    /// of SQL function `BFILENAME`. This is same with [`Bfile::directory_and_file_name`].
+    pub fn bfile_name(&self) -> Result<(String, String)> {
+        self.lob.directory_and_file_name()
+    }
+
     /// Sets the directory alias name and file name.
     pub fn set_directory_and_file_name<D, F>(
         &mut self,
@@ -466,6 +482,22 @@ impl Bfile {
     pub fn file_exists(&self) -> Result<bool> {
         self.lob.file_exists()
     }
+
+    /// Reads all bytes of the file. The file is opened before reading and
+    /// closed after that.
+    pub(crate) fn read_all(&mut self) -> Result<Vec<u8>> {
+        self.lob.open_resource()?;
+        let res = self.lob.size().and_then(|size| {
+            let mut buf = vec![0; size.try_into()?];
+            let len = self.lob.read_bytes(buf.len(), &mut buf)?;
+            buf.truncate(len);
+            Ok(buf)
+        });
+        let close_res = self.lob.close_resource();
+        let buf = res?;
+        close_res?;
+        Ok(buf)
+    }
 }
 
 /// A reference to Oracle data type `BLOB`
@@ -473,8 +505,37 @@ impl Bfile {
 /// This struct implements [`Read`], [`Write`] and [`Seek`] to
 /// read and write bytes; and seek to a position in a LOB.
 ///
+/// Positions used by [`Seek`] are byte offsets from the beginning of the LOB.
+/// A seek beyond the end of the LOB is allowed. [`Read::read`] returns zero
+/// at the position and [`Write::write`] fills the gap with zero bytes.
+/// A seek before the beginning fails.
+///
 /// # Examples
 ///
+/// Read a part of a BLOB.
+///
+/// ```
+/// # use oracle::test_util;
+/// use oracle::sql_type::Blob;
+/// use std::io::{Read, Seek, SeekFrom};
+/// # let conn = test_util::connect()?;
+/// # conn.execute(
+/// #     "insert into TestBLOBs values (1, UTL_RAW.CAST_TO_RAW('BLOB DATA'))",
+/// #     &[],
+/// # )?;
+///
+/// let sql = "select BLOBCol from TestBLOBS where IntCol = 1";
+/// let mut stmt = conn.statement(sql).lob_locator().build()?;
+/// let mut blob = stmt.query_row_as::<Blob>(&[])?;
+/// let mut buf = [0u8; 3];
+/// blob.seek(SeekFrom::Start(5))?;
+/// blob.read_exact(&mut buf)?;
+/// assert_eq!(&buf, b"DAT");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Use [`BufReader`](std::io::BufReader).
+///
 /// ```
 /// # use oracle::Error;
 /// # use oracle::test_util;
@@ -837,6 +898,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn seek_blob() -> std::result::Result<(), std::boxed::Box<dyn std::error::Error>> {
+        let conn = test_util::connect()?;
+        let data = (0..1024).map(|n| n as u8).collect::<Vec<u8>>();
+        let mut lob = Blob::new(&conn)?;
+        lob.write_all(&data)?;
+
+        // read bytes 100..200
+        assert_eq!(lob.seek(io::SeekFrom::Start(100))?, 100);
+        let mut buf = [0u8; 100];
+        lob.read_exact(&mut buf)?;
+        assert_eq!(&buf[..], &data[100..200]);
+        assert_eq!(lob.seek(io::SeekFrom::Current(0))?, 200);
+
+        // seek relative to the current position and the end
+        assert_eq!(lob.seek(io::SeekFrom::Current(-150))?, 50);
+        lob.read_exact(&mut buf[..10])?;
+        assert_eq!(&buf[..10], &data[50..60]);
+        assert_eq!(lob.seek(io::SeekFrom::End(-4))?, 1020);
+        assert_eq!(lob.read(&mut buf)?, 4);
+        assert_eq!(&buf[..4], &data[1020..]);
+
+        // seek beyond the end
+        assert_eq!(lob.seek(io::SeekFrom::End(10))?, 1034);
+        assert_eq!(lob.read(&mut buf)?, 0);
+
+        // seek before the beginning
+        assert!(lob.seek(io::SeekFrom::Current(-2000)).is_err());
+        assert_eq!(lob.seek(io::SeekFrom::Current(0))?, 1034);
+        Ok(())
+    }
+
     #[test]
     fn query_blob() -> std::result::Result<(), std::boxed::Box<dyn std::error::Error>> {
         let conn = test_util::connect()?;
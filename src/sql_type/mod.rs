@@ -17,28 +17,44 @@
 
 use crate::Connection;
 use crate::Error;
+use crate::ParseOracleTypeError;
 use crate::Result;
 use crate::SqlValue;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono")]
 mod chrono;
 pub mod collection;
+mod column_value;
 mod interval_ds;
 mod interval_ym;
 mod lob;
 mod object;
 mod oracle_type;
 mod ref_cursor;
+mod scaled_int;
+#[cfg(feature = "time")]
+mod time;
 mod timestamp;
+mod vector;
 
+pub use self::column_value::ColumnValue;
+#[cfg(all(feature = "aq_unstable", feature = "serde_json"))]
+pub(crate) use self::column_value::{base64_encode, number_to_json};
 pub use self::interval_ds::IntervalDS;
 pub use self::interval_ym::IntervalYM;
-pub(crate) use self::lob::Bfile; // TODO: remove `(crate)`
+pub use self::lob::Bfile;
 pub use self::lob::Blob;
 pub use self::lob::Clob;
 pub use self::lob::Lob;
 pub use self::lob::Nclob;
 pub use self::object::Collection;
+pub use self::object::IntoObject;
 pub use self::object::Object;
 pub use self::object::ObjectType;
 pub use self::object::ObjectTypeAttr;
@@ -46,7 +62,11 @@ pub(crate) use self::object::ObjectTypeInternal;
 pub(crate) use self::oracle_type::NativeType;
 pub use self::oracle_type::OracleType;
 pub use self::ref_cursor::RefCursor;
+pub use self::scaled_int::ScaledInt;
 pub use self::timestamp::Timestamp;
+pub use self::vector::Vector;
+pub use self::vector::VectorElement;
+pub use oracle_procmacro::IntoObject;
 
 /// Conversion from Oracle values to rust values.
 ///
@@ -56,12 +76,16 @@ pub use self::timestamp::Timestamp;
 /// | Oracle Type | Rust Type |
 /// | --- | --- |
 /// | character data types | String |
+/// |     " | `Box<str>`, `Cow<str>` (always owned) |
+/// |     " | `char` (The Oracle value must consist of one character.) |
 /// |     " | `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize`, `f64`, `f32` by using ``String::parse`` |
 /// |     " | `Vec\<u8>` (The Oracle value must be in hexadecimal.) |
 /// |     " | [`Timestamp`] by `String.parse()` |
 /// |     " | [`IntervalDS`] by `String.parse()` |
 /// |     " | [`IntervalYM`] by `String.parse()` |
+/// |     " | [`Vector`] by `String.parse()` |
 /// | numeric data types | `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize`, `f64`, `f32` |
+/// |     " | [`ScaledInt`] |
 /// |     " | `String` |
 /// | `raw` | `Vec\<u8>` |
 /// |     " | `String` (The Oracle value is converted to characters in hexadecimal.) |
@@ -80,6 +104,10 @@ pub use self::timestamp::Timestamp;
 /// | `ref cursor` | [`RefCursor`] |
 /// | `boolean` (PL/SQL only) | `bool` (Oracle client version >= 12.1) |
 ///
+/// `vector` columns cannot be fetched directly because the bundled ODPI-C doesn't
+/// support the `vector` data type. Fetching them fails at runtime. Convert them to
+/// text by `FROM_VECTOR(column)` in the select list and fetch it as [`Vector`] or `String`.
+///
 /// When `chrono` feature is enabled, the following conversions are added.
 ///
 /// | Oracle Type | Rust Type |
@@ -90,6 +118,21 @@ pub use self::timestamp::Timestamp;
 /// |     " | [`chrono::naive::NaiveDate`] |
 /// | interval day to second | [`chrono::Duration`] |
 ///
+/// When `time` feature is enabled, the following conversions are added.
+///
+/// | Oracle Type | Rust Type |
+/// | --- | --- |
+/// | timestamp data types | [`time::OffsetDateTime`] |
+/// |     " | [`time::PrimitiveDateTime`] |
+/// |     " | [`time::Date`] |
+/// | interval day to second | [`time::Duration`] |
+///
+/// When `bigdecimal` feature is enabled, the following conversion is added.
+///
+/// | Oracle Type | Rust Type |
+/// | --- | --- |
+/// | `number`, `float`, `binary_float`, `binary_double` | [`bigdecimal::BigDecimal`] |
+///
 /// This conversion is used also to get values from output parameters.
 ///
 /// [Oracle object]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-8F0BA083-FA6D-4373-B440-50FDDA4D6E90
@@ -99,6 +142,11 @@ pub use self::timestamp::Timestamp;
 /// [`chrono::naive::NaiveDate`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [`chrono::naive::NaiveDateTime`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
 /// [`chrono::Duration`]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [`time::OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+/// [`time::PrimitiveDateTime`]: https://docs.rs/time/0.3/time/struct.PrimitiveDateTime.html
+/// [`time::Date`]: https://docs.rs/time/0.3/time/struct.Date.html
+/// [`time::Duration`]: https://docs.rs/time/0.3/time/struct.Duration.html
+/// [`bigdecimal::BigDecimal`]: https://docs.rs/bigdecimal/0.3/bigdecimal/struct.BigDecimal.html
 pub trait FromSql {
     fn from_sql(val: &SqlValue) -> Result<Self>
     where
@@ -112,7 +160,7 @@ pub trait FromSql {
 ///
 /// | Rust Type | Oracle Type |
 /// | --- | --- |
-/// | `str`, `String` | `nvarchar2(0)` |
+/// | `str`, `String`, `char` | `nvarchar2(0)` |
 /// | `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `f32`, `f64` | `number` |
 /// | `Vec\<u8>` | `raw(0)` |
 /// | `bool` | `boolean` (PL/SQL only) |
@@ -131,11 +179,31 @@ pub trait FromSql {
 /// | [`chrono::naive::NaiveDateTime`] | `timestamp(9)` |
 /// | [`chrono::Duration`] | `interval day(9) to second(9)` |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [`time::OffsetDateTime`] | `timestamp(9) with time zone` |
+/// | [`time::PrimitiveDateTime`] | `timestamp(9)` |
+/// | [`time::Date`] | `timestamp(0)` |
+/// | [`time::Duration`] | `interval day(9) to second(9)` |
+///
+/// When `bigdecimal` feature is enabled, the following is added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [`bigdecimal::BigDecimal`] | `number` |
+///
 /// [`chrono::Date`]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [`chrono::DateTime`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [`chrono::naive::NaiveDate`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [`chrono::naive::NaiveDateTime`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
 /// [`chrono::Duration`]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [`time::OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+/// [`time::PrimitiveDateTime`]: https://docs.rs/time/0.3/time/struct.PrimitiveDateTime.html
+/// [`time::Date`]: https://docs.rs/time/0.3/time/struct.Date.html
+/// [`time::Duration`]: https://docs.rs/time/0.3/time/struct.Duration.html
+/// [`bigdecimal::BigDecimal`]: https://docs.rs/bigdecimal/0.3/bigdecimal/struct.BigDecimal.html
 pub trait ToSqlNull {
     fn oratype_for_null(conn: &Connection) -> Result<OracleType>;
 }
@@ -147,20 +215,31 @@ pub trait ToSqlNull {
 /// | Rust Type | Oracle Type | Oracle Value |
 /// | --- | --- | --- |
 /// | `str`, `String` | `nvarchar2(length of the rust value)` | The specified value |
+/// | `char` | `nvarchar2(length of the UTF-8 encoded value)` | The specified value |
 /// | `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize`, `f32`, `f64` | `number` | The specified value |
 /// | `Vec\<u8>` | `raw(length of the rust value)` | The specified value |
 /// | `bool` | `boolean` (PL/SQL only) | The specified value |
 /// | [`Timestamp`] | `timestamp(9) with time zone` | The specified value |
-/// | [`IntervalDS`] | `interval day(9) to second(9)` | The specified value |
+/// | [`IntervalDS`] | `interval day(lfprec) to second(fsprec)` where lfprec and fsprec are precisions of the rust value | The specified value |
 /// | [`IntervalYM`] | `interval year(9) to month` | The specified value |
+/// | [`ScaledInt`] | `number` | The specified value |
+/// | [`Vector`] | `clob` | The text form such as `[1.5,2.5]` converted to `vector` by Oracle |
 /// | [`Collection`] | type returned by [`Collection::object_type`] | The specified value |
 /// | [`Object`] | type returned by [`Object::object_type`] | The specified value |
+/// | `Vec\<T>` where T is an element type except `u8` | none. It must be bound as `(&vec, &OracleType::Object(collection_type))`. | A collection containing the elements |
 /// | `Option\<T>` where T: `ToSql` + [`ToSqlNull`] | When the value is `Some`, the contained value decides the Oracle type. When it is `None`, ToSqlNull decides it. | When the value is `Some`, the contained value. When it is `None`, a null value.
 /// | [`OracleType`] | type represented by the OracleType. | a null value |
+/// | [`Null`] | type represented by the contained OracleType. | a null value |
+/// | [`OutValue\<T>`](OutValue) where T: [`ToSqlNull`] | type decided by `T` with the length replaced with the capacity | a null value |
 /// | `(&ToSql, &OracleType)` | type represented by the second element. | The value of the first element |
+/// | `&T`, `Box\<T>`, `Rc\<T>`, `Arc\<T>` where T: `ToSql` | same with `T` | The value of `T` |
 ///
-/// When you need to bind output parameters such as varchar2, use `OracleType`
-/// or `(&ToSql, &OracleType)` to specify the maximum length of data types.
+/// [`Vector`] is bound as `clob`, not as `vector`, because the bundled ODPI-C
+/// doesn't support the `vector` data type. It cannot be bound to output
+/// parameters of `vector` PL/SQL variables.
+///
+/// When you need to bind output parameters such as varchar2, use `OracleType`,
+/// [`OutValue`] or `(&ToSql, &OracleType)` to specify the maximum length of data types.
 ///
 /// When `chrono` feature is enabled, the following conversions are added.
 ///
@@ -172,11 +251,31 @@ pub trait ToSqlNull {
 /// | [`chrono::naive::NaiveDateTime`] | `timestamp(9)` |
 /// | [`chrono::Duration`] | `interval day(9) to second(9)` |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [`time::OffsetDateTime`] | `timestamp(9) with time zone` |
+/// | [`time::PrimitiveDateTime`] | `timestamp(9)` |
+/// | [`time::Date`] | `timestamp(0)` |
+/// | [`time::Duration`] | `interval day(9) to second(9)` |
+///
+/// When `bigdecimal` feature is enabled, the following is added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [`bigdecimal::BigDecimal`] | `number` |
+///
 /// [`chrono::Date`]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [`chrono::DateTime`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [`chrono::naive::NaiveDate`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [`chrono::naive::NaiveDateTime`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
 /// [`chrono::Duration`]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [`time::OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+/// [`time::PrimitiveDateTime`]: https://docs.rs/time/0.3/time/struct.PrimitiveDateTime.html
+/// [`time::Date`]: https://docs.rs/time/0.3/time/struct.Date.html
+/// [`time::Duration`]: https://docs.rs/time/0.3/time/struct.Duration.html
+/// [`bigdecimal::BigDecimal`]: https://docs.rs/bigdecimal/0.3/bigdecimal/struct.BigDecimal.html
 ///
 pub trait ToSql {
     fn oratype(&self, conn: &Connection) -> Result<OracleType>;
@@ -244,13 +343,7 @@ impl_from_and_to_sql!(
     set_timestamp,
     OracleType::TimestampTZ(9)
 );
-impl_from_and_to_sql!(
-    IntervalDS,
-    to_interval_ds,
-    IntervalDS,
-    set_interval_ds,
-    OracleType::IntervalDS(9, 9)
-);
+impl_from_sql!(IntervalDS, to_interval_ds);
 impl_from_and_to_sql!(
     IntervalYM,
     to_interval_ym,
@@ -259,6 +352,23 @@ impl_from_and_to_sql!(
     OracleType::IntervalYM(9)
 );
 
+impl ToSqlNull for IntervalDS {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+// The precisions are kept so that values bound as IN OUT parameters are
+// returned with them.
+impl ToSql for IntervalDS {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(self.lfprec(), self.fsprec()))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_interval_ds(self)
+    }
+}
+
 impl ToSqlNull for String {
     fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::NVarchar2(0))
@@ -274,6 +384,52 @@ impl ToSql for String {
     }
 }
 
+impl FromSql for Box<str> {
+    fn from_sql(val: &SqlValue) -> Result<Box<str>> {
+        Ok(val.to_string()?.into_boxed_str())
+    }
+}
+
+/// Converts the value to an owned string.
+///
+/// [`FromSql`] has no lifetime parameter tying the result to the fetch buffer
+/// of a [`Row`](crate::Row), so the result is always [`Cow::Owned`]. The
+/// buffer is reused by subsequent fetches and cannot be borrowed safely
+/// beyond the call.
+impl<'a> FromSql for Cow<'a, str> {
+    fn from_sql(val: &SqlValue) -> Result<Cow<'a, str>> {
+        Ok(Cow::Owned(val.to_string()?))
+    }
+}
+
+impl FromSql for char {
+    fn from_sql(val: &SqlValue) -> Result<char> {
+        let s = val.to_string()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::ParseError(Box::new(ParseOracleTypeError::new(
+                "char",
+            )))),
+        }
+    }
+}
+
+impl ToSqlNull for char {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::NVarchar2(0))
+    }
+}
+
+impl ToSql for char {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::NVarchar2(self.len_utf8() as u32))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_string(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
 impl ToSqlNull for Vec<u8> {
     fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::Raw(0))
@@ -319,6 +475,36 @@ impl<'a> ToSql for &'a [u8] {
     }
 }
 
+impl ToSqlNull for str {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::NVarchar2(0))
+    }
+}
+
+impl ToSql for str {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::NVarchar2(self.len() as u32))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_string(self)
+    }
+}
+
+impl ToSqlNull for [u8] {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Raw(0))
+    }
+}
+
+impl ToSql for [u8] {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Raw(self.len() as u32))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_bytes(self)
+    }
+}
+
 impl<'a, const N: usize> ToSql for &'a [u8; N] {
     fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::Raw(self.len() as u32))
@@ -329,12 +515,17 @@ impl<'a, const N: usize> ToSql for &'a [u8; N] {
     }
 }
 
+/// Converts a null value to `None`.
+///
+/// The null indicator is checked before `T` converts the value. So `T` is
+/// never called for null values and `Err(Error::NullValue)` returned by `T`
+/// for non-null values isn't converted to `None`.
 impl<T: FromSql> FromSql for Option<T> {
     fn from_sql(val: &SqlValue) -> Result<Option<T>> {
-        match <T>::from_sql(val) {
-            Ok(v) => Ok(Some(v)),
-            Err(Error::NullValue) => Ok(None),
-            Err(err) => Err(err),
+        if val.is_null()? {
+            Ok(None)
+        } else {
+            <T>::from_sql(val).map(Some)
         }
     }
 }
@@ -354,6 +545,45 @@ impl<T: ToSql + ToSqlNull> ToSql for Option<T> {
     }
 }
 
+macro_rules! impl_to_sql_for_pointer {
+    ($($pointer:ty),*) => {
+        $(
+            impl<T: ToSqlNull + ?Sized> ToSqlNull for $pointer {
+                fn oratype_for_null(conn: &Connection) -> Result<OracleType> {
+                    <T>::oratype_for_null(conn)
+                }
+            }
+
+            impl<T: ToSql + ?Sized> ToSql for $pointer {
+                fn oratype(&self, conn: &Connection) -> Result<OracleType> {
+                    (**self).oratype(conn)
+                }
+                fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+                    (**self).to_sql(val)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_sql_for_pointer!(Box<T>, Rc<T>, Arc<T>);
+
+// `T` is `Sized` not to conflict with the implementations for `&str` and `&[u8]`.
+impl<T: ToSqlNull> ToSqlNull for &T {
+    fn oratype_for_null(conn: &Connection) -> Result<OracleType> {
+        <T>::oratype_for_null(conn)
+    }
+}
+
+impl<T: ToSql> ToSql for &T {
+    fn oratype(&self, conn: &Connection) -> Result<OracleType> {
+        (**self).oratype(conn)
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        (**self).to_sql(val)
+    }
+}
+
 impl ToSql for OracleType {
     fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
         Ok(self.clone())
@@ -364,6 +594,117 @@ impl ToSql for OracleType {
     }
 }
 
+/// A null value whose Oracle type is explicitly specified
+///
+/// This is same with binding [`OracleType`] itself but makes the intent clear.
+/// The Oracle type is used to allocate the bind buffer. So specify the
+/// maximum length for variable-length types used as output parameters.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # use oracle::sql_type::{Null, OracleType};
+/// # let conn = test_util::connect()?;
+/// let stmt = conn.execute(
+///     "begin :1 := :2 || 'ab'; end;",
+///     &[&Null(OracleType::Varchar2(10)), &Null(OracleType::Varchar2(5))],
+/// )?;
+/// let outval: String = stmt.bind_value(1)?;
+/// assert_eq!(outval, "ab");
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Null(pub OracleType);
+
+impl ToSql for Null {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(self.0.clone())
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_null()
+    }
+}
+
+/// An output parameter whose Oracle type is decided by `T`
+///
+/// This binds a null value whose Oracle type is [`T::oratype_for_null`](ToSqlNull::oratype_for_null).
+/// When the type is a variable-length type such as `varchar2`, `nvarchar2`,
+/// `char`, `nchar` or `raw`, its length is replaced with the capacity,
+/// which is the maximum length of the returned value. The unit of the length
+/// is same with that of [`OracleType`]. The default capacity is
+/// [`OutValue::DEFAULT_CAPACITY`]. Use [`OutValue::with_capacity`] for
+/// longer values. Otherwise, PL/SQL raises `ORA-06502: PL/SQL: numeric or
+/// value error: character string buffer too small`.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # use oracle::sql_type::OutValue;
+/// # let conn = test_util::connect()?;
+/// let stmt = conn.execute(
+///     "begin :1 := rpad('x', 5000, 'x'); end;",
+///     &[&OutValue::<String>::with_capacity(5000)],
+/// )?;
+/// let outval: String = stmt.bind_value(1)?;
+/// assert_eq!(outval.len(), 5000);
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutValue<T> {
+    capacity: u32,
+    phantom: PhantomData<T>,
+}
+
+impl<T> OutValue<T> {
+    /// The default capacity, which is the maximum length of `varchar2`
+    /// in SQL when `MAX_STRING_SIZE` is `STANDARD`.
+    pub const DEFAULT_CAPACITY: u32 = 4000;
+
+    /// Creates a new output parameter with the default capacity.
+    pub const fn new() -> OutValue<T> {
+        OutValue::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a new output parameter with the specified capacity.
+    pub const fn with_capacity(capacity: u32) -> OutValue<T> {
+        OutValue {
+            capacity,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the capacity.
+    pub const fn capacity(&self) -> u32 {
+        self.capacity
+    }
+}
+
+impl<T> Default for OutValue<T> {
+    fn default() -> OutValue<T> {
+        OutValue::new()
+    }
+}
+
+impl<T: ToSqlNull> ToSql for OutValue<T> {
+    fn oratype(&self, conn: &Connection) -> Result<OracleType> {
+        Ok(match T::oratype_for_null(conn)? {
+            OracleType::Varchar2(_) => OracleType::Varchar2(self.capacity),
+            OracleType::NVarchar2(_) => OracleType::NVarchar2(self.capacity),
+            OracleType::Char(_) => OracleType::Char(self.capacity),
+            OracleType::NChar(_) => OracleType::NChar(self.capacity),
+            OracleType::Raw(_) => OracleType::Raw(self.capacity),
+            oratype => oratype,
+        })
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_null()
+    }
+}
+
 impl<'a, T: ToSql> ToSql for (&'a T, &'a OracleType) {
     fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
         Ok(self.1.clone())
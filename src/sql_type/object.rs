@@ -25,7 +25,10 @@ use crate::chkerr;
 use crate::connection::Conn;
 use crate::sql_type::collection::{Indices, Iter, Values};
 use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
 use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
 use crate::sql_type::ToSql;
 use crate::to_rust_str;
 use crate::util::write_literal;
@@ -432,6 +435,95 @@ impl ToSql for Collection {
     }
 }
 
+fn vec_from_collection<T>(val: &SqlValue) -> Result<Vec<T>>
+where
+    T: FromSql,
+{
+    val.to_collection()?.values::<T>().collect()
+}
+
+fn vec_to_collection<T>(vals: &[T], val: &mut SqlValue, type_name: &str) -> Result<()>
+where
+    T: ToSql,
+{
+    let objtype = match val.oracle_type()? {
+        OracleType::Object(objtype) if objtype.is_collection() => objtype.clone(),
+        oratype => {
+            return Err(Error::InvalidTypeConversion(
+                type_name.to_string(),
+                oratype.to_string(),
+            ))
+        }
+    };
+    let mut coll = objtype.new_collection()?;
+    for v in vals {
+        coll.push(v)?;
+    }
+    val.set_collection(&coll)
+}
+
+macro_rules! impl_from_and_to_sql_for_vec {
+    ($($type:ty),*) => {
+        $(
+            impl FromSql for Vec<$type> {
+                fn from_sql(val: &SqlValue) -> Result<Vec<$type>> {
+                    vec_from_collection(val)
+                }
+            }
+
+            impl ToSql for Vec<$type> {
+                fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+                    Err(Error::InvalidOperation(format!(
+                        "Specify the collection type of {} by (&vec, &OracleType::Object(objtype))",
+                        stringify!(Vec<$type>)
+                    )))
+                }
+                fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+                    vec_to_collection(self, val, stringify!(Vec<$type>))
+                }
+            }
+        )*
+    };
+}
+
+// Vec<u8> isn't included because it is converted from and to RAW.
+impl_from_and_to_sql_for_vec!(
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    bool,
+    String,
+    Timestamp,
+    IntervalDS,
+    IntervalYM,
+    Object,
+    Option<i8>,
+    Option<i16>,
+    Option<i32>,
+    Option<i64>,
+    Option<isize>,
+    Option<u8>,
+    Option<u16>,
+    Option<u32>,
+    Option<u64>,
+    Option<usize>,
+    Option<f32>,
+    Option<f64>,
+    Option<bool>,
+    Option<String>,
+    Option<Timestamp>,
+    Option<IntervalDS>,
+    Option<IntervalYM>
+);
+
 impl fmt::Display for Collection {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}(", self.objtype)?;
@@ -611,6 +703,66 @@ impl ToSql for Object {
     }
 }
 
+/// A trait to convert a Rust value into an Oracle object
+///
+/// This is usually implemented by the [`IntoObject` derive macro](macro@crate::sql_type::IntoObject),
+/// which also implements [`ToSql`] and [`ToSqlNull`](crate::sql_type::ToSqlNull) by using the provided
+/// methods of this trait. Then the value can be bound to object type parameters
+/// and set to object type attributes as [`Object`].
+pub trait IntoObject {
+    /// Returns the name of the Oracle object type, which is used to bind the
+    /// value without explicit [`OracleType`].
+    ///
+    /// The default implementation returns `None`.
+    fn object_type_name() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Sets attribute values of `obj` from `self`.
+    fn set_attributes(&self, obj: &mut Object) -> Result<()>;
+
+    /// Creates a new object of `objtype` and sets attribute values from `self`.
+    fn to_object(&self, objtype: &ObjectType) -> Result<Object> {
+        let mut obj = objtype.new_object()?;
+        self.set_attributes(&mut obj)?;
+        Ok(obj)
+    }
+
+    /// Returns the Oracle type named by [`object_type_name`](IntoObject::object_type_name).
+    ///
+    /// This returns `Err(Error::InvalidOperation(...))` when it is `None`.
+    /// Bind the value as `(&value, &OracleType::Object(objtype))` in the case.
+    fn object_oratype(conn: &Connection) -> Result<OracleType>
+    where
+        Self: Sized,
+    {
+        match Self::object_type_name() {
+            Some(name) => Ok(OracleType::Object(conn.object_type(name)?)),
+            None => Err(Error::InvalidOperation(format!(
+                "no object type name for {}. Bind it with OracleType::Object explicitly.",
+                std::any::type_name::<Self>()
+            ))),
+        }
+    }
+
+    /// Sets `self` to `val` as a new object of the object type of `val`.
+    fn set_object_to(&self, val: &mut SqlValue) -> Result<()> {
+        let obj = match val.oracle_type()? {
+            OracleType::Object(objtype) => self.to_object(objtype)?,
+            oratype => {
+                return Err(Error::InvalidTypeConversion(
+                    "Object".to_string(),
+                    oratype.to_string(),
+                ))
+            }
+        };
+        val.set_object(&obj)
+    }
+}
+
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}(", self.objtype)?;
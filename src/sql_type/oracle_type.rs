@@ -14,13 +14,17 @@
 //-----------------------------------------------------------------------------
 
 use std::fmt;
+use std::mem;
 use std::ptr;
+use std::result;
+use std::str;
 
 use crate::binding::*;
 use crate::connection::Conn;
 use crate::sql_type::ObjectType;
 use crate::DpiObjectType;
 use crate::Error;
+use crate::ParseOracleTypeError;
 use crate::Result;
 
 // NativeType corresponds to dpiNativeTypeNum in ODPI
@@ -134,6 +138,10 @@ pub enum OracleType {
     ///
     /// `fsprec` is fractional seconds precision between 0 and 9. When it is
     /// 6, `(fsprec)` is omitted in text represention.
+    ///
+    /// When this is bound as a null value to get an output value, `fsprec`
+    /// is kept as the precision of the [`Timestamp`](crate::sql_type::Timestamp)
+    /// returned by [`Statement::bind_value`](crate::Statement::bind_value).
     Timestamp(u8),
 
     /// TIMESTAMP(fsprec) WITH TIME ZONE
@@ -142,6 +150,10 @@ pub enum OracleType {
     ///
     /// `fsprec` is fractional seconds precision between 0 and 9. When it is
     /// 6, `(fsprec)` is omitted in text represention.
+    ///
+    /// When this is bound as a null value to get an output value, `fsprec`
+    /// is kept as the precision of the [`Timestamp`](crate::sql_type::Timestamp)
+    /// returned by [`Statement::bind_value`](crate::Statement::bind_value).
     TimestampTZ(u8),
 
     /// TIMESTAMP(fsprec) WITH LOCAL TIME ZONE
@@ -151,6 +163,10 @@ pub enum OracleType {
     ///
     /// `fsprec` is fractional seconds precision between 0 and 9. When it is
     /// 6, `(fsprec)` is omitted in text represention.
+    ///
+    /// When this is bound as a null value to get an output value, `fsprec`
+    /// is kept as the precision of the [`Timestamp`](crate::sql_type::Timestamp)
+    /// returned by [`Statement::bind_value`](crate::Statement::bind_value).
     TimestampLTZ(u8),
 
     /// INTERVAL DAY(lfprec) TO SECOND(fsprec)
@@ -178,6 +194,10 @@ pub enum OracleType {
     BLOB,
 
     /// BFILE
+    ///
+    /// The content of a BFILE lives in a file on the database server's
+    /// filesystem. The database stores only a locator pointing to it, so
+    /// values of this type are read-only.
     BFILE,
 
     /// REF CURSOR (not supported)
@@ -190,9 +210,19 @@ pub enum OracleType {
     Object(ObjectType),
 
     /// LONG
+    ///
+    /// Legacy character data type, which can store up to 2 gigabytes.
+    /// Only one LONG or LONG RAW column is allowed per table.
+    /// The whole value is fetched into memory as `String`.
+    /// Bind `(&str, &OracleType::Long)` to insert a value longer than 32767 bytes.
     Long,
 
     /// LONG RAW
+    ///
+    /// Legacy binary data type, which can store up to 2 gigabytes.
+    /// Only one LONG or LONG RAW column is allowed per table.
+    /// The whole value is fetched into memory as `Vec<u8>`.
+    /// Bind `(&[u8], &OracleType::LongRaw)` to insert a value longer than 32767 bytes.
     LongRaw,
 
     /// JSON data type introduced in Oracle 21c
@@ -206,6 +236,83 @@ pub enum OracleType {
 }
 
 impl OracleType {
+    /// Returns the declared size of the type.
+    ///
+    /// The size is in bytes for `Varchar2`, `Char` and `Raw` and in characters
+    /// for `NVarchar2` and `NChar`. `None` is returned for other types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::OracleType;
+    /// assert_eq!(OracleType::Varchar2(100).size(), Some(100));
+    /// assert_eq!(OracleType::Raw(16).size(), Some(16));
+    /// assert_eq!(OracleType::Date.size(), None);
+    /// ```
+    pub fn size(&self) -> Option<u32> {
+        match *self {
+            OracleType::Varchar2(size)
+            | OracleType::NVarchar2(size)
+            | OracleType::Char(size)
+            | OracleType::NChar(size)
+            | OracleType::Raw(size) => Some(size),
+            _ => None,
+        }
+    }
+
+    /// Returns the precision and scale of `Number`.
+    ///
+    /// The precision is zero when it isn't declared. See [`OracleType::Number`].
+    /// `None` is returned for other types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::OracleType;
+    /// assert_eq!(OracleType::Number(10, 2).precision_and_scale(), Some((10, 2)));
+    /// assert_eq!(OracleType::Varchar2(100).precision_and_scale(), None);
+    /// ```
+    pub fn precision_and_scale(&self) -> Option<(u8, i8)> {
+        match *self {
+            OracleType::Number(prec, scale) => Some((prec, scale)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` when `self` and `other` are same data type ignoring
+    /// size, precision and scale.
+    ///
+    /// For example `Varchar2(50)` is compatible with `Varchar2(100)` and
+    /// `Number(10, 2)` is compatible with `Number(5, 0)`. `Number`, `Float`,
+    /// `Int64` and `UInt64` are compatible with each other because they are
+    /// stored as `NUMBER`. `Object` types are compatible only when they are same object type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::sql_type::OracleType;
+    /// assert!(OracleType::Varchar2(50).is_compatible_with(&OracleType::Varchar2(100)));
+    /// assert!(OracleType::Number(10, 2).is_compatible_with(&OracleType::Number(5, 0)));
+    /// assert!(!OracleType::Number(10, 2).is_compatible_with(&OracleType::Date));
+    /// assert!(!OracleType::Varchar2(50).is_compatible_with(&OracleType::NVarchar2(50)));
+    /// ```
+    pub fn is_compatible_with(&self, other: &OracleType) -> bool {
+        match (self, other) {
+            (
+                OracleType::Number(_, _)
+                | OracleType::Float(_)
+                | OracleType::Int64
+                | OracleType::UInt64,
+                OracleType::Number(_, _)
+                | OracleType::Float(_)
+                | OracleType::Int64
+                | OracleType::UInt64,
+            ) => true,
+            (OracleType::Object(objtype1), OracleType::Object(objtype2)) => objtype1 == objtype2,
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+
     pub(crate) fn from_type_info(conn: &Conn, info: &dpiDataTypeInfo) -> Result<OracleType> {
         match info.oracleTypeNum {
             DPI_ORACLE_TYPE_VARCHAR => Ok(OracleType::Varchar2(info.dbSizeInBytes)),
@@ -394,3 +501,322 @@ impl fmt::Display for OracleType {
         }
     }
 }
+
+/// A word in a type name with optional precisions such as `DAY(2)`
+struct TypeWord<'a> {
+    name: &'a str,
+    args: Vec<&'a str>,
+}
+
+impl<'a> TypeWord<'a> {
+    fn parse(word: &'a str) -> Option<TypeWord<'a>> {
+        match word.find('(') {
+            Some(pos) => Some(TypeWord {
+                name: &word[..pos],
+                args: word[pos + 1..].strip_suffix(')')?.split(',').collect(),
+            }),
+            None => Some(TypeWord {
+                name: word,
+                args: Vec::new(),
+            }),
+        }
+    }
+
+    /// Returns the `idx`-th precision, which must not be omitted.
+    fn required_arg<T: str::FromStr>(&self, idx: usize) -> Option<T> {
+        self.args.get(idx)?.parse().ok()
+    }
+
+    /// Returns the `idx`-th precision or `default` when precisions are omitted.
+    fn arg<T: str::FromStr>(&self, idx: usize, default: T) -> Option<T> {
+        if self.args.is_empty() {
+            Some(default)
+        } else {
+            self.required_arg(idx)
+        }
+    }
+}
+
+/// Parses a type name such as `NUMBER(10,2)` and `INTERVAL DAY(2) TO SECOND(3)`.
+///
+/// The name is case-insensitive and parses text returned by [`Display`](fmt::Display)
+/// except object types and types used internally. The sizes of `VARCHAR2`,
+/// `NVARCHAR2` and `RAW` are mandatory. Other omitted precisions are set to
+/// the defaults of Oracle. `NUMBER` without precision is parsed as `Number(0, -127)`,
+/// which is the value in column metadata.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::sql_type::OracleType;
+/// assert_eq!("number(10,2)".parse::<OracleType>()?, OracleType::Number(10, 2));
+/// assert_eq!(
+///     "INTERVAL DAY(2) TO SECOND(3)".parse::<OracleType>()?,
+///     OracleType::IntervalDS(2, 3)
+/// );
+/// assert_eq!(
+///     "INTERVAL YEAR TO MONTH".parse::<OracleType>()?,
+///     OracleType::IntervalYM(2)
+/// );
+/// # Ok::<(), oracle::ParseOracleTypeError>(())
+/// ```
+impl str::FromStr for OracleType {
+    type Err = ParseOracleTypeError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let err = || ParseOracleTypeError::new("OracleType");
+        let s = s
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_uppercase()
+            .replace(" (", "(")
+            .replace("( ", "(")
+            .replace(" )", ")")
+            .replace(" ,", ",")
+            .replace(", ", ",");
+        let words = s
+            .split(' ')
+            .map(TypeWord::parse)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(err)?;
+        let names = words.iter().map(|w| w.name).collect::<Vec<_>>();
+        // the number of precisions allowed in each word
+        let (oratype, max_args): (Option<OracleType>, &[usize]) = match names.as_slice() {
+            ["VARCHAR2"] => (words[0].required_arg(0).map(OracleType::Varchar2), &[1]),
+            ["NVARCHAR2"] => (words[0].required_arg(0).map(OracleType::NVarchar2), &[1]),
+            ["CHAR"] => (words[0].arg(0, 1).map(OracleType::Char), &[1]),
+            ["NCHAR"] => (words[0].arg(0, 1).map(OracleType::NChar), &[1]),
+            ["ROWID"] => (Some(OracleType::Rowid), &[0]),
+            ["RAW"] => (words[0].required_arg(0).map(OracleType::Raw), &[1]),
+            ["BINARY_FLOAT"] => (Some(OracleType::BinaryFloat), &[0]),
+            ["BINARY_DOUBLE"] => (Some(OracleType::BinaryDouble), &[0]),
+            ["NUMBER"] if words[0].args.is_empty() => (Some(OracleType::Number(0, -127)), &[0]),
+            ["NUMBER"] => {
+                let scale = if words[0].args.len() == 1 {
+                    Some(0)
+                } else {
+                    words[0].arg(1, 0)
+                };
+                (
+                    words[0]
+                        .arg(0, 0)
+                        .and_then(|prec| Some(OracleType::Number(prec, scale?))),
+                    &[2],
+                )
+            }
+            ["FLOAT"] => (words[0].arg(0, 126).map(OracleType::Float), &[1]),
+            ["DATE"] => (Some(OracleType::Date), &[0]),
+            ["TIMESTAMP"] => (words[0].arg(0, 6).map(OracleType::Timestamp), &[1]),
+            ["TIMESTAMP", "WITH", "TIME", "ZONE"] => (
+                words[0].arg(0, 6).map(OracleType::TimestampTZ),
+                &[1, 0, 0, 0],
+            ),
+            ["TIMESTAMP", "WITH", "LOCAL", "TIME", "ZONE"] => (
+                words[0].arg(0, 6).map(OracleType::TimestampLTZ),
+                &[1, 0, 0, 0, 0],
+            ),
+            ["INTERVAL", "DAY", "TO", "SECOND"] => (
+                words[1]
+                    .arg(0, 2)
+                    .and_then(|lfprec| Some(OracleType::IntervalDS(lfprec, words[3].arg(0, 6)?))),
+                &[0, 1, 0, 1],
+            ),
+            ["INTERVAL", "YEAR", "TO", "MONTH"] => (
+                words[1].arg(0, 2).map(OracleType::IntervalYM),
+                &[0, 1, 0, 0],
+            ),
+            ["CLOB"] => (Some(OracleType::CLOB), &[0]),
+            ["NCLOB"] => (Some(OracleType::NCLOB), &[0]),
+            ["BLOB"] => (Some(OracleType::BLOB), &[0]),
+            ["BFILE"] => (Some(OracleType::BFILE), &[0]),
+            ["REF", "CURSOR"] => (Some(OracleType::RefCursor), &[0, 0]),
+            ["BOOLEAN"] => (Some(OracleType::Boolean), &[0]),
+            ["LONG"] => (Some(OracleType::Long), &[0]),
+            ["LONG", "RAW"] => (Some(OracleType::LongRaw), &[0, 0]),
+            ["JSON"] => (Some(OracleType::Json), &[0]),
+            _ => (None, &[]),
+        };
+        if words
+            .iter()
+            .zip(max_args)
+            .any(|(word, max)| word.args.len() > *max)
+        {
+            return Err(err());
+        }
+        oratype.ok_or_else(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        assert_eq!(OracleType::Varchar2(100).size(), Some(100));
+        assert_eq!(OracleType::NVarchar2(50).size(), Some(50));
+        assert_eq!(OracleType::Char(10).size(), Some(10));
+        assert_eq!(OracleType::NChar(5).size(), Some(5));
+        assert_eq!(OracleType::Raw(16).size(), Some(16));
+        assert_eq!(OracleType::Number(10, 2).size(), None);
+        assert_eq!(OracleType::Date.size(), None);
+        assert_eq!(OracleType::CLOB.size(), None);
+    }
+
+    #[test]
+    fn precision_and_scale() {
+        assert_eq!(
+            OracleType::Number(10, 2).precision_and_scale(),
+            Some((10, 2))
+        );
+        assert_eq!(
+            OracleType::Number(0, -127).precision_and_scale(),
+            Some((0, -127))
+        );
+        assert_eq!(OracleType::Varchar2(100).precision_and_scale(), None);
+        assert_eq!(OracleType::Date.precision_and_scale(), None);
+    }
+
+    #[test]
+    fn is_compatible_with() {
+        let compatible_pairs = [
+            (OracleType::Varchar2(50), OracleType::Varchar2(100)),
+            (OracleType::NVarchar2(1), OracleType::NVarchar2(2000)),
+            (OracleType::Char(1), OracleType::Char(10)),
+            (OracleType::Raw(16), OracleType::Raw(2000)),
+            (OracleType::Number(10, 2), OracleType::Number(5, 0)),
+            (OracleType::Number(0, -127), OracleType::Float(126)),
+            (OracleType::Number(38, 0), OracleType::Int64),
+            (OracleType::Number(20, 0), OracleType::UInt64),
+            (OracleType::Int64, OracleType::UInt64),
+            (OracleType::Timestamp(0), OracleType::Timestamp(9)),
+            (OracleType::TimestampTZ(3), OracleType::TimestampTZ(6)),
+            (OracleType::IntervalDS(2, 6), OracleType::IntervalDS(9, 9)),
+            (OracleType::IntervalYM(2), OracleType::IntervalYM(9)),
+            (OracleType::Date, OracleType::Date),
+            (OracleType::CLOB, OracleType::CLOB),
+            (OracleType::BinaryDouble, OracleType::BinaryDouble),
+        ];
+        for (t1, t2) in &compatible_pairs {
+            assert!(t1.is_compatible_with(t2), "{} vs {}", t1, t2);
+            assert!(t2.is_compatible_with(t1), "{} vs {}", t2, t1);
+        }
+        let incompatible_pairs = [
+            (OracleType::Number(10, 2), OracleType::Date),
+            (OracleType::Number(10, 2), OracleType::BinaryDouble),
+            (OracleType::Varchar2(50), OracleType::NVarchar2(50)),
+            (OracleType::Varchar2(50), OracleType::Char(50)),
+            (OracleType::Varchar2(50), OracleType::CLOB),
+            (OracleType::Raw(16), OracleType::BLOB),
+            (OracleType::Timestamp(6), OracleType::TimestampTZ(6)),
+            (OracleType::Timestamp(6), OracleType::Date),
+            (OracleType::IntervalDS(2, 6), OracleType::IntervalYM(2)),
+            (OracleType::BinaryFloat, OracleType::BinaryDouble),
+        ];
+        for (t1, t2) in &incompatible_pairs {
+            assert!(!t1.is_compatible_with(t2), "{} vs {}", t1, t2);
+            assert!(!t2.is_compatible_with(t1), "{} vs {}", t2, t1);
+        }
+    }
+
+    #[test]
+    fn lob_to_string() {
+        assert_eq!(OracleType::CLOB.to_string(), "CLOB");
+        assert_eq!(OracleType::NCLOB.to_string(), "NCLOB");
+        assert_eq!(OracleType::BLOB.to_string(), "BLOB");
+        assert_eq!(OracleType::BFILE.to_string(), "BFILE");
+        assert_eq!("clob".parse(), Ok(OracleType::CLOB));
+        assert_eq!("nclob".parse(), Ok(OracleType::NCLOB));
+        assert_eq!("blob".parse(), Ok(OracleType::BLOB));
+        assert_eq!("bfile".parse(), Ok(OracleType::BFILE));
+    }
+
+    #[test]
+    fn from_str_interval() {
+        let parse = |s: &str| s.parse::<OracleType>();
+        assert_eq!(
+            parse("INTERVAL DAY(2) TO SECOND(3)"),
+            Ok(OracleType::IntervalDS(2, 3))
+        );
+        assert_eq!(
+            parse("interval day ( 9 ) to second ( 0 )"),
+            Ok(OracleType::IntervalDS(9, 0))
+        );
+        assert_eq!(
+            parse("INTERVAL DAY TO SECOND"),
+            Ok(OracleType::IntervalDS(2, 6))
+        );
+        assert_eq!(
+            parse("INTERVAL DAY(5) TO SECOND"),
+            Ok(OracleType::IntervalDS(5, 6))
+        );
+        assert_eq!(
+            parse("INTERVAL YEAR(4) TO MONTH"),
+            Ok(OracleType::IntervalYM(4))
+        );
+        assert_eq!(
+            parse("INTERVAL YEAR TO MONTH"),
+            Ok(OracleType::IntervalYM(2))
+        );
+        assert!(parse("INTERVAL YEAR TO MONTH(2)").is_err());
+        assert!(parse("INTERVAL DAY(2,3) TO SECOND").is_err());
+        assert!(parse("INTERVAL DAY TO").is_err());
+        assert!(parse("INTERVAL DAY(x) TO SECOND").is_err());
+
+        for oratype in &[
+            OracleType::IntervalDS(2, 6),
+            OracleType::IntervalDS(2, 3),
+            OracleType::IntervalDS(9, 9),
+            OracleType::IntervalYM(2),
+            OracleType::IntervalYM(4),
+        ] {
+            assert_eq!(parse(&oratype.to_string()).as_ref(), Ok(oratype));
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let parse = |s: &str| s.parse::<OracleType>();
+        for oratype in &[
+            OracleType::Varchar2(10),
+            OracleType::NVarchar2(20),
+            OracleType::Char(1),
+            OracleType::NChar(5),
+            OracleType::Rowid,
+            OracleType::Raw(16),
+            OracleType::BinaryFloat,
+            OracleType::BinaryDouble,
+            OracleType::Number(0, -127),
+            OracleType::Number(10, 0),
+            OracleType::Number(10, 2),
+            OracleType::Float(126),
+            OracleType::Float(63),
+            OracleType::Date,
+            OracleType::Timestamp(6),
+            OracleType::Timestamp(3),
+            OracleType::TimestampTZ(6),
+            OracleType::TimestampTZ(9),
+            OracleType::TimestampLTZ(6),
+            OracleType::TimestampLTZ(0),
+            OracleType::CLOB,
+            OracleType::NCLOB,
+            OracleType::BLOB,
+            OracleType::BFILE,
+            OracleType::RefCursor,
+            OracleType::Boolean,
+            OracleType::Long,
+            OracleType::LongRaw,
+            OracleType::Json,
+        ] {
+            assert_eq!(parse(&oratype.to_string()).as_ref(), Ok(oratype));
+        }
+        assert_eq!(parse("number(10, -2)"), Ok(OracleType::Number(10, -2)));
+        assert_eq!(parse("char"), Ok(OracleType::Char(1)));
+        assert!(parse("VARCHAR2").is_err());
+        assert!(parse("DATE(1)").is_err());
+        assert!(parse("TIMESTAMP WITH TIME(3) ZONE").is_err());
+        assert!(parse("UNKNOWN").is_err());
+        assert!(parse("").is_err());
+    }
+}
@@ -22,6 +22,7 @@ use crate::sql_type::ToSqlNull;
 use crate::statement::QueryParams;
 use crate::statement::Stmt;
 use crate::Connection;
+use crate::DpiStmt;
 use crate::Error;
 use crate::Result;
 use crate::ResultSet;
@@ -32,6 +33,7 @@ use crate::SqlValue;
 use crate::Statement;
 #[cfg(doc)]
 use crate::StatementBuilder;
+use std::mem::MaybeUninit;
 
 /// Result set output by or returned by a PL/SQL block or a stored procedure
 ///
@@ -46,6 +48,12 @@ use crate::StatementBuilder;
 /// [`StatementBuilder::fetch_array_size(1)`][`StatementBuilder::fetch_array_size]
 /// in order to reduce memory usage when a ref cursor contains at most one row.
 ///
+/// Cursor columns such as `cursor(select ...)` in a select list are also
+/// fetched as `RefCursor`. They may be nested to any depth. A cursor column
+/// is closed by Oracle along with the statement or the ref cursor containing it.
+/// Iterate it before the parent is dropped. Otherwise query methods fail with
+/// `DPI-1039: statement was already closed`.
+///
 /// # Examples
 ///
 /// Ref cursor as an output parameter
@@ -109,6 +117,8 @@ use crate::StatementBuilder;
 ///
 pub struct RefCursor {
     stmt: Stmt,
+    // The parent statement of a cursor column. This must be dropped after `stmt`.
+    parent_stmt: Option<DpiStmt>,
 }
 
 impl RefCursor {
@@ -116,6 +126,7 @@ impl RefCursor {
         conn: Conn,
         handle: *mut dpiStmt,
         query_params: QueryParams,
+        parent_stmt: Option<DpiStmt>,
     ) -> Result<RefCursor> {
         chkerr!(
             conn.ctxt(),
@@ -129,7 +140,7 @@ impl RefCursor {
         chkerr!(conn.ctxt(), dpiStmt_addRef(handle));
         let mut stmt = Stmt::new(conn, handle, query_params, "".into());
         stmt.init_row(num_query_columns as usize)?;
-        Ok(RefCursor { stmt })
+        Ok(RefCursor { stmt, parent_stmt })
     }
 
     /// Gets rows as an iterator of [`Row`]s.
@@ -162,6 +173,7 @@ impl RefCursor {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn query(&mut self) -> Result<ResultSet<Row>> {
+        self.check_parent_stmt()?;
         Ok(ResultSet::<Row>::new(&self.stmt))
     }
 
@@ -196,6 +208,7 @@ impl RefCursor {
     where
         T: RowValue,
     {
+        self.check_parent_stmt()?;
         Ok(ResultSet::<T>::new(&self.stmt))
     }
 
@@ -252,6 +265,19 @@ impl RefCursor {
     {
         self.query_as()?.next().unwrap_or(Err(Error::NoDataFound))
     }
+
+    // A cursor column is closed along with its parent statement.
+    // This returns DPI-1039 when the parent statement was closed.
+    fn check_parent_stmt(&self) -> Result<()> {
+        if let Some(ref parent) = self.parent_stmt {
+            let mut info = MaybeUninit::uninit();
+            chkerr!(
+                self.stmt.ctxt(),
+                dpiStmt_getInfo(parent.raw(), info.as_mut_ptr())
+            );
+        }
+        Ok(())
+    }
 }
 
 impl ToSqlNull for RefCursor {
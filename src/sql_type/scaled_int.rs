@@ -0,0 +1,266 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::util::check_number_format;
+use crate::Connection;
+use crate::Error;
+use crate::ParseOracleTypeError;
+use crate::Result;
+use crate::SqlValue;
+use std::fmt;
+use std::result;
+use std::str;
+
+// The exponent range of nonzero Oracle NUMBER values, 1E-130 to 9.99..9E125.
+const MIN_EXPONENT: i64 = -130;
+const MAX_EXPONENT: i64 = 125;
+
+/// A decimal number represented by an unscaled integer and a scale
+///
+/// The value is `mantissa * 10^(-scale)`. For example `123.45` is
+/// `ScaledInt::new(12345, 2)`. This is useful to do exact integer arithmetic
+/// on Oracle NUMBER values without decimal crates.
+///
+/// A value fetched from Oracle is decomposed from its text representation.
+/// The scale is the number of digits after the decimal point.
+/// Trailing zeros in the integer part are kept unless the mantissa overflows
+/// `i128`, in which case they are removed and the scale becomes negative.
+///
+/// [`ScaledInt::new`] accepts any pair of the mantissa and the scale.
+/// Use [`ScaledInt::checked_new`] to check whether the value is within the range
+/// of Oracle NUMBER data type, from 1E-130 to 9.99...9E125 in absolute value.
+///
+/// Note that the equality is based on the pair of the mantissa and the scale.
+/// `ScaledInt::new(1, 0)` isn't equal to `ScaledInt::new(10, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::sql_type::ScaledInt;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let val = conn.query_row_as::<ScaledInt>("select 123.45 from dual", &[])?;
+/// assert_eq!(val.mantissa(), 12345);
+/// assert_eq!(val.scale(), 2);
+/// assert_eq!(val.to_string(), "123.45");
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScaledInt {
+    mantissa: i128,
+    scale: i32,
+}
+
+impl ScaledInt {
+    /// Creates a new ScaledInt whose value is `mantissa * 10^(-scale)`.
+    pub const fn new(mantissa: i128, scale: i32) -> ScaledInt {
+        ScaledInt { mantissa, scale }
+    }
+
+    /// Creates a new ScaledInt whose value is `mantissa * 10^(-scale)`.
+    ///
+    /// This returns `None` when the value is out of the range of Oracle NUMBER
+    /// data type.
+    pub fn checked_new(mantissa: i128, scale: i32) -> Option<ScaledInt> {
+        let val = ScaledInt::new(mantissa, scale);
+        if val.is_number_range() {
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true when the value can be stored in Oracle NUMBER data type.
+    fn is_number_range(&self) -> bool {
+        if self.mantissa == 0 {
+            return true;
+        }
+        let num_digits = self.mantissa.unsigned_abs().to_string().len() as i64;
+        let exponent = num_digits - 1 - self.scale as i64;
+        (MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent)
+    }
+
+    /// Returns the unscaled integer.
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the number of digits after the decimal point.
+    /// A negative value means trailing zeros of the integer part.
+    pub const fn scale(&self) -> i32 {
+        self.scale
+    }
+}
+
+impl fmt::Display for ScaledInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if self.scale <= 0 {
+            let zeros = if self.mantissa == 0 {
+                0
+            } else {
+                self.scale.unsigned_abs() as usize
+            };
+            write!(f, "{}{}{:0<width$}", sign, digits, "", width = zeros)
+        } else {
+            let scale = self.scale as usize;
+            if digits.len() > scale {
+                let (int, frac) = digits.split_at(digits.len() - scale);
+                write!(f, "{}{}.{}", sign, int, frac)
+            } else {
+                write!(f, "{}0.{:0>width$}", sign, digits, width = scale)
+            }
+        }
+    }
+}
+
+impl str::FromStr for ScaledInt {
+    type Err = ParseOracleTypeError;
+
+    fn from_str(s: &str) -> result::Result<ScaledInt, ParseOracleTypeError> {
+        let err = || ParseOracleTypeError::new("ScaledInt");
+        check_number_format(s)?;
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let (s, exponent) = match s.find(|c| c == 'e' || c == 'E') {
+            Some(idx) => (&s[..idx], s[idx + 1..].parse::<i32>().map_err(|_| err())?),
+            None => (s, 0),
+        };
+        let (int, frac) = match s.find('.') {
+            Some(idx) => (&s[..idx], &s[idx + 1..]),
+            None => (s, ""),
+        };
+        let mut digits = format!("{}{}", int.trim_start_matches('0'), frac);
+        let mut scale = (frac.len() as i32).checked_sub(exponent).ok_or_else(err)?;
+        let mantissa = loop {
+            if let Ok(mantissa) = digits.parse::<i128>() {
+                break mantissa;
+            }
+            if digits.is_empty() {
+                break 0;
+            }
+            if !digits.ends_with('0') {
+                return Err(err());
+            }
+            digits.pop();
+            scale = scale.checked_sub(1).ok_or_else(err)?;
+        };
+        ScaledInt::checked_new(if negative { -mantissa } else { mantissa }, scale).ok_or_else(err)
+    }
+}
+
+impl FromSql for ScaledInt {
+    fn from_sql(val: &SqlValue) -> Result<ScaledInt> {
+        let s = val.to_string()?;
+        s.parse()
+            .map_err(|_| Error::OutOfRange(format!("{} cannot be represented as ScaledInt", s)))
+    }
+}
+
+impl ToSqlNull for ScaledInt {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+}
+
+impl ToSql for ScaledInt {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        if !self.is_number_range() {
+            return Err(Error::OutOfRange(format!(
+                "{}E{} is out of the range of Oracle NUMBER",
+                self.mantissa,
+                -(self.scale as i64)
+            )));
+        }
+        val.set_string(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let parse = |s: &str| s.parse::<ScaledInt>().map(|v| (v.mantissa, v.scale));
+        assert_eq!(parse("123.45"), Ok((12345, 2)));
+        assert_eq!(parse("-123.45"), Ok((-12345, 2)));
+        assert_eq!(parse("100"), Ok((100, 0)));
+        assert_eq!(parse("0"), Ok((0, 0)));
+        assert_eq!(parse("0.001"), Ok((1, 3)));
+        assert_eq!(parse("-0.5"), Ok((-5, 1)));
+        assert_eq!(parse("1.5E+3"), Ok((15, -2)));
+        assert_eq!(parse("1.5e-3"), Ok((15, 4)));
+        // 40 digits
+        assert_eq!(
+            parse("1000000000000000000000000000000000000000"),
+            Ok((10i128.pow(38), -1))
+        );
+        assert_eq!(parse("1E+125"), Ok((1, -125)));
+        assert_eq!(parse("9.99E+125"), Ok((999, -123)));
+        assert_eq!(parse("1E-130"), Ok((1, 130)));
+        assert_eq!(parse("-1E-130"), Ok((-1, 130)));
+        assert_eq!(parse("0E+200"), Ok((0, -200)));
+        // out of the range of Oracle NUMBER
+        assert!(parse("1E+126").is_err());
+        assert!(parse("10E+125").is_err());
+        assert!(parse("1E-131").is_err());
+        assert!(parse("0.1E-130").is_err());
+        assert!(parse("12a").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(ScaledInt::new(12345, 2).to_string(), "123.45");
+        assert_eq!(ScaledInt::new(-12345, 2).to_string(), "-123.45");
+        assert_eq!(ScaledInt::new(1, 3).to_string(), "0.001");
+        assert_eq!(ScaledInt::new(-5, 1).to_string(), "-0.5");
+        assert_eq!(ScaledInt::new(100, 0).to_string(), "100");
+        assert_eq!(ScaledInt::new(15, -2).to_string(), "1500");
+        assert_eq!(ScaledInt::new(0, -2).to_string(), "0");
+        assert_eq!(ScaledInt::new(0, 2).to_string(), "0.00");
+        assert_eq!(
+            ScaledInt::new(1, -84).to_string(),
+            format!("1{}", "0".repeat(84))
+        );
+    }
+
+    #[test]
+    fn checked_new() {
+        assert!(ScaledInt::checked_new(1, -125).is_some());
+        assert!(ScaledInt::checked_new(999, -123).is_some());
+        assert!(ScaledInt::checked_new(1, 130).is_some());
+        assert!(ScaledInt::checked_new(12345, 134).is_some());
+        assert!(ScaledInt::checked_new(0, i32::MIN).is_some());
+        assert!(ScaledInt::checked_new(1, -126).is_none());
+        assert!(ScaledInt::checked_new(1000, -123).is_none());
+        assert!(ScaledInt::checked_new(1, 131).is_none());
+        assert!(ScaledInt::checked_new(1, i32::MIN).is_none());
+        assert!(ScaledInt::checked_new(-1, i32::MAX).is_none());
+    }
+}
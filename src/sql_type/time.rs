@@ -0,0 +1,242 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use std::convert::{TryFrom, TryInto};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn utc_offset_from_sql(ts: &Timestamp) -> Result<UtcOffset> {
+    UtcOffset::from_whole_seconds(ts.tz_offset())
+        .map_err(|_| Error::OutOfRange(format!("invalid time zone offset: {}", ts.tz_offset())))
+}
+
+//
+// time::OffsetDateTime
+//
+
+impl FromSql for OffsetDateTime {
+    fn from_sql(val: &SqlValue) -> Result<OffsetDateTime> {
+        let ts = val.to_timestamp()?;
+        Ok(primitive_date_time_from_sql(&ts)?.assume_offset(utc_offset_from_sql(&ts)?))
+    }
+}
+
+impl ToSqlNull for OffsetDateTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::TimestampTZ(9))
+    }
+}
+
+impl ToSql for OffsetDateTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::TimestampTZ(9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = Timestamp::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            self.hour() as u32,
+            self.minute() as u32,
+            self.second() as u32,
+            self.nanosecond(),
+        );
+        let ts = ts.and_tz_offset(self.offset().whole_seconds());
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::PrimitiveDateTime
+//
+
+fn primitive_date_time_from_sql(ts: &Timestamp) -> Result<PrimitiveDateTime> {
+    let time = Time::from_hms_nano(
+        ts.hour() as u8,
+        ts.minute() as u8,
+        ts.second() as u8,
+        ts.nanosecond(),
+    )
+    .map_err(|_| {
+        Error::OutOfRange(format!(
+            "invalid hour-minute-second: {}:{}:{}.{:09}",
+            ts.hour(),
+            ts.minute(),
+            ts.second(),
+            ts.nanosecond()
+        ))
+    })?;
+    Ok(PrimitiveDateTime::new(date_from_sql(ts)?, time))
+}
+
+impl FromSql for PrimitiveDateTime {
+    fn from_sql(val: &SqlValue) -> Result<PrimitiveDateTime> {
+        let ts = val.to_timestamp()?;
+        primitive_date_time_from_sql(&ts)
+    }
+}
+
+impl ToSqlNull for PrimitiveDateTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(9))
+    }
+}
+
+impl ToSql for PrimitiveDateTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = Timestamp::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            self.hour() as u32,
+            self.minute() as u32,
+            self.second() as u32,
+            self.nanosecond(),
+        );
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::Date
+//
+
+fn date_from_sql(ts: &Timestamp) -> Result<Date> {
+    let err = || {
+        Error::OutOfRange(format!(
+            "invalid year-month-day: {}-{}-{}",
+            ts.year(),
+            ts.month(),
+            ts.day()
+        ))
+    };
+    let month = Month::try_from(ts.month() as u8).map_err(|_| err())?;
+    Date::from_calendar_date(ts.year(), month, ts.day() as u8).map_err(|_| err())
+}
+
+impl FromSql for Date {
+    fn from_sql(val: &SqlValue) -> Result<Date> {
+        let ts = val.to_timestamp()?;
+        date_from_sql(&ts)
+    }
+}
+
+impl ToSqlNull for Date {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(0))
+    }
+}
+
+impl ToSql for Date {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(0))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = Timestamp::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            0,
+            0,
+            0,
+            0,
+        );
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::Duration
+//
+
+/// Converts `IntervalDS` to `time::Duration`.
+///
+/// This never fails because the range of `time::Duration` is wider than that
+/// of Oracle intervals.
+impl From<IntervalDS> for Duration {
+    fn from(it: IntervalDS) -> Duration {
+        let secs = it.days() as i64 * 24 * 60 * 60
+            + it.hours() as i64 * 60 * 60
+            + it.minutes() as i64 * 60
+            + it.seconds() as i64;
+        Duration::seconds(secs) + Duration::nanoseconds(it.nanoseconds() as i64)
+    }
+}
+
+/// Converts `time::Duration` to `IntervalDS`.
+///
+/// This fails with [`Error::OutOfRange`] when the number of days
+/// exceeds the range of `INTERVAL DAY(9) TO SECOND(9)`.
+impl TryFrom<Duration> for IntervalDS {
+    type Error = Error;
+
+    fn try_from(d: Duration) -> Result<IntervalDS> {
+        let secs = d.whole_seconds();
+        let nsecs = d.subsec_nanoseconds();
+        let days = secs / (24 * 60 * 60);
+        let secs = secs % (24 * 60 * 60);
+        let hours = secs / (60 * 60);
+        let secs = secs % (60 * 60);
+        let minutes = secs / 60;
+        let secs = secs % 60;
+        if days.abs() >= 1000000000 {
+            return Err(Error::OutOfRange(format!("too large days: {}", d)));
+        }
+        Ok(IntervalDS::new(
+            days as i32,
+            hours as i32,
+            minutes as i32,
+            secs as i32,
+            nsecs,
+        ))
+    }
+}
+
+impl FromSql for Duration {
+    fn from_sql(val: &SqlValue) -> Result<Duration> {
+        Ok(val.to_interval_ds()?.into())
+    }
+}
+
+impl ToSqlNull for Duration {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for Duration {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_interval_ds(&(*self).try_into()?)
+    }
+}
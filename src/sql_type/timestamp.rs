@@ -106,15 +106,16 @@ pub struct Timestamp {
     tz_minute_offset: i32,
     precision: u8,
     with_tz: bool,
+    local_tz: bool,
 }
 
 impl Timestamp {
     pub(crate) fn from_dpi_timestamp(ts: &dpiTimestamp, oratype: &OracleType) -> Timestamp {
-        let (precision, with_tz) = match *oratype {
-            OracleType::Timestamp(prec) => (prec, false),
-            OracleType::TimestampTZ(prec) => (prec, true),
-            OracleType::TimestampLTZ(prec) => (prec, true),
-            _ => (0, false),
+        let (precision, with_tz, local_tz) = match *oratype {
+            OracleType::Timestamp(prec) => (prec, false, false),
+            OracleType::TimestampTZ(prec) => (prec, true, false),
+            OracleType::TimestampLTZ(prec) => (prec, true, true),
+            _ => (0, false, false),
         };
         Timestamp {
             year: ts.year as i32,
@@ -128,6 +129,7 @@ impl Timestamp {
             tz_minute_offset: ts.tzMinuteOffset as i32,
             precision,
             with_tz,
+            local_tz,
         }
     }
 
@@ -166,6 +168,7 @@ impl Timestamp {
             tz_minute_offset: 0,
             precision: 9,
             with_tz: false,
+            local_tz: false,
         }
     }
 
@@ -262,23 +265,73 @@ impl Timestamp {
         self.with_tz
     }
 
+    /// Returns true when the timestamp was fetched from a `TIMESTAMP WITH LOCAL TIME ZONE`
+    /// value. Otherwise, false.
+    ///
+    /// Oracle stores `TIMESTAMP WITH LOCAL TIME ZONE` values normalized to the database
+    /// time zone and converts them to the session time zone when they are fetched.
+    /// So the time zone offset of such a timestamp is that of the session time zone,
+    /// which can be changed by [`Connector::session_timezone`].
+    ///
+    /// [`Connector::session_timezone`]: crate::Connector::session_timezone
+    pub fn is_local_timezone(&self) -> bool {
+        self.local_tz
+    }
+
     /// Returns total time zone offset from UTC in seconds.
     pub fn tz_offset(&self) -> i32 {
         self.tz_hour_offset * 3600 + self.tz_minute_offset * 60
     }
+
+    /// Returns seconds since 1970-01-01 00:00:00 UTC and nanoseconds.
+    fn instant(&self) -> (i64, u32) {
+        // days from civil algorithm in http://howardhinnant.github.io/date_algorithms.html
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = self.month as i64;
+        let doy =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+        let secs =
+            days * 86400 + self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64
+                - self.tz_offset() as i64;
+        (secs, self.nanosecond)
+    }
 }
 
+/// Compares instants of timestamps.
+///
+/// Timestamps are converted to UTC by their time zone offsets before comparison.
+/// Timestamps without time zone are treated as UTC. The precisions are ignored.
+///
+/// ```
+/// # use oracle::sql_type::Timestamp;
+/// let ts1 = Timestamp::new(2017, 8, 9, 10, 0, 0, 0).and_tz_hm_offset(0, 0);
+/// let ts2 = Timestamp::new(2017, 8, 9, 15, 30, 0, 0).and_tz_hm_offset(5, 30);
+/// assert_eq!(ts1, ts2);
+///
+/// let ts3 = Timestamp::new(2017, 8, 9, 10, 0, 0, 0).and_tz_hm_offset(5, 30);
+/// assert!(ts3 < ts1);
+/// ```
 impl cmp::PartialEq for Timestamp {
     fn eq(&self, other: &Self) -> bool {
-        self.year == other.year
-            && self.month == other.month
-            && self.day == other.day
-            && self.hour == other.hour
-            && self.minute == other.minute
-            && self.second == other.second
-            && self.nanosecond == other.nanosecond
-            && self.tz_hour_offset == other.tz_hour_offset
-            && self.tz_minute_offset == other.tz_minute_offset
+        self.instant() == other.instant()
+    }
+}
+
+impl cmp::Eq for Timestamp {}
+
+impl cmp::PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.instant().cmp(&other.instant())
     }
 }
 
@@ -503,6 +556,44 @@ mod tests {
         assert_eq!(ts.to_string(), "-123-03-04 05:06:07 +00:00");
     }
 
+    #[test]
+    fn compare() {
+        let utc = Timestamp::new(2017, 8, 9, 10, 0, 0, 0).and_tz_hm_offset(0, 0);
+        let plus5 = Timestamp::new(2017, 8, 9, 15, 0, 0, 0).and_tz_hm_offset(5, 0);
+        let minus930 = Timestamp::new(2017, 8, 9, 0, 30, 0, 0).and_tz_hm_offset(-9, -30);
+        assert_eq!(utc, plus5);
+        assert_eq!(utc, minus930);
+        assert_eq!(utc.cmp(&plus5), cmp::Ordering::Equal);
+
+        // across date boundaries
+        let ts1 = Timestamp::new(2017, 1, 1, 1, 0, 0, 0).and_tz_hm_offset(2, 0);
+        let ts2 = Timestamp::new(2016, 12, 31, 23, 0, 0, 0);
+        assert_eq!(ts1, ts2);
+        let ts1 = Timestamp::new(2016, 3, 1, 0, 0, 0, 0).and_tz_hm_offset(1, 0);
+        let ts2 = Timestamp::new(2016, 2, 29, 23, 0, 0, 0);
+        assert_eq!(ts1, ts2);
+        let ts1 = Timestamp::new(-100, 1, 1, 0, 0, 0, 0);
+        let ts2 = Timestamp::new(-101, 12, 31, 0, 0, 0, 0);
+        assert!(ts1 > ts2);
+
+        // same local time but different offsets
+        let plus5 = Timestamp::new(2017, 8, 9, 10, 0, 0, 0).and_tz_hm_offset(5, 0);
+        assert_ne!(utc, plus5);
+        assert!(plus5 < utc);
+
+        // precisions and nanoseconds
+        let ts1 = Timestamp::new(2017, 8, 9, 10, 0, 0, 500000000).and_prec(3);
+        let ts2 = Timestamp::new(2017, 8, 9, 10, 0, 0, 500000000).and_prec(9);
+        let ts3 = Timestamp::new(2017, 8, 9, 10, 0, 0, 500000001);
+        assert_eq!(ts1, ts2);
+        assert!(ts1 < ts3);
+        assert!(ts3 > utc);
+
+        let mut v = vec![ts3, ts1, utc];
+        v.sort();
+        assert_eq!(v, vec![utc, ts1, ts3]);
+    }
+
     #[test]
     fn parse() {
         let mut ts = Timestamp::new(
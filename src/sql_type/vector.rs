@@ -0,0 +1,209 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::ParseOracleTypeError;
+use crate::Result;
+use crate::SqlValue;
+use std::fmt;
+use std::result;
+use std::str;
+
+/// Element types of [`Vector`]
+///
+/// | Rust Type | Vector Format |
+/// | --- | --- |
+/// | `f32` | `FLOAT32` |
+/// | `f64` | `FLOAT64` |
+/// | `i8` | `INT8` |
+/// | `u8` | `BINARY` (each element packs 8 dimensions) |
+///
+/// This trait is sealed and cannot be implemented for types outside of the `oracle` crate.
+pub trait VectorElement: Copy + fmt::Display + str::FromStr + crate::private::Sealed {}
+
+impl VectorElement for f32 {}
+impl VectorElement for f64 {}
+impl VectorElement for i8 {}
+impl VectorElement for u8 {}
+
+/// A value of Oracle data type `VECTOR`, which is available since Oracle 23ai
+///
+/// The bundled ODPI-C doesn't support the native vector format. So vectors are
+/// exchanged in the text form such as `[1.5,2.5,3]`. A `Vector` is bound as
+/// a `CLOB`, which Oracle converts to `VECTOR` implicitly when it is inserted
+/// into a `VECTOR` column or passed to vector functions such as `VECTOR_DISTANCE`.
+/// Use `FROM_VECTOR(column)` to fetch a `VECTOR` column as text in the select list.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::Error;
+/// # use oracle::sql_type::Vector;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let embedding = Vector::new(vec![1.5f32, -0.25, 3.0]);
+/// conn.execute("insert into TestVectors values (:1, :2)", &[&1, &embedding])?;
+/// let val = conn.query_row_as::<Vector<f32>>(
+///     "select from_vector(VectorCol) from TestVectors where IntCol = 1",
+///     &[],
+/// )?;
+/// assert_eq!(val, embedding);
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Vector<T> {
+    elements: Vec<T>,
+}
+
+impl<T> Vector<T>
+where
+    T: VectorElement,
+{
+    /// Creates a new vector from elements.
+    pub fn new(elements: Vec<T>) -> Vector<T> {
+        Vector { elements }
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// Consumes the vector and returns its elements.
+    pub fn into_vec(self) -> Vec<T> {
+        self.elements
+    }
+}
+
+impl<T> From<Vec<T>> for Vector<T>
+where
+    T: VectorElement,
+{
+    fn from(elements: Vec<T>) -> Vector<T> {
+        Vector::new(elements)
+    }
+}
+
+impl<T> fmt::Display for Vector<T>
+where
+    T: VectorElement,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, elem) in self.elements.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", elem)?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl<T> str::FromStr for Vector<T>
+where
+    T: VectorElement,
+{
+    type Err = ParseOracleTypeError;
+
+    fn from_str(s: &str) -> result::Result<Vector<T>, ParseOracleTypeError> {
+        let err = || ParseOracleTypeError::new("Vector");
+        let s = s
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(err)?
+            .trim();
+        if s.is_empty() {
+            return Ok(Vector::new(Vec::new()));
+        }
+        let elements = s
+            .split(',')
+            .map(|elem| elem.trim().parse().map_err(|_| err()))
+            .collect::<result::Result<Vec<T>, _>>()?;
+        Ok(Vector::new(elements))
+    }
+}
+
+impl<T> FromSql for Vector<T>
+where
+    T: VectorElement,
+{
+    fn from_sql(val: &SqlValue) -> Result<Vector<T>> {
+        Ok(val.to_string()?.parse()?)
+    }
+}
+
+impl<T> ToSqlNull for Vector<T>
+where
+    T: VectorElement,
+{
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::CLOB)
+    }
+}
+
+impl<T> ToSql for Vector<T>
+where
+    T: VectorElement,
+{
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::CLOB)
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_string(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(
+            Vector::new(vec![1.5f32, -0.25, 3.0]).to_string(),
+            "[1.5,-0.25,3]"
+        );
+        assert_eq!(Vector::new(vec![1i8, -2, 3]).to_string(), "[1,-2,3]");
+        assert_eq!(Vector::<f64>::new(vec![]).to_string(), "[]");
+    }
+
+    #[test]
+    fn from_str() {
+        // text form returned by FROM_VECTOR
+        assert_eq!(
+            "[1.10000002E+000,-2.5E-001]".parse(),
+            Ok(Vector::new(vec![1.1f32, -0.25]))
+        );
+        assert_eq!(" [ 1 , 2 ] ".parse(), Ok(Vector::new(vec![1.0f64, 2.0])));
+        assert_eq!("[255,0]".parse(), Ok(Vector::new(vec![255u8, 0])));
+        assert_eq!("[]".parse(), Ok(Vector::<i8>::new(vec![])));
+        assert!("1,2".parse::<Vector<f32>>().is_err());
+        assert!("[1,]".parse::<Vector<f32>>().is_err());
+        assert!("[128]".parse::<Vector<i8>>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let vec = Vector::new(vec![0.1f32, 1e-10, f32::MAX]);
+        assert_eq!(vec.to_string().parse(), Ok(vec));
+    }
+}
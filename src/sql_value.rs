@@ -13,6 +13,7 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use std::cell::RefCell;
 use std::convert::TryInto;
 use std::fmt;
 use std::os::raw::c_char;
@@ -48,6 +49,7 @@ use crate::util::parse_str_into_raw;
 use crate::util::set_hex_string;
 use crate::Connection;
 use crate::Context;
+use crate::DpiStmt;
 use crate::Error;
 use crate::Result;
 
@@ -141,6 +143,42 @@ pub struct SqlValue {
     keep_dpiobj: *mut dpiObject,
     pub(crate) lob_bind_type: LobBindType,
     pub(crate) query_params: QueryParams,
+    // the statement containing cursor columns, which must be alive
+    // while ref cursors fetched from the columns are used.
+    pub(crate) parent_stmt: Option<DpiStmt>,
+    // parameters used to create the handle. They are reused to duplicate
+    // fetched column values so that the oracle type isn't inspected for
+    // each row.
+    var_param: Option<VarParam>,
+    // the variable which values duplicated from this column value are copied to
+    dup_buffer: RefCell<Option<DupBuffer>>,
+}
+
+// Parameters to create a dpiVar handle, derived from an oracle type and
+// the LOB bind type.
+#[derive(Clone)]
+struct VarParam {
+    oratype_num: u32,
+    native_type: NativeType,
+    size: u32,
+    size_is_byte: i32,
+}
+
+// A variable whose elements are used by values duplicated from a fetched
+// column value. The elements are handed out in order and each duplicated
+// value holds a reference to the variable. This reduces the number of
+// variables created to one per fetch array size rows for each column.
+// Note that the variable is freed after all values using it are dropped.
+struct DupBuffer {
+    handle: *mut dpiVar,
+    data: *mut dpiData,
+    next_index: u32,
+}
+
+impl Drop for DupBuffer {
+    fn drop(&mut self) {
+        unsafe { dpiVar_release(self.handle) };
+    }
 }
 
 impl SqlValue {
@@ -162,6 +200,9 @@ impl SqlValue {
             keep_dpiobj: ptr::null_mut(),
             lob_bind_type,
             query_params,
+            parent_stmt: None,
+            var_param: None,
+            dup_buffer: RefCell::new(None),
         }
     }
 
@@ -192,6 +233,9 @@ impl SqlValue {
             keep_dpiobj: ptr::null_mut(),
             lob_bind_type: LobBindType::Locator,
             query_params: QueryParams::new(),
+            parent_stmt: None,
+            var_param: None,
+            dup_buffer: RefCell::new(None),
         })
     }
 
@@ -228,12 +272,6 @@ impl SqlValue {
         if self.handle_is_reusable(oratype)? {
             return Ok(false);
         }
-        if !self.handle.is_null() {
-            unsafe { dpiVar_release(self.handle) };
-        }
-        self.handle = ptr::null_mut();
-        let mut handle: *mut dpiVar = ptr::null_mut();
-        let mut data: *mut dpiData = ptr::null_mut();
         let (oratype_num, native_type, size, size_is_byte) = match self.lob_bind_type {
             LobBindType::Bytes => match oratype {
                 OracleType::CLOB => &OracleType::Long,
@@ -249,27 +287,50 @@ impl SqlValue {
             LobBindType::Locator => oratype,
         }
         .var_create_param()?;
-        let native_type_num = native_type.to_native_type_num();
-        let object_type_handle = native_type.to_object_type_handle();
+        let param = VarParam {
+            oratype_num,
+            native_type,
+            size,
+            size_is_byte,
+        };
+        self.create_handle(oratype, param)?;
+        Ok(true)
+    }
+
+    fn new_var(&self, param: &VarParam, array_size: u32) -> Result<(*mut dpiVar, *mut dpiData)> {
+        let mut handle: *mut dpiVar = ptr::null_mut();
+        let mut data: *mut dpiData = ptr::null_mut();
         chkerr!(
             self.ctxt(),
             dpiConn_newVar(
                 self.conn.handle.raw(),
-                oratype_num,
-                native_type_num,
-                self.array_size,
-                size,
-                size_is_byte,
+                param.oratype_num,
+                param.native_type.to_native_type_num(),
+                array_size,
+                param.size,
+                param.size_is_byte,
                 0,
-                object_type_handle,
+                param.native_type.to_object_type_handle(),
                 &mut handle,
                 &mut data
             )
         );
+        Ok((handle, data))
+    }
+
+    fn create_handle(&mut self, oratype: &OracleType, param: VarParam) -> Result<()> {
+        if !self.handle.is_null() {
+            unsafe { dpiVar_release(self.handle) };
+        }
+        self.handle = ptr::null_mut();
+        *self.dup_buffer.get_mut() = None;
+        let native_type_num = param.native_type.to_native_type_num();
+        let (handle, data) = self.new_var(&param, self.array_size)?;
         self.handle = handle;
         self.data = data;
-        self.native_type = native_type;
+        self.native_type = param.native_type.clone();
         self.oratype = Some(oratype.clone());
+        self.var_param = Some(param);
         if native_type_num == DPI_NATIVE_TYPE_STMT {
             for i in 0..self.array_size {
                 let handle = unsafe { dpiData_getStmt(data.offset(i as isize)) };
@@ -278,7 +339,7 @@ impl SqlValue {
                 }
             }
         }
-        Ok(true)
+        Ok(())
     }
 
     pub(crate) fn fix_internal_data(&mut self) -> Result<()> {
@@ -312,13 +373,14 @@ impl SqlValue {
 
     /// Gets the Oracle value. It internally does the followings:
     ///
-    /// 1. Checks whether the conversion from the Oracle type to the target rust type
+    /// 1. When the return type is `Option<FromSql>`, checks whether the Oracle
+    ///    value is null at first. It returns `Ok(None)` when it is null.
+    /// 2. Checks whether the conversion from the Oracle type to the target rust type
     ///    is allowed. It returns `Err(Error::InvalidTypeConversion(...))` when it
     ///    isn't allowed.
-    /// 2. Checks whether the Oracle value is null. When it is null and the return
-    ///    type is `Option<FromSql>`, it returns `Ok(None)`. When it is null and it
-    ///    isn't `Option<FromSql>`, it returns `Err(Error::NullValue)`.
-    /// 3. Converts the Oracle value to the rust value. The data type is converted
+    /// 3. Checks whether the Oracle value is null. When it is null, it returns
+    ///    `Err(Error::NullValue)`.
+    /// 4. Converts the Oracle value to the rust value. The data type is converted
     ///    implicitly if required. For example string is converted to i64 by
     ///    [`str::parse`] if `get::<i64>()` is called for `VARCHAR2` columns.
     ///    If the conversion fails, various errors are returned.
@@ -384,6 +446,9 @@ impl SqlValue {
 
     /// Returns `Ok(true)` when the SQL value is null. `Ok(false)` when it isn't null.
     pub fn is_null(&self) -> Result<bool> {
+        if self.data.is_null() {
+            return Err(Error::UninitializedBindValue);
+        }
         unsafe { Ok((*self.data()).isNull != 0) }
     }
 
@@ -723,14 +788,9 @@ impl SqlValue {
     /// NativeType::IntervalDS. Otherwise, this may cause access violation.
     fn set_interval_ds_unchecked(&mut self, val: &IntervalDS) -> Result<()> {
         unsafe {
-            dpiData_setIntervalDS(
-                self.data(),
-                val.days(),
-                val.hours(),
-                val.minutes(),
-                val.seconds(),
-                val.nanoseconds(),
-            )
+            let data = &mut *self.data();
+            data.value.asIntervalDS = val.to_dpi_interval_ds();
+            data.isNull = 0;
         }
         Ok(())
     }
@@ -805,6 +865,54 @@ impl SqlValue {
         self.dup_by_handle()
     }
 
+    // Duplicates a fetched column value into an element of the variable
+    // shared by values duplicated from this value. Other values are
+    // duplicated by dup_by_handle.
+    pub(crate) fn dup_column_value(&self) -> Result<SqlValue> {
+        let (oratype, param) = match (&self.oratype, &self.var_param) {
+            (Some(oratype), Some(param))
+                if self.array_size > 1 && param.native_type != NativeType::Stmt =>
+            {
+                (oratype, param)
+            }
+            _ => return self.dup_by_handle(),
+        };
+        let mut dup_buffer = self.dup_buffer.borrow_mut();
+        if dup_buffer
+            .as_ref()
+            .map_or(true, |buf| buf.next_index >= self.array_size)
+        {
+            *dup_buffer = None;
+            let (handle, data) = self.new_var(param, self.array_size)?;
+            *dup_buffer = Some(DupBuffer {
+                handle,
+                data,
+                next_index: 0,
+            });
+        }
+        let buf = dup_buffer.as_mut().unwrap();
+        let index = buf.next_index;
+        chkerr!(
+            self.ctxt(),
+            dpiVar_copyData(buf.handle, index, self.handle, self.buffer_row_index())
+        );
+        buf.next_index += 1;
+        chkerr!(self.ctxt(), dpiVar_addRef(buf.handle));
+        let mut val = SqlValue::new(
+            self.conn.clone(),
+            self.lob_bind_type,
+            self.query_params.clone(),
+            self.array_size,
+        );
+        val.handle = buf.handle;
+        val.data = buf.data;
+        val.native_type = param.native_type.clone();
+        val.oratype = Some(oratype.clone());
+        val.var_param = Some(param.clone());
+        val.buffer_row_index = BufferRowIndex::Owned(index);
+        Ok(val)
+    }
+
     pub(crate) fn dup_by_handle(&self) -> Result<SqlValue> {
         let mut val = SqlValue::new(
             self.conn.clone(),
@@ -813,7 +921,12 @@ impl SqlValue {
             1,
         );
         if let Some(ref oratype) = self.oratype {
-            val.init_handle(oratype)?;
+            match self.var_param {
+                Some(ref param) => val.create_handle(oratype, param.clone())?,
+                None => {
+                    val.init_handle(oratype)?;
+                }
+            }
             chkerr!(
                 self.ctxt(),
                 dpiVar_copyData(val.handle, 0, self.handle, self.buffer_row_index()),
@@ -954,6 +1067,43 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as string into `buf`, reusing its allocated memory.
+    /// The result is same with [`SqlValue::to_string`].
+    pub(crate) fn to_string_into(&self, buf: &mut String) -> Result<()> {
+        match self.native_type {
+            NativeType::Char | NativeType::Number => {
+                self.check_not_null()?;
+                let s = unsafe {
+                    let bytes = dpiData_getBytes(self.data());
+                    to_rust_slice((*bytes).ptr, (*bytes).length)
+                };
+                buf.clear();
+                buf.push_str(&String::from_utf8_lossy(s));
+            }
+            _ => {
+                let s = self.to_string()?;
+                buf.clear();
+                buf.push_str(&s);
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the SQL value as a slice in the fetch buffer without copying.
+    /// The Oracle type must be `RAW`.
+    pub(crate) fn as_raw_slice(&self) -> Result<&[u8]> {
+        match self.oracle_type()? {
+            OracleType::Raw(_) => {
+                self.check_not_null()?;
+                unsafe {
+                    let bytes = dpiData_getBytes(self.data());
+                    Ok(to_rust_slice((*bytes).ptr, (*bytes).length))
+                }
+            }
+            _ => self.invalid_conversion_to_rust_type("&[u8]"),
+        }
+    }
+
     /// Gets the SQL value as Vec\<u8>. ...
     pub(crate) fn to_bytes(&self) -> Result<Vec<u8>> {
         match self.native_type {
@@ -1079,6 +1229,7 @@ impl SqlValue {
                 self.conn.clone(),
                 self.get_stmt_unchecked()?,
                 self.query_params.clone(),
+                self.parent_stmt.clone(),
             )?),
             _ => self.invalid_conversion_to_rust_type("RefCursor"),
         }
@@ -1279,6 +1430,9 @@ impl SqlValue {
             keep_dpiobj: ptr::null_mut(),
             lob_bind_type: self.lob_bind_type,
             query_params: self.query_params.clone(),
+            parent_stmt: self.parent_stmt.clone(),
+            var_param: None,
+            dup_buffer: RefCell::new(None),
         }
     }
 }
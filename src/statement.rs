@@ -16,6 +16,8 @@
 #[cfg(feature = "stmt_without_lifetime")]
 use oracle_procmacro::remove_stmt_lifetime;
 use std::borrow::ToOwned;
+use std::cell::Cell;
+use std::cmp;
 use std::fmt;
 #[cfg(not(feature = "stmt_without_lifetime"))]
 use std::marker::PhantomData;
@@ -24,6 +26,7 @@ use std::os::raw::c_char;
 use std::ptr;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 
 use crate::binding::*;
 use crate::chkerr;
@@ -41,9 +44,12 @@ use crate::sql_type::{Blob, Clob, Nclob};
 use crate::sql_value::BufferRowIndex;
 use crate::to_odpi_str;
 use crate::to_rust_str;
+use crate::util::returning_into_bind_names;
 use crate::Connection;
 use crate::Context;
+use crate::DpiStmt;
 use crate::Error;
+use crate::FromRowInPlace;
 use crate::Result;
 use crate::ResultSet;
 use crate::Row;
@@ -68,6 +74,7 @@ pub struct QueryParams {
     pub fetch_array_size: u32,
     pub prefetch_rows: Option<u32>,
     pub lob_bind_type: LobBindType,
+    pub lob_prefetch_size: Option<u32>,
 }
 
 impl QueryParams {
@@ -76,6 +83,7 @@ impl QueryParams {
             fetch_array_size: DPI_DEFAULT_FETCH_ARRAY_SIZE,
             prefetch_rows: None,
             lob_bind_type: LobBindType::Bytes,
+            lob_prefetch_size: None,
         }
     }
 }
@@ -88,6 +96,7 @@ pub struct StatementBuilder<'conn, 'sql> {
     scrollable: bool,
     tag: String,
     exclude_from_cache: bool,
+    cancel_on_drop: bool,
 }
 
 #[cfg_attr(feature = "stmt_without_lifetime", remove_stmt_lifetime)]
@@ -96,19 +105,21 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
         StatementBuilder {
             conn,
             sql,
-            query_params: QueryParams::new(),
+            query_params: conn.conn.default_query_params(),
             scrollable: false,
             tag: "".into(),
             exclude_from_cache: false,
+            cancel_on_drop: conn.conn.cancel_on_drop(),
         }
     }
 
     /// Changes the array size used for performing fetches.
     ///
     /// This specifies the number of rows allocated before performing
-    /// fetches. The default value is 100. Higher value reduces
-    /// the number of network round trips to fetch rows but requires
-    /// more memory. The preferable value depends on the query and
+    /// fetches. The default value is 100 or the value set by
+    /// [`Connector::default_fetch_array_size`](crate::Connector::default_fetch_array_size).
+    /// Higher value reduces the number of network round trips to fetch
+    /// rows but requires more memory. The preferable value depends on the query and
     /// the environment.
     ///
     /// If the query returns only onw row, it is better to change
@@ -133,8 +144,9 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
 
     /// The number of rows that will be prefetched by the Oracle Client
     /// library when a query is executed. The default value is
-    /// DPI_DEFAULT_PREFETCH_ROWS (2). Increasing this value may reduce
-    /// the number of round-trips to the database that are required in
+    /// DPI_DEFAULT_PREFETCH_ROWS (2) or the value set by
+    /// [`Connector::default_prefetch_rows`](crate::Connector::default_prefetch_rows).
+    /// Increasing this value may reduce the number of round-trips to the database that are required in
     /// order to fetch rows, but at the cost of increasing memory
     /// requirements.
     /// Setting this value to 0 will disable prefetch completely,
@@ -188,7 +200,10 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
     }
 
     // make the visibility public when scrollable cursors is supported.
-    fn scrollable<'a>(&'a mut self, scrollable: bool) -> &'a mut StatementBuilder<'conn, 'sql> {
+    pub(crate) fn scrollable<'a>(
+        &'a mut self,
+        scrollable: bool,
+    ) -> &'a mut StatementBuilder<'conn, 'sql> {
         self.scrollable = scrollable;
         self
     }
@@ -254,16 +269,152 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
     }
 
     /// Excludes the statement from the cache even when stmt_cache_size is not zero.
+    ///
+    /// The bind metadata cache in the connection is also bypassed.
     pub fn exclude_from_cache<'a>(&'a mut self) -> &'a mut StatementBuilder<'conn, 'sql> {
         self.exclude_from_cache = true;
         self
     }
 
+    /// Enables or disables canceling the query in progress when the statement
+    /// is dropped. The default value is the value set by [`Connection::set_cancel_on_drop`],
+    /// which is false unless it is changed.
+    ///
+    /// A query is in progress after it is executed until all rows are fetched.
+    /// The cursor of a query in progress is kept open on the server even after
+    /// the statement is dropped when it is returned to the statement cache.
+    /// When this is enabled, such a statement is removed from the statement cache
+    /// on drop so that the cursor is closed and the server stops the query.
+    /// Statements whose rows are fetched completely are returned to the cache as usual.
+    ///
+    /// A statement cannot be dropped while its own execution or fetch is running
+    /// in this crate. Use [`Connection::break_execution`] from another thread
+    /// to cancel such a call.
+    ///
+    /// Note that a result set borrowing the statement, such as one returned by
+    /// [`Statement::query`], doesn't cancel the query on drop because the
+    /// statement keeps the cursor. The query is canceled when the statement is
+    /// dropped. A result set owning the statement, such as one returned by
+    /// [`Connection::query`], cancels the query on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select level from dual connect by level <= 1000000")
+    ///     .cancel_on_drop(true)
+    ///     .build()?;
+    /// let first = stmt.query_row_as::<i32>(&[])?;
+    /// assert_eq!(first, 1);
+    /// // The query, whose rows aren't fetched completely, is canceled here.
+    /// drop(stmt);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn cancel_on_drop<'a>(
+        &'a mut self,
+        enabled: bool,
+    ) -> &'a mut StatementBuilder<'conn, 'sql> {
+        self.cancel_on_drop = enabled;
+        self
+    }
+
     pub fn build(&self) -> Result<Statement<'conn>> {
         Statement::new(self)
     }
 }
 
+/// Options of [`Connection::execute_with_options`]
+///
+/// [`Connection::execute`] is same with [`Connection::execute_with_options`]
+/// with the default options.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::ExecuteOptions;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// # conn.execute("delete from TestTempTable", &[])?;
+/// // Commit the insert statement on success even when autocommit mode is off.
+/// let opts = ExecuteOptions::new().commit_on_success(true).clone();
+/// conn.execute_with_options(
+///     "insert into TestTempTable values(:1, :2)",
+///     &[&1, &"one"],
+///     &opts,
+/// )?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecuteOptions {
+    commit_on_success: Option<bool>,
+    fetch_array_size: Option<u32>,
+    parse_only: bool,
+}
+
+impl ExecuteOptions {
+    /// Creates options with default values.
+    pub fn new() -> ExecuteOptions {
+        ExecuteOptions::default()
+    }
+
+    /// Specifies whether the current transaction is committed when the
+    /// statement is executed successfully.
+    ///
+    /// When this isn't set, [`Connection::autocommit`] decides it.
+    pub fn commit_on_success(&mut self, commit: bool) -> &mut ExecuteOptions {
+        self.commit_on_success = Some(commit);
+        self
+    }
+
+    /// Changes the array size used for performing fetches.
+    ///
+    /// See [`StatementBuilder::fetch_array_size`].
+    pub fn fetch_array_size(&mut self, size: u32) -> &mut ExecuteOptions {
+        self.fetch_array_size = Some(size);
+        self
+    }
+
+    /// Parses the statement without executing it.
+    ///
+    /// This is useful to check syntax errors of the statement.
+    pub fn parse_only(&mut self, parse_only: bool) -> &mut ExecuteOptions {
+        self.parse_only = parse_only;
+        self
+    }
+
+    /// Returns the value set by [`ExecuteOptions::commit_on_success`].
+    /// `None` means that the autocommit mode of the connection is used.
+    pub fn commit_on_success_value(&self) -> Option<bool> {
+        self.commit_on_success
+    }
+
+    /// Returns the value set by [`ExecuteOptions::fetch_array_size`].
+    /// `None` means that the default value is used.
+    pub fn fetch_array_size_value(&self) -> Option<u32> {
+        self.fetch_array_size
+    }
+
+    /// Returns the value set by [`ExecuteOptions::parse_only`].
+    pub fn is_parse_only(&self) -> bool {
+        self.parse_only
+    }
+
+    fn exec_mode(&self, autocommit: bool) -> dpiExecMode {
+        let mut exec_mode = DPI_MODE_EXEC_DEFAULT;
+        if self.commit_on_success.unwrap_or(autocommit) {
+            exec_mode |= DPI_MODE_EXEC_COMMIT_ON_SUCCESS;
+        }
+        if self.parse_only {
+            exec_mode |= DPI_MODE_EXEC_PARSE_ONLY;
+        }
+        exec_mode
+    }
+}
+
 /// Parameters of [`Connection::prepare`]
 ///
 /// No new variants are added to this enum in the future. That's because
@@ -290,6 +441,61 @@ pub enum StmtParam {
     Scrollable,
 }
 
+/// Metadata derived from a prepared statement, which is cached per SQL text
+/// in the connection.
+#[derive(Debug)]
+pub(crate) struct BindMetadata {
+    statement_type: StatementType,
+    is_returning: bool,
+    // number of bind placeholders, including duplicated names
+    bind_count: usize,
+    bind_names: Vec<String>,
+    // zero-based indexes of bind variables in the RETURNING INTO clause
+    returning_binds: Vec<usize>,
+}
+
+impl BindMetadata {
+    fn new(ctxt: &Context, handle: *mut dpiStmt, sql: &str) -> Result<BindMetadata> {
+        let mut info = MaybeUninit::uninit();
+        chkerr!(ctxt, dpiStmt_getInfo(handle, info.as_mut_ptr()));
+        let info = unsafe { info.assume_init() };
+        let mut num = 0;
+        chkerr!(ctxt, dpiStmt_getBindCount(handle, &mut num));
+        let bind_count = num as usize;
+        let mut bind_names = Vec::with_capacity(bind_count);
+        if bind_count > 0 {
+            let mut names: Vec<*const c_char> = vec![ptr::null_mut(); bind_count];
+            let mut lengths = vec![0; bind_count];
+            chkerr!(
+                ctxt,
+                dpiStmt_getBindNames(handle, &mut num, names.as_mut_ptr(), lengths.as_mut_ptr())
+            );
+            for i in 0..(num as usize) {
+                bind_names.push(to_rust_str(names[i], lengths[i]));
+            }
+        }
+        let returning_binds = if info.isReturning != 0 {
+            returning_into_bind_names(sql)
+                .into_iter()
+                .filter_map(|name| {
+                    bind_names
+                        .iter()
+                        .position(|bind_name| bind_name.eq_ignore_ascii_case(name))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Ok(BindMetadata {
+            statement_type: StatementType::from_enum(info.statementType),
+            is_returning: info.isReturning != 0,
+            bind_count,
+            bind_names,
+            returning_binds,
+        })
+    }
+}
+
 /// Statement type returned by [`Statement::statement_type`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum StatementType {
@@ -398,6 +604,9 @@ pub(crate) struct Stmt {
     shared_buffer_row_index: Rc<AtomicU32>,
     pub(crate) query_params: QueryParams,
     tag: String,
+    cancel_on_drop: bool,
+    // true after a query is executed until all rows are fetched
+    fetch_in_progress: Cell<bool>,
 }
 
 impl Stmt {
@@ -407,6 +616,8 @@ impl Stmt {
         query_params: QueryParams,
         tag: String,
     ) -> Stmt {
+        conn.open_stmt_count.fetch_add(1, Ordering::Relaxed);
+        let cancel_on_drop = conn.cancel_on_drop();
         Stmt {
             conn,
             handle,
@@ -415,6 +626,8 @@ impl Stmt {
             shared_buffer_row_index: Rc::new(AtomicU32::new(0)),
             query_params,
             tag,
+            cancel_on_drop,
+            fetch_in_progress: Cell::new(false),
         }
     }
 
@@ -431,12 +644,23 @@ impl Stmt {
     }
 
     fn close(&mut self) -> Result<()> {
+        if self.cancel_on_drop && self.fetch_in_progress.replace(false) {
+            // Close the cursor instead of keeping it open in the statement cache.
+            chkerr!(self.ctxt(), dpiStmt_deleteFromCache(self.handle));
+        }
         let tag = to_odpi_str(&self.tag);
         chkerr!(self.ctxt(), dpiStmt_close(self.handle, tag.ptr, tag.len));
         Ok(())
     }
 
     pub(crate) fn init_row(&mut self, num_cols: usize) -> Result<()> {
+        let conn = self.conn.clone();
+        conn.define_with_lob_prefetch_size(self.query_params.lob_prefetch_size, || {
+            self.define_columns(num_cols)
+        })
+    }
+
+    fn define_columns(&mut self, num_cols: usize) -> Result<()> {
         let mut column_names = Vec::with_capacity(num_cols);
         let mut column_values = Vec::with_capacity(num_cols);
         self.column_info = Vec::with_capacity(num_cols);
@@ -463,6 +687,10 @@ impl Stmt {
                 }
                 _ => oratype,
             };
+            if *oratype == OracleType::RefCursor {
+                // Keep the statement alive while cursors fetched from the column are used.
+                val.parent_stmt = Some(DpiStmt::with_add_ref(self.handle));
+            }
             val.init_handle(oratype)?;
             chkerr!(
                 self.ctxt(),
@@ -479,7 +707,8 @@ impl Stmt {
         let mut buffer_row_index = 0;
         chkerr!(
             self.ctxt(),
-            dpiStmt_fetch(self.handle, &mut found, &mut buffer_row_index)
+            dpiStmt_fetch(self.handle, &mut found, &mut buffer_row_index),
+            self.fetch_in_progress.set(false)
         );
         Ok(if found != 0 {
             self.shared_buffer_row_index
@@ -487,6 +716,7 @@ impl Stmt {
             // if self.row.is_none(), dpiStmt_fetch() returns non-zero.
             Some(self.row.as_ref().unwrap())
         } else {
+            self.fetch_in_progress.set(false);
             None
         })
     }
@@ -500,18 +730,38 @@ impl Stmt {
         chkerr!(self.ctxt(), dpiStmt_getRowCount(self.handle, &mut count));
         Ok(count)
     }
+
+    pub(crate) fn column_index(&self, name: &str) -> Option<usize> {
+        find_column_index(self.column_info.iter().map(|ci| ci.name()), name)
+    }
 }
 
 impl Drop for Stmt {
     fn drop(&mut self) {
         let _ = self.close();
         unsafe { dpiStmt_release(self.handle) };
+        self.conn.open_stmt_count.fetch_sub(1, Ordering::Relaxed);
     }
 }
 
 /// Statement
+///
+/// The [`Debug`](fmt::Debug) output contains the SQL text, the statement type
+/// and bind variable names. It doesn't contain bind values not to leak
+/// secrets such as passwords to logs.
+///
+/// # Lifetime
+///
+/// A statement borrows the [`Connection`] which created it, so it is a compile
+/// error to use the statement after the connection is dropped. When
+/// `stmt_without_lifetime` feature is enabled, the borrow is removed.
+///
+/// In both cases the statement internally holds a reference-counted handle of the
+/// underlying session. The session is kept open until all statements and result
+/// sets created from it, such as one returned by [`Statement::into_result_set`],
+/// are dropped. So ODPI-C statement handles are always released before the
+/// connection handle.
 #[cfg_attr(feature = "stmt_without_lifetime", remove_stmt_lifetime)]
-#[derive(Debug)]
 pub struct Statement<'conn> {
     pub(crate) stmt: Stmt,
     statement_type: StatementType,
@@ -519,10 +769,24 @@ pub struct Statement<'conn> {
     bind_count: usize,
     bind_names: Vec<String>,
     bind_values: Vec<SqlValue>,
+    returning_binds: Vec<usize>,
     #[cfg(not(feature = "stmt_without_lifetime"))]
     phantom: PhantomData<&'conn ()>,
 }
 
+#[cfg_attr(feature = "stmt_without_lifetime", remove_stmt_lifetime)]
+impl<'conn> fmt::Debug for Statement<'conn> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sql = self.oci_attr::<oci_attr::Statement>();
+        f.debug_struct("Statement")
+            .field("sql", &sql.as_deref().unwrap_or("?"))
+            .field("statement_type", &self.statement_type)
+            .field("bind_count", &self.bind_count)
+            .field("bind_names", &self.bind_names)
+            .finish()
+    }
+}
+
 #[cfg_attr(feature = "stmt_without_lifetime", remove_stmt_lifetime)]
 impl<'conn> Statement<'conn> {
     pub(crate) fn from_params(
@@ -549,6 +813,7 @@ impl<'conn> Statement<'conn> {
 
     fn new(builder: &StatementBuilder<'conn, '_>) -> Result<Statement<'conn>> {
         let conn = builder.conn;
+        conn.conn.check_open_statements()?;
         let sql = to_odpi_str(builder.sql);
         let tag = to_odpi_str(&builder.tag);
         let mut handle: *mut dpiStmt = ptr::null_mut();
@@ -564,46 +829,35 @@ impl<'conn> Statement<'conn> {
                 &mut handle
             )
         );
-        let mut info = MaybeUninit::uninit();
-        chkerr!(
-            conn.ctxt(),
-            dpiStmt_getInfo(handle, info.as_mut_ptr()),
-            unsafe {
-                dpiStmt_release(handle);
-            }
-        );
-        let info = unsafe { info.assume_init() };
-        let mut num = 0;
-        chkerr!(
-            conn.ctxt(),
-            dpiStmt_getBindCount(handle, &mut num),
-            unsafe {
-                dpiStmt_release(handle);
-            }
-        );
-        let bind_count = num as usize;
-        let mut bind_names = Vec::with_capacity(bind_count);
-        let mut bind_values = Vec::with_capacity(bind_count);
-        if bind_count > 0 {
-            let mut names: Vec<*const c_char> = vec![ptr::null_mut(); bind_count];
-            let mut lengths = vec![0; bind_count];
-            chkerr!(
-                conn.ctxt(),
-                dpiStmt_getBindNames(handle, &mut num, names.as_mut_ptr(), lengths.as_mut_ptr()),
-                unsafe {
-                    dpiStmt_release(handle);
+        let metadata = if builder.exclude_from_cache {
+            None
+        } else {
+            conn.conn.cached_bind_metadata(builder.sql)
+        };
+        let metadata = match metadata {
+            Some(metadata) => metadata,
+            None => {
+                let metadata = match BindMetadata::new(conn.ctxt(), handle, builder.sql) {
+                    Ok(metadata) => Arc::new(metadata),
+                    Err(err) => {
+                        unsafe { dpiStmt_release(handle) };
+                        return Err(err);
+                    }
+                };
+                conn.conn
+                    .bind_metadata_derive_count
+                    .fetch_add(1, Ordering::Relaxed);
+                if !builder.exclude_from_cache {
+                    conn.conn.cache_bind_metadata(builder.sql, metadata.clone());
                 }
-            );
-            bind_names = Vec::with_capacity(num as usize);
-            for i in 0..(num as usize) {
-                bind_names.push(to_rust_str(names[i], lengths[i]));
-                bind_values.push(SqlValue::for_bind(
-                    conn.conn.clone(),
-                    builder.query_params.clone(),
-                    1,
-                ));
+                metadata
             }
         };
+        let bind_count = metadata.bind_count;
+        let bind_names = metadata.bind_names.clone();
+        let bind_values = (0..bind_count)
+            .map(|_| SqlValue::for_bind(conn.conn.clone(), builder.query_params.clone(), 1))
+            .collect();
         let tag = if builder.exclude_from_cache {
             chkerr!(conn.ctxt(), dpiStmt_deleteFromCache(handle), unsafe {
                 dpiStmt_release(handle);
@@ -612,13 +866,16 @@ impl<'conn> Statement<'conn> {
         } else {
             builder.tag.clone()
         };
+        let mut stmt = Stmt::new(conn.conn.clone(), handle, builder.query_params.clone(), tag);
+        stmt.cancel_on_drop = builder.cancel_on_drop;
         Ok(Statement {
-            stmt: Stmt::new(conn.conn.clone(), handle, builder.query_params.clone(), tag),
-            statement_type: StatementType::from_enum(info.statementType),
-            is_returning: info.isReturning != 0,
+            stmt,
+            statement_type: metadata.statement_type,
+            is_returning: metadata.is_returning,
             bind_count,
             bind_names,
             bind_values,
+            returning_binds: metadata.returning_binds.clone(),
             #[cfg(not(feature = "stmt_without_lifetime"))]
             phantom: PhantomData,
         })
@@ -769,6 +1026,119 @@ impl<'conn> Statement<'conn> {
         rows.next().unwrap_or(Err(Error::NoDataFound))
     }
 
+    /// Fetches the next row of the last executed query into `target`.
+    ///
+    /// This overwrites `target` by the next row instead of allocating a new value
+    /// for each row. It returns `Ok(true)` when a row is fetched and `Ok(false)`
+    /// when no more rows are available. The query must be executed by
+    /// [`Statement::query`] or [`Statement::query_named`] in advance.
+    ///
+    /// See [`FromRowInPlace`].
+    ///
+    /// [`FromRowInPlace`]: crate::FromRowInPlace
+    pub fn fetch_into<T>(&mut self, target: &mut T) -> Result<bool>
+    where
+        T: FromRowInPlace,
+    {
+        self.check_stmt_type(true, "fetch_into")?;
+        if self.stmt.row.is_none() {
+            return Err(Error::InvalidOperation(
+                "Could not use the `fetch_into` method before the query is executed".into(),
+            ));
+        }
+        match self.stmt.next() {
+            Some(row) => {
+                target.set_from_row(row?)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Fetches the next row of the last executed query.
+    ///
+    /// This returns `Ok(None)` when no more rows are available instead of
+    /// an error. The query must be executed by [`Statement::query`] or
+    /// [`Statement::query_named`] in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select level from dual connect by level <= 3")
+    ///     .build()?;
+    /// stmt.query(&[])?;
+    /// while let Some(row) = stmt.fetch_opt()? {
+    ///     let level: i32 = row.get(0)?;
+    ///     println!("level: {}", level);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn fetch_opt(&mut self) -> Result<Option<Row>> {
+        self.check_stmt_type(true, "fetch_opt")?;
+        if self.stmt.row.is_none() {
+            return Err(Error::InvalidOperation(
+                "Could not use the `fetch_opt` method before the query is executed".into(),
+            ));
+        }
+        match self.stmt.next() {
+            Some(row) => Ok(Some(row?.get_as::<Row>()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches up to `n` rows of the last executed query.
+    ///
+    /// Fewer rows than `n` are returned at the end of the result set and
+    /// an empty vector is returned when no more rows are available.
+    /// Unlike collecting all rows of a [`ResultSet`], memory usage is bounded
+    /// by `n`. The query must be executed by [`Statement::query`] or
+    /// [`Statement::query_named`] in advance.
+    ///
+    /// Rows are fetched from the server in batches of the size set by
+    /// [`StatementBuilder::fetch_array_size`]. Set it to `n` to fetch
+    /// a chunk in one network round-trip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select level from dual connect by level <= 25")
+    ///     .fetch_array_size(10)
+    ///     .build()?;
+    /// stmt.query(&[])?;
+    /// loop {
+    ///     let rows = stmt.fetch_chunk(10)?;
+    ///     if rows.is_empty() {
+    ///         break;
+    ///     }
+    ///     // process rows
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn fetch_chunk(&mut self, n: usize) -> Result<Vec<Row>> {
+        self.check_stmt_type(true, "fetch_chunk")?;
+        if self.stmt.row.is_none() {
+            return Err(Error::InvalidOperation(
+                "Could not use the `fetch_chunk` method before the query is executed".into(),
+            ));
+        }
+        let mut rows = Vec::new();
+        while rows.len() < n {
+            match self.stmt.next() {
+                Some(row) => rows.push(row?.get_as::<Row>()?),
+                None => break,
+            }
+        }
+        Ok(rows)
+    }
+
     /// Binds values by position and executes the statement.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -795,6 +1165,16 @@ impl<'conn> Statement<'conn> {
     ///
     /// # Ok::<(), Error>(())
     /// ```
+    ///
+    /// # Reusing a statement
+    ///
+    /// A statement can be executed many times. The statement is parsed once
+    /// when it is built and only bind values are replaced on the following
+    /// executions. Values of different types may be bound between executions.
+    /// The bind variable is recreated then.
+    ///
+    /// Values not specified in `params` keep the values bound previously.
+    /// Use [`Statement::clear_binds`] to set them to NULL.
     pub fn execute(&mut self, params: &[&dyn ToSql]) -> Result<()> {
         self.exec(params, false, "execute")
     }
@@ -854,7 +1234,7 @@ impl<'conn> Statement<'conn> {
         for (i, param) in params.iter().enumerate() {
             self.bind(i + 1, *param)?;
         }
-        self.exec_common()
+        self.exec_common(&ExecuteOptions::new())
     }
 
     pub(crate) fn exec_named(
@@ -867,15 +1247,24 @@ impl<'conn> Statement<'conn> {
         for param in params {
             self.bind(param.0, param.1)?;
         }
-        self.exec_common()
+        self.exec_common(&ExecuteOptions::new())
     }
 
-    fn exec_common(&mut self) -> Result<()> {
-        let mut num_query_columns = 0;
-        let mut exec_mode = DPI_MODE_EXEC_DEFAULT;
-        if self.conn().autocommit() {
-            exec_mode |= DPI_MODE_EXEC_COMMIT_ON_SUCCESS;
+    pub(crate) fn exec_with_options(
+        &mut self,
+        params: &[&dyn ToSql],
+        opts: &ExecuteOptions,
+    ) -> Result<()> {
+        self.check_stmt_type(false, "execute")?;
+        for (i, param) in params.iter().enumerate() {
+            self.bind(i + 1, *param)?;
         }
+        self.exec_common(opts)
+    }
+
+    fn exec_common(&mut self, opts: &ExecuteOptions) -> Result<()> {
+        let mut num_query_columns = 0;
+        let exec_mode = opts.exec_mode(self.conn().autocommit());
         chkerr!(
             self.ctxt(),
             dpiStmt_setFetchArraySize(self.handle(), self.stmt.query_params.fetch_array_size)
@@ -891,6 +1280,9 @@ impl<'conn> Statement<'conn> {
             dpiStmt_execute(self.handle(), exec_mode, &mut num_query_columns)
         );
         self.ctxt().set_warning();
+        if opts.parse_only {
+            return Ok(());
+        }
         if self.is_ddl() {
             let fncode = self.oci_attr::<SqlFnCode>()?;
             match fncode {
@@ -899,9 +1291,13 @@ impl<'conn> Statement<'conn> {
                 }
                 _ => (),
             }
+            self.conn().clear_bind_metadata_cache()?;
         }
-        if self.statement_type == StatementType::Select && self.stmt.row.is_none() {
-            self.stmt.init_row(num_query_columns as usize)?;
+        if self.statement_type == StatementType::Select {
+            self.stmt.fetch_in_progress.set(true);
+            if self.stmt.row.is_none() {
+                self.stmt.init_row(num_query_columns as usize)?;
+            }
         }
         if self.is_returning {
             for val in self.bind_values.iter_mut() {
@@ -911,6 +1307,22 @@ impl<'conn> Statement<'conn> {
         Ok(())
     }
 
+    pub(crate) fn describe(&mut self) -> Result<Vec<ColumnInfo>> {
+        self.check_stmt_type(true, "describe")?;
+        let mut num_query_columns = 0;
+        chkerr!(
+            self.ctxt(),
+            dpiStmt_execute(
+                self.handle(),
+                DPI_MODE_EXEC_DESCRIBE_ONLY,
+                &mut num_query_columns
+            )
+        );
+        (0..num_query_columns as usize)
+            .map(|i| ColumnInfo::new(&self.stmt, i))
+            .collect()
+    }
+
     /// Returns the number of bind variables in the statement.
     ///
     /// In SQL statements this is the total number of bind variables whereas in
@@ -995,6 +1407,114 @@ impl<'conn> Statement<'conn> {
         self.bind_values[pos].set(value)
     }
 
+    /// Set NULL whose data type is `oratype` to a bind value in the statement.
+    ///
+    /// The data type is used to allocate the bind buffer. When the bind value
+    /// is also used as an output parameter, `oratype` must be large enough to
+    /// hold the output value.
+    ///
+    /// This is same with `stmt.bind(bindidx, oratype)` but makes the intent clear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # use oracle::sql_type::OracleType;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("begin :outval := nvl(to_char(:inval), 'null'); end;")
+    ///     .build()?;
+    /// stmt.bind_null("outval", &OracleType::Varchar2(10))?;
+    /// stmt.bind_null("inval", &OracleType::Number(0, 0))?;
+    /// stmt.execute(&[])?;
+    /// let outval: String = stmt.bind_value("outval")?;
+    /// assert_eq!(outval, "null");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn bind_null<I>(&mut self, bindidx: I, oratype: &OracleType) -> Result<()>
+    where
+        I: BindIndex,
+    {
+        self.bind(bindidx, oratype)
+    }
+
+    /// Binds an output parameter to receive a ref cursor.
+    ///
+    /// This is same with `stmt.bind(bindidx, &OracleType::RefCursor)`.
+    /// Get the ref cursor by [`bind_value`](#method.bind_value) after execution.
+    ///
+    /// Cursor columns in the ref cursor, such as `cursor(select ...)` in
+    /// the select list, are fetched as [`RefCursor`] and iterated in the same way.
+    /// See [`RefCursor`] about their lifetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # use oracle::sql_type::RefCursor;
+    /// # let conn = test_util::connect()?;
+    /// let sql = r#"
+    /// begin
+    ///   open :cursor for
+    ///     select IntCol, cursor(select StringCol from TestStrings s where s.IntCol <= t.IntCol order by s.IntCol)
+    ///       from TestNumbers t where IntCol <= 2 order by IntCol;
+    /// end;
+    /// "#;
+    /// let mut stmt = conn.statement(sql).build()?;
+    /// stmt.bind_ref_cursor_out("cursor")?;
+    /// stmt.execute(&[])?;
+    ///
+    /// let mut cursor: RefCursor = stmt.bind_value("cursor")?;
+    /// for row_result in cursor.query_as::<(i32, RefCursor)>()? {
+    ///     let (int_col, mut sub_cursor) = row_result?;
+    ///     let strings = sub_cursor.query_as::<String>()?.collect::<Result<Vec<_>, _>>()?;
+    ///     assert_eq!(strings.len(), int_col as usize);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn bind_ref_cursor_out<I>(&mut self, bindidx: I) -> Result<()>
+    where
+        I: BindIndex,
+    {
+        self.bind(bindidx, &OracleType::RefCursor)
+    }
+
+    /// Sets all bind values in the statement to NULL.
+    ///
+    /// This is useful to reuse a prepared statement without leaking values
+    /// bound for the previous execution to the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select :1 || '-' || :2 from dual")
+    ///     .build()?;
+    /// assert_eq!(stmt.query_row_as::<String>(&[&"a", &"b"])?, "a-b");
+    ///
+    /// // The second value bound previously is used.
+    /// assert_eq!(stmt.query_row_as::<String>(&[&"c"])?, "c-b");
+    ///
+    /// // The second value is NULL after `clear_binds`.
+    /// stmt.clear_binds()?;
+    /// assert_eq!(stmt.query_row_as::<String>(&[&"c"])?, "c-");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn clear_binds(&mut self) -> Result<()> {
+        for val in &mut self.bind_values {
+            // Values which have never been bound are left untouched.
+            if val.oracle_type().is_ok() {
+                val.set_null()?;
+            }
+        }
+        Ok(())
+    }
+
     /// Gets a bind value in the statement.
     ///
     /// The position starts from one when the bind index type is `usize`.
@@ -1032,6 +1552,32 @@ impl<'conn> Statement<'conn> {
         self.bind_values[pos].get()
     }
 
+    /// Gets a bind value in the statement by the bind variable name.
+    ///
+    /// This is same with [`bind_value`](#method.bind_value) called with a `&str`
+    /// bind index. The name is compared case-insensitively with names returned
+    /// by [`bind_names`](#method.bind_names). A leading colon is optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::sql_type::OracleType;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn.statement("begin :result := :val * 2; end;").build()?;
+    /// stmt.execute_named(&[("result", &OracleType::Number(0, 0)), ("val", &21)])?;
+    /// let result: i32 = stmt.bind_value_by_name("result")?;
+    /// assert_eq!(result, 42);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn bind_value_by_name<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromSql,
+    {
+        self.bind_value(name.strip_prefix(':').unwrap_or(name))
+    }
+
     /// Gets values returned by RETURNING INTO clause.
     ///
     /// When the `bindidx` ponints to a bind variable out of RETURNING INTO clause,
@@ -1089,6 +1635,93 @@ impl<'conn> Statement<'conn> {
         Ok(vec)
     }
 
+    /// Gets values returned by RETURNING INTO clause as a row.
+    ///
+    /// All bind variables in RETURNING INTO clause are converted to `T` in the
+    /// order of the clause. Their names are used as column names, which are
+    /// needed when `T` is a struct deriving [`RowValue`].
+    ///
+    /// This returns [`Error::NoDataFound`] when no rows are affected and
+    /// [`Error::InvalidOperation`] when more than one row is affected.
+    /// Use [`returned_rows`](#method.returned_rows) for the latter case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::sql_type::OracleType;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let stmt = conn.execute(
+    ///     "insert into TestTempTable values (9201, 'returned') \
+    ///      returning IntCol, StringCol into :id, :str",
+    ///     &[&None::<i32>, &OracleType::Varchar2(30)],
+    /// )?;
+    /// let (id, s) = stmt.returned_row::<(i32, String)>()?;
+    /// assert_eq!(id, 9201);
+    /// assert_eq!(s, "returned");
+    /// # conn.rollback()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn returned_row<T>(&self) -> Result<T>
+    where
+        T: RowValue,
+    {
+        match self.returned_row_count()? {
+            0 => Err(Error::NoDataFound),
+            1 => T::get(&self.returned_row_at(0)?),
+            n => Err(Error::InvalidOperation(format!(
+                "RETURNING INTO clause returned {} rows. Use returned_rows instead.",
+                n
+            ))),
+        }
+    }
+
+    /// Gets values returned by RETURNING INTO clause as rows.
+    ///
+    /// This is same with [`returned_row`](#method.returned_row) except that
+    /// this returns a row per affected row.
+    pub fn returned_rows<T>(&self) -> Result<Vec<T>>
+    where
+        T: RowValue,
+    {
+        let rows = self.returned_row_count()?;
+        let mut vec = Vec::with_capacity(rows as usize);
+        for i in 0..rows {
+            vec.push(T::get(&self.returned_row_at(i)?)?);
+        }
+        Ok(vec)
+    }
+
+    fn returned_row_count(&self) -> Result<u32> {
+        if !self.is_returning {
+            return Err(Error::InvalidOperation(
+                "the statement doesn't have RETURNING INTO clause".into(),
+            ));
+        }
+        let mut rows = 0;
+        chkerr!(self.ctxt(), dpiStmt_getRowCount(self.handle(), &mut rows));
+        let array_size = self
+            .returning_binds
+            .iter()
+            .map(|idx| self.bind_values[*idx].array_size)
+            .min()
+            .unwrap_or(0);
+        Ok(cmp::min(rows, array_size as u64) as u32)
+    }
+
+    fn returned_row_at(&self, index: u32) -> Result<Row> {
+        let mut column_names = Vec::with_capacity(self.returning_binds.len());
+        let mut column_values = Vec::with_capacity(self.returning_binds.len());
+        for idx in &self.returning_binds {
+            let mut sqlval = self.bind_values[*idx].unsafely_clone();
+            sqlval.buffer_row_index = BufferRowIndex::Owned(index);
+            column_names.push(self.bind_names[*idx].clone());
+            column_values.push(sqlval);
+        }
+        Row::new(column_names, column_values)
+    }
+
     /// Returns the number of rows fetched when the SQL statement is a query.
     /// Otherwise, the number of rows affected.
     ///
@@ -1190,6 +1823,7 @@ impl<'conn> Statement<'conn> {
                 self.stmt.conn.clone(),
                 handle,
                 self.stmt.query_params.clone(),
+                None,
             )?;
             unsafe {
                 dpiStmt_release(handle);
@@ -1198,6 +1832,38 @@ impl<'conn> Statement<'conn> {
         }
     }
 
+    /// Returns the zero-based index of the column whose name is `name`
+    /// in the result set of the last executed query.
+    ///
+    /// A column whose name matches exactly is preferred. Otherwise the name is
+    /// compared case-insensitively. The first one is returned when more than
+    /// one column matches. `None` is returned when no column matches
+    /// or the statement hasn't been executed as a query yet.
+    ///
+    /// Resolve the index once and then use it to get values in a loop
+    /// instead of looking up the column name for each row.
+    ///
+    /// See also [`ResultSet::column_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol, StringCol from TestStrings where IntCol = 1")
+    ///     .build()?;
+    /// let row = stmt.query_row(&[])?;
+    /// let idx = stmt.column_index("stringcol").unwrap();
+    /// assert_eq!(idx, 1);
+    /// assert_eq!(row.get::<_, String>(idx)?, "String 1");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.stmt.column_index(name)
+    }
+
     /// Returns statement type
     pub fn statement_type(&self) -> StatementType {
         self.statement_type
@@ -1240,7 +1906,16 @@ impl<'conn> Statement<'conn> {
         self.is_returning
     }
 
-    /// Returns the rowid of the last row that was affected by the statement.
+    /// Returns the rowid of the row affected by the statement.
+    ///
+    /// This returns `Some(rowid)` only when the last execution of an `insert`,
+    /// `update`, `delete` or `merge` statement affected exactly one row.
+    /// `None` is returned when no rows or more than one row were affected, or when
+    /// the statement isn't DML.
+    ///
+    /// Note that Oracle may not report the rowid for a `merge` statement even when
+    /// it merged exactly one row. `None` is returned in that case. Query the rowid
+    /// by a `select` statement after the `merge` when it is required.
     ///
     /// ```
     /// # use oracle::Error;
@@ -1259,6 +1934,11 @@ impl<'conn> Statement<'conn> {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn last_row_id(&self) -> Result<Option<String>> {
+        if !self.is_dml() || self.row_count()? != 1 {
+            // dpiStmt_getLastRowid returns the rowid of the last row
+            // even when multiple rows are affected.
+            return Ok(None);
+        }
         let mut rowid = ptr::null_mut();
         chkerr!(self.ctxt(), dpiStmt_getLastRowid(self.handle(), &mut rowid));
         if rowid.is_null() {
@@ -1274,6 +1954,65 @@ impl<'conn> Statement<'conn> {
         }
     }
 
+    /// Returns the array size used for performing fetches.
+    ///
+    /// See [`StatementBuilder::fetch_array_size`].
+    pub fn fetch_array_size(&self) -> u32 {
+        self.stmt.query_params.fetch_array_size
+    }
+
+    /// Returns the number of rows prefetched when the query is executed.
+    ///
+    /// See [`StatementBuilder::prefetch_rows`].
+    pub fn prefetch_rows(&self) -> u32 {
+        self.stmt
+            .query_params
+            .prefetch_rows
+            .unwrap_or(DPI_DEFAULT_PREFETCH_ROWS)
+    }
+
+    /// Sets the prefetch buffer size in bytes for each LOB locator fetched by the query.
+    ///
+    /// When LOB columns are fetched as locators, the Oracle client library
+    /// prefetches up to `size` bytes of each LOB along with the locator.
+    /// Reading a LOB whose length is within the size needs no additional
+    /// network round trips.
+    ///
+    /// This takes effect only when LOB columns are fetched as [`Clob`], [`Nclob`]
+    /// or [`Blob`] by [`StatementBuilder::lob_locator`]. Otherwise LOB data are
+    /// fetched inline as `String` or `Vec<u8>` and the size is not used.
+    ///
+    /// The size is applied when the query is executed next time. It overrides
+    /// [`DefaultLobPrefetchSize`][crate::oci_attr::DefaultLobPrefetchSize] of the session
+    /// for this statement only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::sql_type::Clob;
+    /// # use oracle::test_util;
+    /// # use std::io::Read;
+    /// # let conn = test_util::connect()?;
+    /// # conn.execute("insert into TestCLOBs values (1, 'clob data')", &[])?;
+    /// let mut stmt = conn
+    ///     .statement("select CLOBCol from TestCLOBs where IntCol = :1")
+    ///     .lob_locator()
+    ///     .build()?;
+    /// stmt.set_lob_prefetch_size(64 * 1024);
+    /// let mut clob = stmt.query_row_as::<Clob>(&[&1])?;
+    /// let mut data = String::new();
+    /// clob.read_to_string(&mut data)?;
+    /// assert_eq!(data, "clob data");
+    /// # conn.rollback()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_lob_prefetch_size(&mut self, size: u32) {
+        self.stmt.query_params.lob_prefetch_size = Some(size);
+        // Columns are defined again to apply the size.
+        self.stmt.row = None;
+    }
+
     /// Gets an OCI handle attribute corresponding to the specified type parameter
     /// See the [`oci_attr` module][crate::oci_attr] for details.
     pub fn oci_attr<T>(&self) -> Result<<<T::DataType as DataType>::Type as ToOwned>::Owned>
@@ -1446,12 +2185,41 @@ impl ColumnIndex for usize {
 
 impl<'a> ColumnIndex for &'a str {
     fn idx(&self, column_names: &[String]) -> Result<usize> {
-        for (idx, colname) in column_names.iter().enumerate() {
-            if colname.as_str().eq_ignore_ascii_case(self) {
-                return Ok(idx);
-            }
-        }
-        Err(Error::InvalidColumnName((*self).to_string()))
+        find_column_index(column_names.iter().map(String::as_str), self)
+            .ok_or_else(|| Error::InvalidColumnName((*self).to_string()))
+    }
+}
+
+fn find_column_index<'a, I>(column_names: I, name: &str) -> Option<usize>
+where
+    I: Iterator<Item = &'a str> + Clone,
+{
+    column_names
+        .clone()
+        .position(|colname| colname == name)
+        .or_else(|| {
+            column_names
+                .clone()
+                .position(|colname| colname.eq_ignore_ascii_case(name))
+        })
+}
+
+// Returns indices of all columns matched with `name` by the same rule with
+// `find_column_index`.
+pub(crate) fn find_column_indices(column_names: &[String], name: &str) -> Vec<usize> {
+    let indices = |f: &dyn Fn(&str) -> bool| {
+        column_names
+            .iter()
+            .enumerate()
+            .filter(|(_, colname)| f(colname))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>()
+    };
+    let exact = indices(&|colname| colname == name);
+    if exact.is_empty() {
+        indices(&|colname| colname.eq_ignore_ascii_case(name))
+    } else {
+        exact
     }
 }
 
@@ -1460,6 +2228,39 @@ mod tests {
     use super::*;
     use crate::test_util;
 
+    #[test]
+    fn execute_options() {
+        let opts = ExecuteOptions::new();
+        assert_eq!(opts, ExecuteOptions::default());
+        assert_eq!(opts.commit_on_success_value(), None);
+        assert_eq!(opts.fetch_array_size_value(), None);
+        assert!(!opts.is_parse_only());
+        assert_eq!(opts.exec_mode(false), DPI_MODE_EXEC_DEFAULT);
+        assert_eq!(opts.exec_mode(true), DPI_MODE_EXEC_COMMIT_ON_SUCCESS);
+
+        let mut opts = ExecuteOptions::new();
+        opts.commit_on_success(false).parse_only(true);
+        assert_eq!(opts.exec_mode(true), DPI_MODE_EXEC_PARSE_ONLY);
+        opts.commit_on_success(true);
+        assert_eq!(
+            opts.exec_mode(false),
+            DPI_MODE_EXEC_COMMIT_ON_SUCCESS | DPI_MODE_EXEC_PARSE_ONLY
+        );
+    }
+
+    #[test]
+    fn find_column_indices() {
+        let names = ["ID", "id", "ID", "NAME"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(super::find_column_indices(&names, "ID"), vec![0, 2]);
+        assert_eq!(super::find_column_indices(&names, "id"), vec![1]);
+        assert_eq!(super::find_column_indices(&names, "Id"), vec![0, 1, 2]);
+        assert_eq!(super::find_column_indices(&names, "name"), vec![3]);
+        assert!(super::find_column_indices(&names, "X").is_empty());
+    }
+
     #[test]
     fn column_info() -> Result<()> {
         let conn = test_util::connect()?;
@@ -1553,3 +2354,16 @@ mod tests {
 /// ```
 #[cfg(doctest)]
 struct TestStmtWithoutLifetime;
+
+#[cfg_attr(feature = "stmt_without_lifetime", doc = "```no_run")]
+#[cfg_attr(not(feature = "stmt_without_lifetime"), doc = "```compile_fail")]
+/// # use oracle::{Connection, Error};
+/// let stmt = {
+///     let conn = Connection::connect("scott", "tiger", "")?;
+///     conn.statement("select 1 from dual").build()?
+///     // `conn` is dropped here while `stmt` borrows it.
+/// };
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(doctest)]
+struct TestStmtOutlivingConnection;
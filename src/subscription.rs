@@ -0,0 +1,220 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2021 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::binding::*;
+use crate::chkerr;
+use crate::to_rust_str;
+use crate::Context;
+use crate::Result;
+use std::fmt;
+use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Identifier of a subscription returned by [`Connection::subscribe`]
+///
+/// [`Connection::subscribe`]: crate::Connection::subscribe
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(pub(crate) u64);
+
+impl SubscriptionId {
+    /// Returns the registration ID assigned by the server.
+    ///
+    /// This corresponds to the `REGID` column of the `USER_CHANGE_NOTIFICATION_REGS` view.
+    pub fn registration_id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for SubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Type of [`SubscriptionEvent`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubscriptionEventType {
+    /// The database started up.
+    Startup,
+    /// The database shut down.
+    Shutdown,
+    /// An instance of a RAC database shut down.
+    ShutdownAny,
+    /// The subscription was unregistered by the server.
+    Deregister,
+    /// Objects referenced by registered queries changed.
+    ObjectChange,
+    /// The result sets of registered queries changed.
+    QueryChange,
+    /// Other event types
+    Other(u32),
+}
+
+impl SubscriptionEventType {
+    fn from_dpi_value(val: dpiEventType) -> SubscriptionEventType {
+        match val {
+            DPI_EVENT_STARTUP => SubscriptionEventType::Startup,
+            DPI_EVENT_SHUTDOWN => SubscriptionEventType::Shutdown,
+            DPI_EVENT_SHUTDOWN_ANY => SubscriptionEventType::ShutdownAny,
+            DPI_EVENT_DEREG => SubscriptionEventType::Deregister,
+            DPI_EVENT_OBJCHANGE => SubscriptionEventType::ObjectChange,
+            DPI_EVENT_QUERYCHANGE => SubscriptionEventType::QueryChange,
+            _ => SubscriptionEventType::Other(val),
+        }
+    }
+}
+
+/// Notification passed to the callback registered by [`Connection::subscribe`]
+///
+/// [`Connection::subscribe`]: crate::Connection::subscribe
+#[derive(Clone, Debug)]
+pub struct SubscriptionEvent {
+    id: SubscriptionId,
+    event_type: SubscriptionEventType,
+    table_names: Vec<String>,
+    registered: bool,
+}
+
+impl SubscriptionEvent {
+    unsafe fn from_dpi_message(id: SubscriptionId, msg: &dpiSubscrMessage) -> SubscriptionEvent {
+        let mut table_names = Vec::new();
+        let mut add_tables = |tables: *mut dpiSubscrMessageTable, num: u32| {
+            if !tables.is_null() {
+                for table in slice::from_raw_parts(tables, num as usize) {
+                    let name = to_rust_str(table.name, table.nameLength);
+                    if !table_names.contains(&name) {
+                        table_names.push(name);
+                    }
+                }
+            }
+        };
+        add_tables(msg.tables, msg.numTables);
+        if !msg.queries.is_null() {
+            for query in slice::from_raw_parts(msg.queries, msg.numQueries as usize) {
+                add_tables(query.tables, query.numTables);
+            }
+        }
+        SubscriptionEvent {
+            id,
+            event_type: SubscriptionEventType::from_dpi_value(msg.eventType),
+            table_names,
+            registered: msg.registered != 0,
+        }
+    }
+
+    /// Returns the subscription which received the event.
+    pub fn id(&self) -> SubscriptionId {
+        self.id
+    }
+
+    /// Returns the event type.
+    pub fn event_type(&self) -> SubscriptionEventType {
+        self.event_type
+    }
+
+    /// Returns the names of changed tables in the form `SCHEMA.TABLE`.
+    pub fn table_names(&self) -> &[String] {
+        &self.table_names
+    }
+
+    /// Returns whether the subscription is still registered.
+    pub fn registered(&self) -> bool {
+        self.registered
+    }
+}
+
+pub(crate) type SubscriptionCallback = Box<dyn Fn(&SubscriptionEvent) + Send + Sync>;
+
+pub(crate) struct CallbackContext {
+    pub(crate) id: AtomicU64,
+    callback: SubscriptionCallback,
+}
+
+impl CallbackContext {
+    pub(crate) fn new(callback: SubscriptionCallback) -> CallbackContext {
+        CallbackContext {
+            id: AtomicU64::new(0),
+            callback,
+        }
+    }
+}
+
+pub(crate) unsafe extern "C" fn subscr_callback(
+    context: *mut c_void,
+    message: *mut dpiSubscrMessage,
+) {
+    if context.is_null() || message.is_null() {
+        return;
+    }
+    let ctx = &*(context as *const CallbackContext);
+    let msg = &*message;
+    if !msg.errorInfo.is_null() {
+        return;
+    }
+    let event =
+        SubscriptionEvent::from_dpi_message(SubscriptionId(ctx.id.load(Ordering::Acquire)), msg);
+    // Don't unwind across the FFI boundary.
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| (ctx.callback)(&event)));
+}
+
+/// A registered subscription owned by a connection
+pub(crate) struct Subscription {
+    pub(crate) handle: *mut dpiSubscr,
+    pub(crate) context: *mut CallbackContext,
+}
+
+// The callback context is Send and Sync and the handle is used only
+// while the registry lock is held.
+unsafe impl Send for Subscription {}
+
+impl Subscription {
+    /// Unregisters the subscription.
+    ///
+    /// The subscription is returned back on error so that it can be retried.
+    pub(crate) fn unsubscribe(
+        self,
+        ctxt: &Context,
+        conn: *mut dpiConn,
+    ) -> std::result::Result<(), (crate::Error, Subscription)> {
+        let res: Result<()> = (|| {
+            chkerr!(ctxt, dpiConn_unsubscribe(conn, self.handle));
+            Ok(())
+        })();
+        match res {
+            Ok(()) => {
+                // dpiConn_unsubscribe releases the handle. No notifications
+                // are delivered after it completes.
+                unsafe { drop(Box::from_raw(self.context)) };
+                Ok(())
+            }
+            Err(err) => Err((err, self)),
+        }
+    }
+
+    /// Releases the subscription without unregistering it explicitly.
+    ///
+    /// This is used when [`Subscription::unsubscribe`] fails while the
+    /// connection is dropped. The subscription is unregistered when the
+    /// last reference to the handle is released. No notifications are
+    /// delivered after that, so the callback context is freed too.
+    pub(crate) fn release(self) {
+        unsafe {
+            dpiSubscr_release(self.handle);
+            drop(Box::from_raw(self.context));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::Connection;
+use crate::Result;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::atomic::Ordering;
+
+/// A transaction guard created by [`Connection::transaction`]
+///
+/// The transaction is rolled back when the guard is dropped without calling
+/// [`commit`](#method.commit). Autocommit mode is disabled while the guard
+/// is alive and restored afterward.
+///
+/// The guard dereferences to [`Connection`] so that SQL statements can be
+/// executed through it.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// # conn.execute("delete from TestTempTable where IntCol = 9001", &[])?;
+/// # conn.commit()?;
+/// {
+///     let tx = conn.transaction()?;
+///     tx.execute("insert into TestTempTable values (9001, 'rolled back')", &[])?;
+///     // rolled back here because `tx` is dropped without commit.
+/// }
+/// let count = conn.query_row_as::<u32>("select count(*) from TestTempTable where IntCol = 9001", &[])?;
+/// assert_eq!(count, 0);
+///
+/// let tx = conn.transaction()?;
+/// tx.execute("insert into TestTempTable values (9001, 'committed')", &[])?;
+/// tx.commit()?;
+/// # conn.execute("delete from TestTempTable where IntCol = 9001", &[])?;
+/// # conn.commit()?;
+/// # Ok::<(), Error>(())
+/// ```
+pub struct Transaction<'conn> {
+    conn: &'conn Connection,
+    autocommit: bool,
+    finished: bool,
+}
+
+impl<'conn> Transaction<'conn> {
+    pub(crate) fn new(conn: &'conn Connection) -> Transaction<'conn> {
+        let autocommit = conn.conn.autocommit.swap(false, Ordering::Relaxed);
+        Transaction {
+            conn,
+            autocommit,
+            finished: false,
+        }
+    }
+
+    /// Commits the transaction.
+    pub fn commit(mut self) -> Result<()> {
+        self.finished = true;
+        self.conn.commit()
+    }
+
+    /// Rolls back the transaction.
+    ///
+    /// This is same with dropping the guard except that an error is returned.
+    pub fn rollback(mut self) -> Result<()> {
+        self.finished = true;
+        self.conn.rollback()
+    }
+}
+
+impl Deref for Transaction<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.conn.rollback();
+        }
+        self.conn
+            .conn
+            .autocommit
+            .store(self.autocommit, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Debug for Transaction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Transaction")
+            .field("conn", &self.conn)
+            .field("autocommit", &self.autocommit)
+            .finish()
+    }
+}
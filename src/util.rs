@@ -24,6 +24,7 @@ use crate::ParseOracleTypeError;
 use crate::Result;
 
 pub struct Scanner<'a> {
+    len: usize,
     chars: str::Chars<'a>,
     char: Option<char>,
     ndigits: u32,
@@ -34,12 +35,18 @@ impl<'a> Scanner<'a> {
         let mut chars = s.chars();
         let char = chars.next();
         Scanner {
+            len: s.len(),
             chars,
             char,
             ndigits: 0,
         }
     }
 
+    /// Returns the byte offset of the current character.
+    pub fn position(&self) -> usize {
+        self.len - self.chars.as_str().len() - self.char.map_or(0, char::len_utf8)
+    }
+
     pub fn next(&mut self) -> Option<char> {
         self.char = self.chars.next();
         self.char
@@ -50,29 +57,29 @@ impl<'a> Scanner<'a> {
     }
 
     pub fn read_digits(&mut self) -> Option<u64> {
-        let mut num = 0;
+        let mut num: u64 = 0;
         self.ndigits = 0;
         loop {
-            num = num * 10
-                + match self.char {
-                    Some('0') => 0,
-                    Some('1') => 1,
-                    Some('2') => 2,
-                    Some('3') => 3,
-                    Some('4') => 4,
-                    Some('5') => 5,
-                    Some('6') => 6,
-                    Some('7') => 7,
-                    Some('8') => 8,
-                    Some('9') => 9,
-                    _ => {
-                        if self.ndigits > 0 {
-                            return Some(num);
-                        } else {
-                            return None;
-                        }
+            // saturate instead of overflowing when there are too many digits.
+            num = num.saturating_mul(10).saturating_add(match self.char {
+                Some('0') => 0,
+                Some('1') => 1,
+                Some('2') => 2,
+                Some('3') => 3,
+                Some('4') => 4,
+                Some('5') => 5,
+                Some('6') => 6,
+                Some('7') => 7,
+                Some('8') => 8,
+                Some('9') => 9,
+                _ => {
+                    if self.ndigits > 0 {
+                        return Some(num);
+                    } else {
+                        return None;
                     }
-                };
+                }
+            });
             self.char = self.chars.next();
             self.ndigits += 1;
         }
@@ -195,6 +202,30 @@ pub fn write_literal(
     }
 }
 
+/// Decodes percent-encoded characters such as `%40` in `s`.
+/// It returns `None` when `s` contains an invalid escape sequence or the
+/// decoded bytes aren't valid UTF-8.
+pub fn percent_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes.get(i + 1..i + 3)?;
+            let hex = str::from_utf8(hex).ok()?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
 pub fn duration_to_msecs(dur: Duration) -> Option<u32> {
     let msecs = dur
         .as_secs()
@@ -207,6 +238,304 @@ pub fn duration_to_msecs(dur: Duration) -> Option<u32> {
     }
 }
 
+/// Splits a SQL script into statements.
+///
+/// SQL statements are terminated by `;` and PL/SQL blocks, which start with
+/// `BEGIN`, `DECLARE` or `CREATE ... FUNCTION/PROCEDURE/PACKAGE/TRIGGER/TYPE/LIBRARY`,
+/// are terminated by a line containing only `/`. The last statement doesn't need
+/// a terminator. Terminators inside quoted strings and comments are ignored.
+///
+/// Each item is a pair of the line number (one based) where the statement starts
+/// and the statement text without the terminator.
+pub fn split_sql_script(script: &str) -> Vec<(usize, &str)> {
+    let bytes = script.as_bytes();
+    let len = bytes.len();
+    let mut stmts = Vec::new();
+    let mut pos = 0;
+    let mut line = 1;
+    loop {
+        // skip whitespace, comments and stray `/` lines between statements.
+        loop {
+            if pos >= len {
+                return stmts;
+            }
+            match bytes[pos] {
+                b'\n' => {
+                    line += 1;
+                    pos += 1;
+                }
+                b if b.is_ascii_whitespace() => pos += 1,
+                b'-' if bytes.get(pos + 1) == Some(&b'-') => pos = skip_line_comment(bytes, pos),
+                b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                    pos = skip_block_comment(bytes, pos, &mut line)
+                }
+                b'/' if is_slash_line(script, pos) => pos += 1,
+                _ => break,
+            }
+        }
+        let start = pos;
+        let start_line = line;
+        let plsql = is_plsql_block(&script[start..]);
+        let mut end = None;
+        while pos < len {
+            match bytes[pos] {
+                b'-' if bytes.get(pos + 1) == Some(&b'-') => pos = skip_line_comment(bytes, pos),
+                b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                    pos = skip_block_comment(bytes, pos, &mut line)
+                }
+                b'\'' | b'"' => pos = skip_quoted(bytes, pos, bytes[pos], &mut line),
+                b'q' | b'Q' if pos + 1 < len && is_q_quote_start(&bytes[start..=pos + 1]) => {
+                    pos = skip_q_quoted(bytes, pos + 1, &mut line)
+                }
+                b';' if !plsql => {
+                    end = Some(pos);
+                    pos += 1;
+                    break;
+                }
+                b'\n' => {
+                    line += 1;
+                    pos += 1;
+                    let next_line = script[pos..].split('\n').next().unwrap_or("");
+                    if next_line.trim() == "/" {
+                        end = Some(pos - 1);
+                        pos += next_line.len();
+                        break;
+                    }
+                }
+                _ => pos += 1,
+            }
+        }
+        let sql = script[start..end.unwrap_or(len)].trim_end();
+        if !sql.is_empty() {
+            stmts.push((start_line, sql));
+        }
+    }
+}
+
+/// Expands the bind variable `name` in `sql` into a comma-separated list of
+/// `count` bind variables.
+///
+/// `:name` is rewritten to `:name_0, :name_1, ...`. A numeric name such as `:2`
+/// is rewritten to `:in2_0, :in2_1, ...` because `2_0` isn't a valid bind variable
+/// name. Other bind variables are left unchanged. The rewritten SQL and whether
+/// each bind variable in `sql` is `name`, in order of appearance, are returned.
+/// Bind variables in quoted strings and comments are ignored.
+pub(crate) fn expand_bind_list(sql: &str, name: &str, count: usize) -> Result<(String, Vec<bool>)> {
+    let name = name.strip_prefix(':').unwrap_or(name);
+    let mut placeholders = Vec::new();
+    scan_sql_tokens(sql, |token| {
+        if let SqlToken::Bind(start, end) = token {
+            placeholders.push((start, end, sql[start + 1..end].eq_ignore_ascii_case(name)));
+        }
+    });
+    if !placeholders.iter().any(|&(_, _, is_list)| is_list) {
+        return Err(Error::InvalidBindName(name.to_string()));
+    }
+    let prefix = if name.starts_with(|c: char| c.is_ascii_digit()) {
+        "in"
+    } else {
+        ""
+    };
+    let list = (0..count)
+        .map(|idx| format!(":{}{}_{}", prefix, name, idx))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut expanded = String::with_capacity(sql.len() + list.len());
+    let mut last = 0;
+    for &(start, end, is_list) in &placeholders {
+        if is_list {
+            expanded.push_str(&sql[last..start]);
+            expanded.push_str(&list);
+            last = end;
+        }
+    }
+    expanded.push_str(&sql[last..]);
+    let is_list = placeholders
+        .into_iter()
+        .map(|(_, _, is_list)| is_list)
+        .collect();
+    Ok((expanded, is_list))
+}
+
+/// Returns the names of bind variables in the `RETURNING ... INTO` clause.
+pub(crate) fn returning_into_bind_names(sql: &str) -> Vec<&str> {
+    // 0: before RETURNING, 1: after RETURNING, 2: after INTO
+    let mut state = 0;
+    let mut names = Vec::new();
+    scan_sql_tokens(sql, |token| match token {
+        SqlToken::Word(start, end) => {
+            let word = &sql[start..end];
+            if word.eq_ignore_ascii_case("RETURNING") || word.eq_ignore_ascii_case("RETURN") {
+                state = 1;
+                names.clear();
+            } else if state == 1 && word.eq_ignore_ascii_case("INTO") {
+                state = 2;
+            }
+        }
+        SqlToken::Bind(start, end) if state == 2 => names.push(&sql[start + 1..end]),
+        SqlToken::Bind(_, _) => (),
+    });
+    names
+}
+
+enum SqlToken {
+    // an identifier, keyword or number
+    Word(usize, usize),
+    // a bind variable including the leading colon
+    Bind(usize, usize),
+}
+
+// Calls `f` for each word and bind variable out of quoted strings and comments.
+fn scan_sql_tokens<F>(sql: &str, mut f: F)
+where
+    F: FnMut(SqlToken),
+{
+    let bytes = sql.as_bytes();
+    let len = bytes.len();
+    let mut line = 1;
+    let mut pos = 0;
+    let ident_end = |start: usize| {
+        bytes[start..]
+            .iter()
+            .position(|b| !is_ident_byte(*b))
+            .map_or(len, |idx| start + idx)
+    };
+    while pos < len {
+        match bytes[pos] {
+            b'-' if bytes.get(pos + 1) == Some(&b'-') => pos = skip_line_comment(bytes, pos),
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos = skip_block_comment(bytes, pos, &mut line)
+            }
+            b'\'' | b'"' => pos = skip_quoted(bytes, pos, bytes[pos], &mut line),
+            b'q' | b'Q' if pos + 1 < len && is_q_quote_start(&bytes[..=pos + 1]) => {
+                pos = skip_q_quoted(bytes, pos + 1, &mut line)
+            }
+            b'n' | b'N' if pos + 2 < len && is_q_quote_start(&bytes[..=pos + 2]) => {
+                pos = skip_q_quoted(bytes, pos + 2, &mut line)
+            }
+            b':' if bytes.get(pos + 1).map_or(false, |b| is_ident_byte(*b)) => {
+                let end = ident_end(pos + 1);
+                f(SqlToken::Bind(pos, end));
+                pos = end;
+            }
+            b if is_ident_byte(b) => {
+                let end = ident_end(pos);
+                f(SqlToken::Word(pos, end));
+                pos = end;
+            }
+            _ => pos += 1,
+        }
+    }
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b == b'#'
+}
+
+// Checks whether `bytes` ends with `q'` or `nq'` which isn't a part of an identifier.
+fn is_q_quote_start(bytes: &[u8]) -> bool {
+    let prefix = match bytes {
+        [prefix @ .., b'q' | b'Q', b'\''] => prefix,
+        _ => return false,
+    };
+    let prefix = match prefix {
+        [rest @ .., b'n' | b'N'] => rest,
+        _ => prefix,
+    };
+    prefix.last().map_or(true, |b| !is_ident_byte(*b))
+}
+
+fn is_slash_line(script: &str, pos: usize) -> bool {
+    let line_start = script[..pos].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = script[pos..]
+        .find('\n')
+        .map_or(script.len(), |idx| pos + idx);
+    script[line_start..line_end].trim() == "/"
+}
+
+fn is_plsql_block(sql: &str) -> bool {
+    let mut words = sql
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_ascii_uppercase());
+    match words.next().as_deref() {
+        Some("BEGIN") | Some("DECLARE") => true,
+        Some("CREATE") => {
+            for word in words {
+                match word.as_str() {
+                    "OR" | "REPLACE" | "EDITIONABLE" | "NONEDITIONABLE" => (),
+                    "FUNCTION" | "PROCEDURE" | "PACKAGE" | "TRIGGER" | "TYPE" | "LIBRARY" => {
+                        return true
+                    }
+                    _ => return false,
+                }
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+// Returns the position of the newline terminating the comment.
+fn skip_line_comment(bytes: &[u8], pos: usize) -> usize {
+    bytes[pos..]
+        .iter()
+        .position(|b| *b == b'\n')
+        .map_or(bytes.len(), |idx| pos + idx)
+}
+
+// Returns the position after the closing `*/`.
+fn skip_block_comment(bytes: &[u8], mut pos: usize, line: &mut usize) -> usize {
+    pos += 2;
+    while pos < bytes.len() {
+        if bytes[pos] == b'*' && bytes.get(pos + 1) == Some(&b'/') {
+            return pos + 2;
+        }
+        if bytes[pos] == b'\n' {
+            *line += 1;
+        }
+        pos += 1;
+    }
+    pos
+}
+
+// Returns the position after the closing quote.
+// A doubled quote is handled as two adjacent quoted strings.
+fn skip_quoted(bytes: &[u8], mut pos: usize, quote: u8, line: &mut usize) -> usize {
+    pos += 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b if b == quote => return pos + 1,
+            b'\n' => *line += 1,
+            _ => (),
+        }
+        pos += 1;
+    }
+    pos
+}
+
+// Skips a q-quoted string such as `q'[...]'`. `pos` points to the first quote.
+fn skip_q_quoted(bytes: &[u8], mut pos: usize, line: &mut usize) -> usize {
+    let close = match bytes.get(pos + 1) {
+        Some(b'[') => b']',
+        Some(b'{') => b'}',
+        Some(b'<') => b'>',
+        Some(b'(') => b')',
+        Some(b) => *b,
+        None => return bytes.len(),
+    };
+    pos += 2;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b if b == close && bytes.get(pos + 1) == Some(&b'\'') => return pos + 2,
+            b'\n' => *line += 1,
+            _ => (),
+        }
+        pos += 1;
+    }
+    pos
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +573,19 @@ mod tests {
         assert_eq!(check_number_format("9.9"), ok);
     }
 
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode(""), Some("".into()));
+        assert_eq!(percent_decode("scott"), Some("scott".into()));
+        assert_eq!(percent_decode("p%40ss%3Aw%2fd"), Some("p@ss:w/d".into()));
+        assert_eq!(percent_decode("%E3%81%82"), Some("\u{3042}".into()));
+        assert_eq!(percent_decode("%"), None);
+        assert_eq!(percent_decode("%4"), None);
+        assert_eq!(percent_decode("%4G"), None);
+        assert_eq!(percent_decode("%+1"), None);
+        assert_eq!(percent_decode("%FF"), None);
+    }
+
     #[test]
     fn test_parse_str_into_raw() {
         let err = Err(ParseOracleTypeError::new("raw"));
@@ -285,4 +627,127 @@ mod tests {
         );
         assert_eq!(duration_to_msecs(Duration::new(50 * 24 * 60 * 60, 0)), None);
     }
+
+    #[test]
+    fn test_split_sql_script() {
+        assert_eq!(split_sql_script(""), vec![]);
+        assert_eq!(split_sql_script(" \n-- comment\n"), vec![]);
+        assert_eq!(
+            split_sql_script("select 1 from dual;\nselect 2 from dual"),
+            vec![(1, "select 1 from dual"), (2, "select 2 from dual")]
+        );
+        // terminators in comments and strings
+        assert_eq!(
+            split_sql_script(
+                "-- first;\ninsert into t values ('a;b', \"c;\", q'[d';]');\n/* x; */ delete from t;\n"
+            ),
+            vec![
+                (2, "insert into t values ('a;b', \"c;\", q'[d';]')"),
+                (3, "delete from t")
+            ]
+        );
+        // PL/SQL blocks
+        assert_eq!(
+            split_sql_script(
+                "begin\n  null;\nend;\n/\ncreate or replace procedure p is\nbegin\n  null;\nend;\n/\nselect 1 from dual\n/\n"
+            ),
+            vec![
+                (1, "begin\n  null;\nend;"),
+                (5, "create or replace procedure p is\nbegin\n  null;\nend;"),
+                (10, "select 1 from dual"),
+            ]
+        );
+        // a PL/SQL block without the trailing slash
+        assert_eq!(
+            split_sql_script(
+                "create table t (a number);\n\ndeclare\n  x number;\nbegin\n  null;\nend;"
+            ),
+            vec![
+                (1, "create table t (a number)"),
+                (3, "declare\n  x number;\nbegin\n  null;\nend;"),
+            ]
+        );
+        // multiline string
+        assert_eq!(
+            split_sql_script("select 'a\nb' from dual;\nselect 1 from dual;"),
+            vec![(1, "select 'a\nb' from dual"), (3, "select 1 from dual")]
+        );
+        // an identifier ending with q at the end of the script
+        assert_eq!(
+            split_sql_script("select 1 from dual; drop sequence myseq"),
+            vec![(1, "select 1 from dual"), (1, "drop sequence myseq")]
+        );
+        assert_eq!(
+            split_sql_script("drop sequence MYSEQ"),
+            vec![(1, "drop sequence MYSEQ")]
+        );
+    }
+
+    #[test]
+    fn test_expand_bind_list() {
+        assert_eq!(
+            expand_bind_list("select * from t where id in (:ids)", "ids", 3).unwrap(),
+            (
+                "select * from t where id in (:ids_0, :ids_1, :ids_2)".to_string(),
+                vec![true]
+            )
+        );
+        // other bind variables are left unchanged
+        assert_eq!(
+            expand_bind_list("select * from t where a = :1 and b in (:2)", "2", 2).unwrap(),
+            (
+                "select * from t where a = :1 and b in (:in2_0, :in2_1)".to_string(),
+                vec![false, true]
+            )
+        );
+        assert_eq!(
+            expand_bind_list(
+                "select * from t where a in (:IDS) and b = :b and c in (:ids)",
+                ":ids",
+                1
+            )
+            .unwrap(),
+            (
+                "select * from t where a in (:ids_0) and b = :b and c in (:ids_0)".to_string(),
+                vec![true, false, true]
+            )
+        );
+        // bind-like text in strings and comments
+        assert_eq!(
+            expand_bind_list("select ':x' /* :y */ from t where a in (:x) -- :z", "x", 2)
+                .unwrap()
+                .0,
+            "select ':x' /* :y */ from t where a in (:x_0, :x_1) -- :z"
+        );
+        assert_eq!(
+            expand_bind_list("select q'[:a]', nq'{:b}' from t where id in (:c)", "c", 1)
+                .unwrap()
+                .0,
+            "select q'[:a]', nq'{:b}' from t where id in (:c_0)"
+        );
+        assert!(expand_bind_list("select * from t", "a", 1).is_err());
+        assert!(expand_bind_list("select * from t where a = :a", "b", 1).is_err());
+    }
+
+    #[test]
+    fn test_returning_into_bind_names() {
+        assert_eq!(
+            returning_into_bind_names(
+                "insert into t values (:a, :b) returning id, created_at into :id, :ts"
+            ),
+            vec!["id", "ts"]
+        );
+        assert_eq!(
+            returning_into_bind_names("update t set a = :a where b = :b RETURN a INTO :out"),
+            vec!["out"]
+        );
+        assert_eq!(
+            returning_into_bind_names("select 'returning x into :y' from t where a = :a"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            returning_into_bind_names("insert into t values (:1) returning a into :2"),
+            vec!["2"]
+        );
+    }
 }
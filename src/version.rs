@@ -43,7 +43,22 @@ use std::str::FromStr;
 /// println!("Server version: {}", server_version);
 /// # Ok::<(), Error>(())
 /// ```
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Versions are compared part by part and converted from and to strings
+/// such as `"19.3.0.0.0"`.
+///
+/// ```
+/// # use oracle::Version;
+/// let ver = "19.3.0.0.0".parse::<Version>()?;
+/// assert_eq!(ver, Version::new(19, 3, 0, 0, 0));
+/// assert_eq!(ver.to_string(), "19.3.0.0.0");
+/// assert!(ver < Version::new(21, 0, 0, 0, 0));
+/// # Ok::<(), std::num::ParseIntError>(())
+/// ```
+///
+/// When `serde` feature is enabled, this implements `Serialize` and `Deserialize`
+/// as a string in the same format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Version {
     major: i32,
     minor: i32,
@@ -144,6 +159,27 @@ impl FromStr for Version {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> result::Result<Version, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +214,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn round_trip() {
+        for s in &["19.3.0.0.0", "21.11.0.0.0", "12.1.0.2.0"] {
+            assert_eq!(s.parse::<Version>().unwrap().to_string(), *s);
+        }
+        assert!("19.x".parse::<Version>().is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Version::new(19, 0, 0, 0, 0) < Version::new(21, 0, 0, 0, 0));
+        assert!(Version::new(19, 3, 0, 0, 0) < Version::new(19, 10, 0, 0, 0));
+        assert!(Version::new(12, 2, 0, 1, 0) > Version::new(12, 1, 0, 2, 0));
+        let mut versions = vec![
+            "21.0.0.0.0".parse::<Version>().unwrap(),
+            "11.2.0.4.0".parse::<Version>().unwrap(),
+            "19.3.0.0.0".parse::<Version>().unwrap(),
+        ];
+        versions.sort();
+        assert_eq!(
+            versions,
+            vec![
+                Version::new(11, 2, 0, 4, 0),
+                Version::new(19, 3, 0, 0, 0),
+                Version::new(21, 0, 0, 0, 0)
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn serde() {
+        let ver = Version::new(19, 3, 0, 0, 0);
+        let json = serde_json::to_string(&ver).unwrap();
+        assert_eq!(json, "\"19.3.0.0.0\"");
+        assert_eq!(serde_json::from_str::<Version>(&json).unwrap(), ver);
+        assert!(serde_json::from_str::<Version>("\"x\"").is_err());
+    }
+
     #[test]
     fn client_version() {
         let ver = Version::client().unwrap();
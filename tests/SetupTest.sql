@@ -32,3 +32,34 @@ create type &main_user..udt_issue19_obj as object (
 /
 create type &main_user..udt_issue19_col as varray(10) of float;
 /
+create type &main_user..udt_NumberTable as table of number;
+/
+create table &main_user..TestNumberTables (
+    IntCol                              number(9) not null,
+    NumberTableCol                      udt_NumberTable
+) nested table NumberTableCol store as TestNumberTables_nt;
+
+-- VECTOR data type is available since Oracle 23ai.
+begin
+$if dbms_db_version.version >= 23 $then
+    execute immediate 'create table &main_user..TestVectors (
+        IntCol                          number(9) not null,
+        VectorCol                       vector(3, float32)
+    )';
+$else
+    null;
+$end
+end;
+/
+
+-- JSON payload queues are available since Oracle 21c.
+begin
+$if dbms_db_version.version >= 21 $then
+    dbms_aqadm.create_queue_table('&main_user..JSON_QUEUE_TAB', 'JSON');
+    dbms_aqadm.create_queue('&main_user..JSON_QUEUE', '&main_user..JSON_QUEUE_TAB');
+    dbms_aqadm.start_queue('&main_user..JSON_QUEUE');
+$else
+    null;
+$end
+end;
+/
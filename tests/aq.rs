@@ -0,0 +1,53 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+#![cfg(all(feature = "aq_unstable", feature = "serde_json"))]
+
+mod common;
+
+use oracle::aq::{MsgProps, Queue};
+use oracle::Result;
+use serde_json::{json, Value};
+
+#[test]
+fn aq_json_payload() -> Result<()> {
+    let conn = common::connect()?;
+    if !common::check_oracle_version("aq_json_payload", &conn, 21, 0)? {
+        return Ok(());
+    }
+    let queue = Queue::<Value>::new(&conn, "JSON_QUEUE", &())?;
+
+    let payload = json!({
+        "id": 1001,
+        "name": "order",
+        "price": 12.5,
+        "items": ["apple", "orange"],
+        "paid": true,
+        "note": null,
+    });
+    let mut msg = MsgProps::<Value>::new(&conn)?;
+    msg.set_payload(&payload)?;
+    queue.enqueue(&msg)?;
+
+    let new_msg = queue.dequeue()?;
+    let new_payload = new_msg.payload()?;
+    assert_eq!(new_payload["id"], 1001);
+    assert_eq!(new_payload["name"], "order");
+    assert_eq!(new_payload["price"], 12.5);
+    assert_eq!(new_payload["items"], json!(["apple", "orange"]));
+    assert_eq!(new_payload["paid"], true);
+    assert_eq!(new_payload["note"], Value::Null);
+    assert_eq!(new_payload, payload);
+    Ok(())
+}
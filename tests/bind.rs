@@ -1,7 +1,9 @@
 mod common;
 
-use oracle::sql_type::{IntervalDS, IntervalYM, OracleType, Timestamp};
-use oracle::Result;
+use oracle::sql_type::{IntervalDS, IntervalYM, Null, OracleType, OutValue, Timestamp};
+use oracle::{Error, Result};
+use std::rc::Rc;
+use std::sync::Arc;
 
 macro_rules! test_in_out {
     ($stmt:expr, $type:ty, $val:expr) => {
@@ -220,3 +222,176 @@ fn bind_named() -> Result<()> {
     assert_eq!(outval, None);
     Ok(())
 }
+
+#[test]
+fn timestamp_precision_of_null_out_bind() -> Result<()> {
+    let conn = common::connect()?;
+
+    let mut stmt = conn
+        .statement("begin :1 := to_timestamp('2012-03-04 05:06:07.123456', 'YYYY-MM-DD HH24:MI:SS.FF'); end;")
+        .build()?;
+    stmt.execute(&[&OracleType::Timestamp(6)])?;
+    let ts: Timestamp = stmt.bind_value(1)?;
+    assert_eq!(ts.precision(), 6);
+    assert_eq!(ts.nanosecond(), 123456000);
+    assert_eq!(ts.to_string(), "2012-03-04 05:06:07.123456");
+
+    let mut stmt = conn
+        .statement("begin :1 := to_timestamp_tz('2012-03-04 05:06:07.123456 +09:00', 'YYYY-MM-DD HH24:MI:SS.FF TZH:TZM'); end;")
+        .build()?;
+    stmt.execute(&[&OracleType::TimestampTZ(6)])?;
+    let ts: Timestamp = stmt.bind_value(1)?;
+    assert_eq!(ts.precision(), 6);
+    assert_eq!(ts.to_string(), "2012-03-04 05:06:07.123456 +09:00");
+
+    let mut stmt = conn
+        .statement("begin :1 := to_timestamp('2012-03-04 05:06:07.123456', 'YYYY-MM-DD HH24:MI:SS.FF'); end;")
+        .build()?;
+    stmt.execute(&[&OracleType::TimestampLTZ(6)])?;
+    let ts: Timestamp = stmt.bind_value(1)?;
+    assert_eq!(ts.precision(), 6);
+    assert!(ts.is_local_timezone());
+    assert_eq!(ts.nanosecond(), 123456000);
+    Ok(())
+}
+
+#[test]
+fn bind_null() -> Result<()> {
+    let conn = common::connect()?;
+
+    // null input values
+    let mut stmt = conn
+        .statement("begin :out := nvl(to_char(:num), 'N') || nvl(:str, 'S'); end;")
+        .build()?;
+    stmt.bind_null("out", &OracleType::Varchar2(10))?;
+    stmt.bind_null("num", &OracleType::Number(0, 0))?;
+    stmt.bind_null("str", &OracleType::Varchar2(10))?;
+    stmt.execute(&[])?;
+    let outval: String = stmt.bind_value("out")?;
+    assert_eq!(outval, "NS");
+
+    // null output values
+    let mut stmt = conn
+        .statement("begin :num := 12345; :str := 'abcdefghij'; end;")
+        .build()?;
+    stmt.bind_null("num", &OracleType::Number(0, 0))?;
+    stmt.bind_null("str", &OracleType::Varchar2(10))?;
+    stmt.execute(&[])?;
+    assert_eq!(stmt.bind_value::<_, i32>("num")?, 12345);
+    assert_eq!(stmt.bind_value::<_, String>("str")?, "abcdefghij");
+
+    // Null as ToSql
+    let stmt = conn.execute(
+        "begin :1 := nvl(to_char(:2), 'N') || nvl(:3, 'S'); end;",
+        &[
+            &Null(OracleType::Varchar2(10)),
+            &Null(OracleType::Number(0, 0)),
+            &Null(OracleType::Varchar2(10)),
+        ],
+    )?;
+    let outval: String = stmt.bind_value(1)?;
+    assert_eq!(outval, "NS");
+    let outval: Option<i32> = stmt.bind_value(2)?;
+    assert_eq!(outval, None);
+    Ok(())
+}
+
+#[test]
+fn bind_references_and_smart_pointers() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("begin :1 := :2 || '-' || to_char(:3) || '-' || :4 || '-' || :5; end;")
+        .build()?;
+    let string = String::from("ref");
+    let boxed = Box::new(10i32);
+    let arc_str: Arc<str> = Arc::from("arc");
+    let rc_string = Rc::new(String::from("rc"));
+    stmt.execute(&[
+        &OracleType::Varchar2(30),
+        &&string,
+        &boxed,
+        &arc_str,
+        &rc_string,
+    ])?;
+    let outval: String = stmt.bind_value(1)?;
+    assert_eq!(outval, "ref-10-arc-rc");
+
+    // null values
+    stmt.execute(&[
+        &OracleType::Varchar2(30),
+        &None::<&String>,
+        &None::<Box<i32>>,
+        &None::<Arc<str>>,
+        &None::<Rc<String>>,
+    ])?;
+    let outval: String = stmt.bind_value(1)?;
+    assert_eq!(outval, "---");
+    Ok(())
+}
+
+#[test]
+fn bind_option() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select :1 from dual";
+
+    let row = conn.query_row(sql, &[&Some(5)])?;
+    assert!(matches!(
+        row.sql_values()[0].oracle_type()?,
+        OracleType::Number(_, _)
+    ));
+    assert_eq!(row.get::<_, Option<i32>>(0)?, Some(5));
+
+    // The null value is bound as NUMBER, which is inferred from i32.
+    let row = conn.query_row(sql, &[&None::<i32>])?;
+    assert!(matches!(
+        row.sql_values()[0].oracle_type()?,
+        OracleType::Number(_, _)
+    ));
+    assert_eq!(row.get::<_, Option<i32>>(0)?, None);
+
+    // The type of the null value is also used for PL/SQL.
+    let mut stmt = conn
+        .statement("begin :1 := case when :2 is null then 'null' else 'not null' end; end;")
+        .build()?;
+    stmt.execute(&[&OracleType::Varchar2(10), &None::<i32>])?;
+    assert_eq!(stmt.bind_value::<_, String>(1)?, "null");
+    Ok(())
+}
+
+#[test]
+fn bind_interval_ds_with_precisions() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("begin :1 := :1 + interval '1' second; end;")
+        .build()?;
+    let it = IntervalDS::new(1, 2, 3, 4, 123000000).and_prec(2, 3);
+    stmt.execute(&[&it])?;
+    let outval: IntervalDS = stmt.bind_value(1)?;
+    assert_eq!(outval, IntervalDS::new(1, 2, 3, 5, 123000000));
+    assert_eq!(outval.lfprec(), 2);
+    assert_eq!(outval.fsprec(), 3);
+    assert_eq!(outval.to_string(), "+01 02:03:05.123");
+    Ok(())
+}
+
+#[test]
+fn bind_out_value_with_capacity() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "begin :1 := rpad('x', 2000, 'x'); end;";
+
+    let stmt = conn.execute(sql, &[&OutValue::<String>::with_capacity(2000)])?;
+    let outval: String = stmt.bind_value(1)?;
+    assert_eq!(outval, "x".repeat(2000));
+
+    // The default capacity is large enough.
+    let stmt = conn.execute(sql, &[&OutValue::<String>::new()])?;
+    let outval: String = stmt.bind_value(1)?;
+    assert_eq!(outval.len(), 2000);
+
+    // too small buffer
+    match conn.execute(sql, &[&OutValue::<String>::with_capacity(1000)]) {
+        Err(Error::OciError(dberr)) => assert_eq!(dberr.code(), 6502),
+        res => panic!("unexpected result: {:?}", res.map(|_| ())),
+    }
+    Ok(())
+}
@@ -15,7 +15,13 @@
 
 mod common;
 
-use oracle::{ConnStatus, Connector, Result};
+use oracle::sql_type::{Clob, OracleType, Timestamp};
+use oracle::{
+    ConnStatus, Connector, Error, ExecuteOptions, Feature, ResilientConnection, Result, Version,
+};
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 #[test]
 fn app_context() -> Result<()> {
@@ -32,6 +38,58 @@ fn app_context() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn session_timezone() -> Result<()> {
+    let connector = |tz| {
+        Connector::new(
+            common::main_user(),
+            common::main_password(),
+            common::connect_string(),
+        )
+        .session_timezone(tz)
+        .connect()
+    };
+    // A timestamp without time zone is interpreted in the session time zone
+    // when it is converted to timestamp with local time zone.
+    let sql =
+        "select cast(timestamp '2020-01-01 09:00:00' as timestamp with local time zone) from dual";
+
+    let conn = connector("+09:00")?;
+    let tz = conn.query_row_as::<String>("select sessiontimezone from dual", &[])?;
+    assert_eq!(tz, "+09:00");
+    let ts = conn.query_row_as::<Timestamp>(sql, &[])?;
+    assert_eq!(
+        ts,
+        Timestamp::new(2020, 1, 1, 9, 0, 0, 0).and_tz_hm_offset(9, 0)
+    );
+    assert!(ts.is_local_timezone());
+
+    // The value stored under +09:00 is converted to the session time zone
+    // when it is fetched.
+    let conn = connector("-05:00")?;
+    let mut stmt = conn
+        .statement("select cast(:1 as timestamp with local time zone) from dual")
+        .build()?;
+    let ts = stmt.query_row_as::<Timestamp>(&[&ts])?;
+    assert_eq!(
+        ts,
+        Timestamp::new(2019, 12, 31, 19, 0, 0, 0).and_tz_hm_offset(-5, 0)
+    );
+    assert!(ts.is_local_timezone());
+
+    // TIMESTAMP WITH TIME ZONE isn't affected by the session time zone.
+    let ts = conn.query_row_as::<Timestamp>(
+        "select timestamp '2020-01-01 09:00:00 +09:00' from dual",
+        &[],
+    )?;
+    assert_eq!(
+        ts,
+        Timestamp::new(2020, 1, 1, 9, 0, 0, 0).and_tz_hm_offset(9, 0)
+    );
+    assert!(!ts.is_local_timezone());
+    Ok(())
+}
+
 #[test]
 fn test_autocommit() -> Result<()> {
     let mut conn = common::connect()?;
@@ -75,6 +133,105 @@ fn execute() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn execute_with_options() -> Result<()> {
+    let conn = common::connect()?;
+
+    common::truncate_table(&conn, "TestTempTable")?;
+
+    // The default options follow the autocommit mode.
+    assert_eq!(conn.autocommit(), false);
+    conn.execute_with_options(
+        "insert into TestTempTable values(1, '1')",
+        &[],
+        &ExecuteOptions::new(),
+    )?;
+    conn.rollback()?;
+    let row_count = conn.query_row_as::<u32>("select count(*) from TestTempTable", &[])?;
+    assert_eq!(row_count, 0);
+
+    // commit on success with custom fetch array size
+    let stmt = conn.execute_with_options(
+        "insert into TestTempTable values(:1, :2)",
+        &[&1, &"1"],
+        ExecuteOptions::new()
+            .commit_on_success(true)
+            .fetch_array_size(10),
+    )?;
+    assert_eq!(stmt.row_count()?, 1);
+    conn.rollback()?;
+    let row_count = conn.query_row_as::<u32>("select count(*) from TestTempTable", &[])?;
+    assert_eq!(row_count, 1);
+
+    // parse only
+    conn.execute_with_options(
+        "delete from TestTempTable",
+        &[],
+        ExecuteOptions::new().parse_only(true),
+    )?;
+    let row_count = conn.query_row_as::<u32>("select count(*) from TestTempTable", &[])?;
+    assert_eq!(row_count, 1);
+    assert!(conn
+        .execute_with_options(
+            "delete from NonExistingTable",
+            &[],
+            ExecuteOptions::new().parse_only(true)
+        )
+        .is_err());
+
+    // select statements are rejected as `execute`.
+    assert!(conn
+        .execute_with_options("select * from TestTempTable", &[], &ExecuteOptions::new())
+        .is_err());
+
+    conn.execute("delete from TestTempTable", &[])?;
+    conn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn describe() -> Result<()> {
+    let conn = common::connect()?;
+
+    let colinfo = conn.describe(
+        "select s1.IntCol, s1.StringCol, s2.NullableCol other_col \
+           from TestStrings s1, TestStrings s2 \
+          where s1.IntCol = s2.IntCol + 1 and s1.IntCol > :1",
+    )?;
+    assert_eq!(colinfo.len(), 3);
+    assert_eq!(colinfo[0].name(), "INTCOL");
+    assert_eq!(colinfo[0].oracle_type(), &OracleType::Number(9, 0));
+    assert_eq!(colinfo[0].nullable(), false);
+    assert_eq!(colinfo[1].name(), "STRINGCOL");
+    assert_eq!(colinfo[1].oracle_type(), &OracleType::Varchar2(20));
+    assert_eq!(colinfo[1].nullable(), false);
+    assert_eq!(colinfo[2].name(), "OTHER_COL");
+    assert_eq!(colinfo[2].oracle_type(), &OracleType::Varchar2(50));
+    assert_eq!(colinfo[2].nullable(), true);
+
+    // non-select statements
+    match conn.describe("delete from TestTempTable") {
+        Err(Error::InvalidOperation(_)) => (),
+        res => panic!("unexpected result {:?}", res),
+    }
+    Ok(())
+}
+
+#[test]
+fn session_info() -> Result<()> {
+    let conn = common::connect()?;
+    let info = conn.session_info()?;
+    assert_ne!(info.sid(), 0);
+    assert_ne!(info.instance(), 0);
+    assert_ne!(info.service_name(), "");
+    if let Some(serial) = info.serial() {
+        assert_ne!(serial, 0);
+    }
+    let sid = conn.query_row_as::<u32>("select sys_context('USERENV', 'SID') from dual", &[])?;
+    assert_eq!(info.sid(), sid);
+    Ok(())
+}
+
 #[test]
 fn query() -> Result<()> {
     let conn = common::connect()?;
@@ -106,6 +263,48 @@ fn query() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn query_in() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select * from TestStrings where IntCol in (:ids) order by IntCol";
+
+    let ids = [7, 2, 5];
+    let rows = conn
+        .query_in(sql, "ids", &ids, &[])?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(rows.len(), 3);
+    common::assert_test_string_row(2, &rows[0]);
+    common::assert_test_string_row(5, &rows[1]);
+    common::assert_test_string_row(7, &rows[2]);
+
+    let ids: [i32; 0] = [];
+    assert_eq!(conn.query_in(sql, "ids", &ids, &[])?.count(), 0);
+
+    let ids = (0..1001).collect::<Vec<i32>>();
+    assert!(matches!(
+        conn.query_in(sql, "ids", &ids, &[]),
+        Err(Error::OutOfRange(_))
+    ));
+
+    // mixed with other bind variables
+    let sql = "select * from TestStrings \
+               where IntCol > :1 and IntCol in (:2) and IntCol < :3 order by IntCol";
+    let rows = conn
+        .query_in(sql, "2", &[1, 3, 5, 7, 9], &[&2, &8])?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(rows.len(), 3);
+    common::assert_test_string_row(3, &rows[0]);
+    common::assert_test_string_row(5, &rows[1]);
+    common::assert_test_string_row(7, &rows[2]);
+
+    // the list bind variable isn't found
+    assert!(matches!(
+        conn.query_in(sql, "ids", &[1], &[&2, &8]),
+        Err(Error::InvalidBindName(_))
+    ));
+    Ok(())
+}
+
 #[test]
 fn query_row() -> Result<()> {
     let conn = common::connect()?;
@@ -133,3 +332,791 @@ fn status() -> Result<()> {
     assert_eq!(conn.status()?, ConnStatus::Closed);
     Ok(())
 }
+
+#[test]
+fn max_open_cursors() -> Result<()> {
+    let conn = common::connect()?;
+    let base = conn.open_statement_count();
+    let max = base + 10;
+    let mut stmts = Vec::new();
+
+    // strict mode
+    conn.set_max_open_cursors(max, true)?;
+    for _ in base..max {
+        stmts.push(conn.statement("select 1 from dual").build()?);
+    }
+    assert_eq!(conn.open_statement_count(), max);
+    match conn.statement("select 1 from dual").build() {
+        Err(Error::InvalidOperation(msg)) => assert!(msg.starts_with("too many open statements")),
+        res => panic!("unexpected result: {:?}", res),
+    }
+    stmts.pop();
+    stmts.push(conn.statement("select 1 from dual").build()?);
+
+    // warning mode
+    let warned = Arc::new(AtomicUsize::new(0));
+    let warned_in_handler = warned.clone();
+    conn.set_open_cursors_warning_handler(move |count| {
+        warned_in_handler.store(count, Ordering::Relaxed);
+    })?;
+    conn.set_max_open_cursors(max, false)?;
+    stmts.push(conn.statement("select 1 from dual").build()?);
+    assert_eq!(warned.load(Ordering::Relaxed), max + 1);
+
+    stmts.clear();
+    assert_eq!(conn.open_statement_count(), base);
+    Ok(())
+}
+
+#[test]
+fn commit_many() -> Result<()> {
+    let conn = common::connect()?;
+    conn.execute(
+        "delete from TestTempTable where IntCol between 9101 and 9109",
+        &[],
+    )?;
+    conn.commit()?;
+
+    let sql = "insert into TestTempTable select :1 + level, 'batch ' || :2 from dual connect by level <= 3";
+    let count = conn.commit_many(&[
+        (sql, &[&9100, &9100]),
+        (sql, &[&9103, &9103]),
+        (sql, &[&9106, &9106]),
+    ])?;
+    assert_eq!(count, 9);
+
+    // The rows are visible from another session.
+    let conn2 = common::connect()?;
+    let sql = "select count(*) from TestTempTable where IntCol between 9101 and 9109";
+    assert_eq!(conn2.query_row_as::<u64>(sql, &[])?, 9);
+
+    // All changes are rolled back on error.
+    let res = conn.commit_many(&[
+        ("delete from TestTempTable where IntCol = 9101", &[]),
+        ("insert into NonExistentTable values (1)", &[]),
+    ]);
+    assert!(res.is_err());
+    assert_eq!(conn.query_row_as::<u64>(sql, &[])?, 9);
+
+    conn.execute(
+        "delete from TestTempTable where IntCol between 9101 and 9109",
+        &[],
+    )?;
+    conn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn commit_async() -> Result<()> {
+    let conn = common::connect()?;
+    conn.execute("delete from TestTempTable where IntCol = 9111", &[])?;
+    conn.commit()?;
+
+    conn.execute(
+        "insert into TestTempTable values (:1, :2)",
+        &[&9111, &"async"],
+    )?;
+    conn.commit_async()?;
+
+    // The row is visible from another session.
+    let conn2 = common::connect()?;
+    let sql = "select StringCol from TestTempTable where IntCol = 9111";
+    assert_eq!(conn2.query_row_as::<String>(sql, &[])?, "async");
+
+    // The transaction was committed, so rollback doesn't discard the row.
+    conn.rollback()?;
+    assert_eq!(conn.query_row_as::<String>(sql, &[])?, "async");
+
+    conn.execute("delete from TestTempTable where IntCol = 9111", &[])?;
+    conn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn subscribe_and_unsubscribe() -> Result<()> {
+    let conn = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .events(true)
+    .connect()?;
+    let id = conn.subscribe("select IntCol from TestTempTable", |_| {})?;
+    assert_eq!(conn.subscriptions()?, vec![id]);
+
+    conn.unsubscribe(id)?;
+    assert!(conn.subscriptions()?.is_empty());
+
+    // The second unsubscribe fails without touching the server.
+    match conn.unsubscribe(id) {
+        Err(Error::InvalidOperation(msg)) => {
+            assert_eq!(msg, format!("subscription {} is not registered", id))
+        }
+        res => panic!("unexpected result: {:?}", res),
+    }
+
+    // Subscriptions left are unregistered on close.
+    conn.subscribe("select IntCol from TestTempTable", |_| {})?;
+    conn.close()?;
+    Ok(())
+}
+
+#[test]
+fn external_and_internal_names() -> Result<()> {
+    let conn = common::connect()?;
+    conn.set_external_name("rust-oracle external")?;
+    conn.set_internal_name("rust-oracle internal")?;
+    assert_eq!(conn.external_name()?, "rust-oracle external");
+    assert_eq!(conn.internal_name()?, "rust-oracle internal");
+
+    // The names are kept while transactions begin and end.
+    conn.execute("insert into TestTempTable values (9201, 'name')", &[])?;
+    conn.rollback()?;
+    assert_eq!(conn.external_name()?, "rust-oracle external");
+    assert_eq!(conn.internal_name()?, "rust-oracle internal");
+    Ok(())
+}
+
+#[test]
+fn execute_file() -> Result<()> {
+    let conn = common::connect()?;
+    let path = std::env::temp_dir().join(format!("rust-oracle-test-{}.sql", std::process::id()));
+    // The second statement has no terminator.
+    std::fs::write(
+        &path,
+        "insert into TestTempTable values (7001, 'first');\n\
+         insert into TestTempTable values (7002, 'second')\n",
+    )
+    .unwrap();
+    let result = conn.execute_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    result?;
+    let rows = conn
+        .query_as::<(i32, String)>(
+            "select IntCol, StringCol from TestTempTable where IntCol in (7001, 7002) order by IntCol",
+            &[],
+        )?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        rows,
+        vec![(7001, "first".to_string()), (7002, "second".to_string())]
+    );
+    conn.rollback()?;
+
+    // The error message includes the line number of the failing statement.
+    let err = conn
+        .execute_script("insert into TestTempTable values (7003, 'ok');\n\ninsert into NoSuchTable values (1);\n")
+        .unwrap_err();
+    match err {
+        Error::OciError(ref dberr) => assert!(dberr.message().ends_with("(at line 3)"), "{}", err),
+        _ => panic!("unexpected error: {}", err),
+    }
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn max_lob_inline_size() -> Result<()> {
+    let conn = common::connect()?;
+    assert_eq!(conn.max_lob_inline_size()?, None);
+    conn.execute("insert into TestCLOBs values (1, 'small clob')", &[])?;
+    conn.execute(
+        "insert into TestCLOBs values (2, to_clob(rpad('x', 4000, 'x')) || rpad('y', 4000, 'y'))",
+        &[],
+    )?;
+
+    conn.set_max_lob_inline_size(Some(1024))?;
+    assert_eq!(conn.max_lob_inline_size()?, Some(1024));
+
+    // The CLOB within the size is fetched as String in the same round trips
+    // as a VARCHAR2 column.
+    let round_trips = |sql: &str| -> Result<(String, u64)> {
+        let before = conn.round_trip_count()?;
+        let val = conn.query_row_as::<String>(sql, &[])?;
+        let after = conn.round_trip_count()?;
+        Ok((val, after - before))
+    };
+    let (val, expected) = round_trips("select to_char(IntCol) from TestCLOBs where IntCol = 1")?;
+    assert_eq!(val, "1");
+    let (val, actual) = round_trips("select CLOBCol from TestCLOBs where IntCol = 1")?;
+    assert_eq!(val, "small clob");
+    assert_eq!(actual, expected);
+
+    // The CLOB over the size is fetched as a locator.
+    let mut clob =
+        conn.query_row_as::<Clob>("select CLOBCol from TestCLOBs where IntCol = 2", &[])?;
+    let mut data = String::new();
+    clob.read_to_string(&mut data).unwrap();
+    assert_eq!(data.len(), 8000);
+
+    conn.set_max_lob_inline_size(None)?;
+    assert_eq!(conn.max_lob_inline_size()?, None);
+    let data =
+        conn.query_row_as::<String>("select CLOBCol from TestCLOBs where IntCol = 2", &[])?;
+    assert_eq!(data.len(), 8000);
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn round_trip_count() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select IntCol from TestNumbers order by IntCol"; // 10 rows
+
+    let fetch_all = |prefetch_rows: u32, fetch_array_size: u32| -> Result<u64> {
+        let mut stmt = conn
+            .statement(sql)
+            .prefetch_rows(prefetch_rows)
+            .fetch_array_size(fetch_array_size)
+            .build()?;
+        let before = conn.round_trip_count()?;
+        let count = stmt.query(&[])?.count();
+        let after = conn.round_trip_count()?;
+        assert_eq!(count, 10);
+        Ok(after - before)
+    };
+
+    // All rows are fetched along with the execution.
+    let round_trips = fetch_all(20, 20)?;
+    assert!((1..=2).contains(&round_trips), "{}", round_trips);
+
+    // Two rows per round trip
+    let round_trips = fetch_all(2, 2)?;
+    assert!((5..=7).contains(&round_trips), "{}", round_trips);
+
+    // No round trips between two calls
+    let before = conn.round_trip_count()?;
+    let after = conn.round_trip_count()?;
+    assert_eq!(after, before);
+    Ok(())
+}
+
+#[test]
+fn set_db_op() -> Result<()> {
+    let conn = common::connect()?;
+    if !common::check_oracle_version("set_db_op", &conn, 12, 1)? {
+        return Ok(());
+    }
+    let db_op = format!("rust_oracle_test_{}", std::process::id());
+    conn.set_db_op(&db_op)?;
+    conn.query_row("select /*+ MONITOR */ count(*) from TestNumbers", &[])?;
+
+    let sql = "select count(*) from v$sql_monitor where dbop_name = :1";
+    match conn.query_row_as::<u32>(sql, &[&db_op]) {
+        Ok(count) => assert!(count >= 1, "db_op {} isn't monitored", db_op),
+        Err(Error::OciError(err)) if err.code() == 942 || err.code() == 13716 => {
+            // ORA-00942: table or view does not exist
+            // ORA-13716: Diagnostic Package License is needed for using this feature.
+            println!(
+                "Skip set_db_op, which requires access to v$sql_monitor: {}",
+                err
+            );
+        }
+        Err(err) => return Err(err),
+    }
+    Ok(())
+}
+
+#[test]
+fn resilient_connection() -> Result<()> {
+    let connector = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    );
+    let mut conn = ResilientConnection::new(connector)?;
+    common::truncate_table(conn.connection(), "TestTempTable")?;
+
+    // A query is retried after reconnection.
+    conn.connection().close()?;
+    let val = conn.query_row_as::<i32>("select 1 from dual", &[])?;
+    assert_eq!(val, 1);
+    assert_eq!(conn.connection().status()?, ConnStatus::Normal);
+
+    // DML isn't retried by default.
+    conn.connection().close()?;
+    let err = conn
+        .execute("insert into TestTempTable values(1, '1')", &[])
+        .unwrap_err();
+    assert!(err.is_disconnected(), "{:?}", err);
+
+    // DML is retried when it is allowed.
+    conn.retry_non_idempotent(true);
+    let row_count = conn.execute("insert into TestTempTable values(1, '1')", &[])?;
+    assert_eq!(row_count, 1);
+    let rows = conn.query_as::<(i32, String)>("select * from TestTempTable", &[])?;
+    assert_eq!(rows, vec![(1, "1".to_string())]);
+    conn.rollback()?;
+
+    // The connection is checked before use when it is idle.
+    conn.ping_interval(Some(std::time::Duration::from_secs(0)));
+    conn.retry_non_idempotent(false);
+    conn.connection().close()?;
+    let row_count = conn.execute("delete TestTempTable", &[])?;
+    assert_eq!(row_count, 0);
+    Ok(())
+}
+
+#[test]
+fn resilient_connection_in_transaction() -> Result<()> {
+    let connector = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    );
+    let mut conn = ResilientConnection::new(connector)?;
+    conn.retry_non_idempotent(true);
+    common::truncate_table(conn.connection(), "TestTempTable")?;
+    assert!(!conn.in_transaction());
+
+    // Queries don't start a transaction.
+    conn.query_row_as::<i32>("select 1 from dual", &[])?;
+    assert!(!conn.in_transaction());
+
+    // Uncommitted DML prevents automatic reconnection.
+    conn.execute("insert into TestTempTable values(1, '1')", &[])?;
+    assert!(conn.in_transaction());
+    conn.connection().close()?;
+    let err = conn
+        .query_row_as::<i32>("select 1 from dual", &[])
+        .unwrap_err();
+    assert!(err.is_disconnected(), "{:?}", err);
+    assert_eq!(conn.reconnect_count(), 0);
+    assert!(conn.in_transaction());
+
+    // Explicit reconnection discards the transaction.
+    conn.reconnect()?;
+    assert!(!conn.in_transaction());
+    let rows = conn.query_as::<(i32, String)>("select * from TestTempTable", &[])?;
+    assert!(rows.is_empty());
+
+    // A statement is retried again after commit.
+    conn.execute("insert into TestTempTable values(1, '1')", &[])?;
+    conn.commit()?;
+    assert!(!conn.in_transaction());
+    conn.connection().close()?;
+    conn.execute("delete TestTempTable", &[])?;
+    assert_eq!(conn.retry_count(), 1);
+    conn.rollback()?;
+    assert!(!conn.in_transaction());
+    Ok(())
+}
+
+#[test]
+fn resilient_connection_metrics() -> Result<()> {
+    let connector = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    );
+    let mut conn = ResilientConnection::new(connector)?;
+    assert_eq!(conn.reconnect_count(), 0);
+    assert_eq!(conn.retry_count(), 0);
+    assert!(conn.last_error().is_none());
+
+    // A query is retried after reconnection.
+    conn.connection().close()?;
+    conn.query_row_as::<i32>("select 1 from dual", &[])?;
+    assert_eq!(conn.reconnect_count(), 1);
+    assert_eq!(conn.retry_count(), 1);
+    assert!(conn.last_error().map_or(false, Error::is_disconnected));
+
+    // An error returned to the caller doesn't reconnect.
+    conn.connection().close()?;
+    assert!(conn.execute("delete TestTempTable", &[]).is_err());
+    assert_eq!(conn.reconnect_count(), 1);
+    assert_eq!(conn.retry_count(), 1);
+    conn.query_row_as::<i32>("select 1 from dual", &[])?;
+    assert_eq!(conn.reconnect_count(), 2);
+    assert_eq!(conn.retry_count(), 2);
+
+    // Reconnection by ping isn't a retry.
+    conn.ping_interval(Some(std::time::Duration::from_secs(0)));
+    conn.connection().close()?;
+    conn.query_row_as::<i32>("select 1 from dual", &[])?;
+    assert_eq!(conn.reconnect_count(), 3);
+    assert_eq!(conn.retry_count(), 2);
+
+    // explicit reconnection
+    conn.reconnect()?;
+    assert_eq!(conn.reconnect_count(), 4);
+    Ok(())
+}
+
+#[test]
+fn client_version() -> Result<()> {
+    let conn = common::connect()?;
+    let version = conn.client_version()?;
+    assert!(version.major() > 0, "{}", version);
+    assert_eq!(version, Version::client()?);
+    Ok(())
+}
+
+#[test]
+fn supports() -> Result<()> {
+    let conn = common::connect()?;
+    assert!(conn.supports(Feature::Tpc));
+    if common::check_oracle_version("supports", &conn, 12, 1)? {
+        assert!(conn.supports(Feature::ImplicitResults));
+        assert!(conn.supports(Feature::PlsqlBoolean));
+    } else {
+        assert!(!conn.supports(Feature::ImplicitResults));
+    }
+    let client_ver = conn.client_version()?;
+    let server_ver = conn.server_version()?.0;
+    assert_eq!(
+        conn.supports(Feature::NativeJson),
+        client_ver.major() >= 21 && server_ver.major() >= 21
+    );
+    Ok(())
+}
+
+#[test]
+fn new_temp_clob() -> Result<()> {
+    let conn = common::connect()?;
+    let line = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ\n";
+    let count = 1024 * 1024 / line.len();
+    let mut clob = conn.new_temp_clob()?;
+    for _ in 0..count {
+        clob.write_all(line.as_bytes()).unwrap();
+    }
+    conn.execute("insert into TestCLOBs values (:1, :2)", &[&1, &clob])?;
+    drop(clob);
+
+    let data =
+        conn.query_row_as::<String>("select CLOBCol from TestCLOBs where IntCol = 1", &[])?;
+    assert_eq!(data.len(), line.len() * count);
+    assert!(data.lines().all(|l| l == line.trim_end()));
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn new_temp_blob() -> Result<()> {
+    let conn = common::connect()?;
+    let mut blob = conn.new_temp_blob()?;
+    blob.write_all(&[1, 2, 3, 4]).unwrap();
+    conn.execute("insert into TestBLOBs values (:1, :2)", &[&1, &blob])?;
+    let data =
+        conn.query_row_as::<Vec<u8>>("select BLOBCol from TestBLOBs where IntCol = 1", &[])?;
+    assert_eq!(data, [1, 2, 3, 4]);
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn alter_session() -> Result<()> {
+    let conn = common::connect()?;
+    conn.alter_session()
+        .nls_date_format("YYYY/MM/DD")
+        .time_zone("+03:00")
+        .execute()?;
+    let date_format = conn.query_row_as::<String>(
+        "select value from nls_session_parameters where parameter = 'NLS_DATE_FORMAT'",
+        &[],
+    )?;
+    assert_eq!(date_format, "YYYY/MM/DD");
+    let time_zone = conn.query_row_as::<String>("select sessiontimezone from dual", &[])?;
+    assert_eq!(time_zone, "+03:00");
+
+    // The error message contains the offending clause.
+    let err = conn
+        .alter_session()
+        .nls_date_format("YYYY-MM-DD")
+        .time_zone("invalid time zone")
+        .execute()
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("TIME_ZONE = 'invalid time zone'"),
+        "{}",
+        err
+    );
+    Ok(())
+}
+
+#[test]
+fn default_fetch_array_size_and_prefetch_rows() -> Result<()> {
+    let sql = "select IntCol from TestNumbers order by IntCol";
+    let conn = common::connect()?;
+    let stmt = conn.statement(sql).build()?;
+    assert_eq!(stmt.fetch_array_size(), 100);
+    assert_eq!(stmt.prefetch_rows(), 2);
+
+    let conn = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .default_fetch_array_size(5000)
+    .default_prefetch_rows(500)
+    .connect()?;
+    let mut stmt = conn.statement(sql).build()?;
+    assert_eq!(stmt.fetch_array_size(), 5000);
+    assert_eq!(stmt.prefetch_rows(), 500);
+    assert_eq!(stmt.query_as::<i32>(&[])?.count(), 10);
+
+    // per-statement settings override the defaults
+    let stmt = conn
+        .statement(sql)
+        .fetch_array_size(10)
+        .prefetch_rows(0)
+        .build()?;
+    assert_eq!(stmt.fetch_array_size(), 10);
+    assert_eq!(stmt.prefetch_rows(), 0);
+    Ok(())
+}
+
+#[test]
+fn transaction_rollback_on_drop() -> Result<()> {
+    let mut conn = common::connect()?;
+    let count_sql = "select count(*) from TestTempTable where IntCol = 9101";
+
+    conn.execute("delete from TestTempTable where IntCol = 9101", &[])?;
+    conn.commit()?;
+
+    {
+        let tx = conn.transaction()?;
+        tx.execute("insert into TestTempTable values (9101, 'tx')", &[])?;
+        assert_eq!(tx.query_row_as::<u32>(count_sql, &[])?, 1);
+    }
+    assert_eq!(conn.query_row_as::<u32>(count_sql, &[])?, 0);
+
+    // autocommit is disabled inside of the transaction and restored after that.
+    conn.set_autocommit(true);
+    {
+        let tx = conn.transaction()?;
+        assert_eq!(tx.autocommit(), false);
+        tx.execute("insert into TestTempTable values (9101, 'tx')", &[])?;
+    }
+    assert_eq!(conn.autocommit(), true);
+    assert_eq!(conn.query_row_as::<u32>(count_sql, &[])?, 0);
+    Ok(())
+}
+
+#[test]
+fn transaction_commit() -> Result<()> {
+    let conn = common::connect()?;
+    let count_sql = "select count(*) from TestTempTable where IntCol = 9102";
+
+    conn.execute("delete from TestTempTable where IntCol = 9102", &[])?;
+    conn.commit()?;
+
+    let tx = conn.transaction()?;
+    tx.execute("insert into TestTempTable values (9102, 'tx')", &[])?;
+    tx.commit()?;
+    conn.rollback()?;
+    assert_eq!(conn.query_row_as::<u32>(count_sql, &[])?, 1);
+
+    conn.execute("delete from TestTempTable where IntCol = 9102", &[])?;
+    conn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn explain_plan() -> Result<()> {
+    let conn = common::connect()?;
+    let plan = conn.explain_plan(
+        "select s.StringCol, n.NumberCol \
+           from TestStrings s, TestNumbers n \
+          where s.IntCol = n.IntCol",
+    )?;
+    assert!(!plan.is_empty());
+    assert_eq!(plan[0].id(), 0);
+    assert_eq!(plan[0].parent_id(), None);
+    assert_eq!(plan[0].operation(), "SELECT STATEMENT");
+    assert!(
+        plan.iter()
+            .any(|row| row.operation().contains("JOIN") || row.operation() == "NESTED LOOPS"),
+        "{:?}",
+        plan
+    );
+    assert!(plan
+        .iter()
+        .any(|row| row.object_name() == Some("TESTSTRINGS")));
+
+    // The plan rows are deleted.
+    let count = conn.query_row_as::<u32>(
+        "select count(*) from plan_table where statement_id like 'rust-oracle-%'",
+        &[],
+    )?;
+    assert_eq!(count, 0);
+    Ok(())
+}
+
+#[test]
+fn table_columns() -> Result<()> {
+    let conn = common::connect()?;
+    let owner = common::main_user().to_uppercase();
+
+    let tables = conn.list_tables(&owner)?;
+    assert!(tables.iter().any(|name| name == "TESTSTRINGS"));
+    assert!(tables.windows(2).all(|w| w[0] <= w[1]));
+
+    let cols = conn.table_columns(&owner, "TESTSTRINGS")?;
+    let names = cols.iter().map(|col| col.name()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "INTCOL",
+            "STRINGCOL",
+            "RAWCOL",
+            "FIXEDCHARCOL",
+            "NULLABLECOL"
+        ]
+    );
+    assert_eq!(cols[0].data_type(), "NUMBER");
+    assert_eq!(cols[0].data_precision(), Some(9));
+    assert_eq!(cols[0].data_scale(), Some(0));
+    assert!(!cols[0].nullable());
+    assert_eq!(cols[1].data_type(), "VARCHAR2");
+    assert_eq!(cols[1].data_length(), 20);
+    assert_eq!(cols[1].data_precision(), None);
+    assert_eq!(cols[4].data_type(), "VARCHAR2");
+    assert!(cols[4].nullable());
+    assert_eq!(cols[4].data_default(), None);
+
+    assert!(conn.table_columns(&owner, "NO_SUCH_TABLE")?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn database_info() -> Result<()> {
+    let conn = common::connect()?;
+    let info = conn.database_info()?;
+    assert!(!info.container_name().is_empty());
+    match info.container_id() {
+        0 => {
+            assert_eq!(info.is_cdb(), Some(false));
+            assert_eq!(info.pdb_name(), None);
+        }
+        1 => {
+            assert!(info.is_cdb_root());
+            assert_eq!(info.container_name(), "CDB$ROOT");
+            assert_eq!(info.pdb_name(), None);
+        }
+        _ => {
+            assert!(!info.is_cdb_root());
+            assert_eq!(info.pdb_name(), Some(info.container_name()));
+            assert_ne!(info.is_cdb(), Some(false));
+        }
+    }
+    assert_eq!(info.is_autonomous(), info.cloud_service().is_some());
+    Ok(())
+}
+
+#[test]
+fn take_warnings() -> Result<()> {
+    let conn = common::connect()?;
+    conn.take_warnings();
+
+    // create a procedure with compilation error
+    conn.execute(
+        "create or replace procedure rust_oracle_warn_proc is begin; null; end;",
+        &[],
+    )?;
+    // a successful call doesn't clear accumulated warnings.
+    conn.query_row("select 1 from dual", &[])?;
+    conn.execute("drop procedure rust_oracle_warn_proc", &[])?;
+
+    let warnings = conn.take_warnings();
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    match &warnings[0] {
+        Error::OciError(dberr) => assert_eq!(dberr.code(), 24344),
+        err => panic!("unexpected warning: {:?}", err),
+    }
+    assert!(conn.take_warnings().is_empty());
+
+    // Only the last 100 warnings are kept.
+    for _ in 0..110 {
+        conn.execute(
+            "create or replace procedure rust_oracle_warn_proc is begin; null; end;",
+            &[],
+        )?;
+    }
+    conn.execute("drop procedure rust_oracle_warn_proc", &[])?;
+    assert_eq!(conn.take_warnings().len(), 100);
+    Ok(())
+}
+
+// This test runs only when both ODPIC_TEST_TCPS_CONNECT_STRING and
+// ODPIC_TEST_WALLET_LOCATION are set, for example to an Autonomous
+// Database endpoint and the directory of its unzipped wallet.
+#[test]
+fn connect_with_wallet_location() -> Result<()> {
+    let (connect_string, wallet_location) = match (
+        std::env::var("ODPIC_TEST_TCPS_CONNECT_STRING"),
+        std::env::var("ODPIC_TEST_WALLET_LOCATION"),
+    ) {
+        (Ok(connect_string), Ok(wallet_location)) => (connect_string, wallet_location),
+        _ => return Ok(()),
+    };
+    let conn = Connector::new(common::main_user(), common::main_password(), connect_string)
+        .wallet_location(wallet_location)
+        .connect()?;
+    let val = conn.query_row_as::<i32>("select 1 from dual", &[])?;
+    assert_eq!(val, 1);
+    Ok(())
+}
+
+// This test runs only when ODPIC_TEST_DIR_NAME and ODPIC_TEST_DIR_PATH are
+// set to the name of a directory object readable by the test user and its
+// path, which must be writable from the machine running tests.
+#[test]
+fn read_bfile() -> Result<()> {
+    let (dir_name, dir_path) = match (
+        std::env::var("ODPIC_TEST_DIR_NAME"),
+        std::env::var("ODPIC_TEST_DIR_PATH"),
+    ) {
+        (Ok(dir_name), Ok(dir_path)) => (dir_name, dir_path),
+        _ => return Ok(()),
+    };
+    let conn = common::connect()?;
+    let file_name = "rust_oracle_read_bfile.bin";
+    let data = (0..=255u8).cycle().take(10000).collect::<Vec<_>>();
+    let path = std::path::Path::new(&dir_path).join(file_name);
+    std::fs::write(&path, &data).unwrap();
+    let res = conn.read_bfile(&dir_name, file_name);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(res?, data);
+    Ok(())
+}
+
+#[test]
+fn connect_with_proxy_user() -> Result<()> {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let counter_in_callback = counter.clone();
+    let conn = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .proxy_user(common::proxy_user())
+    .session_init(move |conn| {
+        counter_in_callback.fetch_add(1, Ordering::Relaxed);
+        conn.execute("alter session set nls_date_format = 'YYYY-MM-DD'", &[])?;
+        Ok(())
+    })
+    .connect()?;
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    let row = conn.query_row_as::<(String, String, String)>(
+        "select sys_context('USERENV', 'SESSION_USER'), sys_context('USERENV', 'PROXY_USER'), to_char(date '2021-02-03') from dual",
+        &[],
+    )?;
+    assert_eq!(row.0, common::proxy_user().to_uppercase());
+    assert_eq!(row.1, common::main_user().to_uppercase());
+    assert_eq!(row.2, "2021-02-03");
+    Ok(())
+}
+
+#[test]
+fn execute_timed() -> Result<()> {
+    let conn = common::connect()?;
+    let (stmt, elapsed) = conn.execute_timed(
+        "update TestStrings set IntCol = IntCol where IntCol <= :1",
+        &[&3],
+    )?;
+    assert!(elapsed > std::time::Duration::ZERO);
+    assert_eq!(stmt.row_count()?, 3);
+    conn.rollback()?;
+    Ok(())
+}
@@ -15,8 +15,9 @@
 
 mod common;
 
-use oracle::sql_type::{IntervalDS, IntervalYM, OracleType, Timestamp};
+use oracle::sql_type::{IntervalDS, IntervalYM, OracleType, ScaledInt, Timestamp, Vector};
 use oracle::{Error, Result};
+use std::borrow::Cow;
 
 macro_rules! chk_num_from {
     ($conn:ident, $val_from:expr, $val_to:expr, $(($T:ident, $success:tt)),+) => {
@@ -455,6 +456,22 @@ fn numeric_to_sql() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn scaled_int_from_to_sql() -> Result<()> {
+    let conn = common::connect()?;
+    let val = conn.query_row_as::<ScaledInt>("select 123.45 from dual", &[])?;
+    assert_eq!((val.mantissa(), val.scale()), (12345, 2));
+    let val = conn.query_row_as::<ScaledInt>("select -0.001 from dual", &[])?;
+    assert_eq!((val.mantissa(), val.scale()), (-1, 3));
+    let val = conn.query_row_as::<ScaledInt>("select 100 from dual", &[])?;
+    assert_eq!((val.mantissa(), val.scale()), (100, 0));
+
+    let mut stmt = conn.statement("begin :out := to_char(:in); end;").build()?;
+    stmt.execute(&[&OracleType::Varchar2(50), &ScaledInt::new(-12345, 2)])?;
+    assert_eq!(stmt.bind_value::<_, String>(1)?, "-123.45");
+    Ok(())
+}
+
 #[test]
 fn raw_from_to_sql() -> Result<()> {
     let conn = common::connect()?;
@@ -472,6 +489,52 @@ fn raw_from_to_sql() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn boxed_and_cow_str_from_sql() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select StringCol from TestStrings where IntCol = 1";
+
+    let s: Box<str> = conn.query_row_as(sql, &[])?;
+    assert_eq!(&*s, "String 1");
+
+    let s: Cow<str> = conn.query_row_as(sql, &[])?;
+    assert_eq!(s, "String 1");
+    // The fetch buffer isn't borrowed.
+    assert!(matches!(s, Cow::Owned(_)));
+
+    let s: Option<Box<str>> = conn.query_row_as("select null from dual", &[])?;
+    assert_eq!(s, None);
+    Ok(())
+}
+
+#[test]
+fn char_from_to_sql() -> Result<()> {
+    let conn = common::connect()?;
+
+    test_from_sql!(&conn, "cast('A' as char(1))", &OracleType::Char(1), &'A');
+    test_from_sql!(
+        &conn,
+        "cast(unistr('\\3042') as nchar(1))",
+        &OracleType::NChar(1),
+        &'\u{3042}'
+    );
+    test_to_sql!(&conn, &'A', ":1", "A");
+    test_to_sql!(&conn, &'\u{3042}', ":1", "\u{3042}");
+
+    for c in &['A', '\u{3042}'] {
+        let result: char = conn.query_row_as("select :1 from dual", &[c])?;
+        assert_eq!(result, *c);
+    }
+
+    // more than one character
+    let row = conn.query_row("select 'AB' from dual", &[])?;
+    match row.get::<_, char>(0) {
+        Err(Error::ParseError(_)) => (),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    Ok(())
+}
+
 //
 // Timestamp
 //
@@ -809,6 +872,61 @@ fn interval_ym_to_sql() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn null_value_from_sql() -> Result<()> {
+    let conn = common::connect()?;
+    let row = conn.query_row("select cast(null as number), 10 from dual", &[])?;
+
+    // NULL
+    match row.get::<_, i32>(0) {
+        Err(Error::NullValue) => (),
+        res => panic!("unexpected result {:?}", res),
+    }
+    assert_eq!(row.get::<_, Option<i32>>(0)?, None);
+    match row.get::<_, String>(0) {
+        Err(Error::NullValue) => (),
+        res => panic!("unexpected result {:?}", res),
+    }
+    assert_eq!(row.get::<_, Option<String>>(0)?, None);
+
+    // non-NULL
+    assert_eq!(row.get::<_, i32>(1)?, 10);
+    assert_eq!(row.get::<_, Option<i32>>(1)?, Some(10));
+
+    // tuple
+    let (v1, v2) = row.get_as::<(Option<i32>, Option<i32>)>()?;
+    assert_eq!(v1, None);
+    assert_eq!(v2, Some(10));
+
+    // uninitialized bind values
+    let stmt = conn.statement("begin :1 := 1; end;").build()?;
+    match stmt.bind_value::<_, Option<i32>>(1) {
+        Err(Error::UninitializedBindValue) => (),
+        res => panic!("unexpected result {:?}", res),
+    }
+    Ok(())
+}
+
+#[test]
+fn vector_to_and_from_sql() -> Result<()> {
+    let conn = common::connect()?;
+    if !common::check_oracle_version("vector_to_and_from_sql", &conn, 23, 0)? {
+        return Ok(());
+    }
+    let embedding = vec![1.5f32, -0.25, 1e-3];
+    conn.execute(
+        "insert into TestVectors values (:1, :2)",
+        &[&1, &Vector::new(embedding.clone())],
+    )?;
+    let val = conn.query_row_as::<Vector<f32>>(
+        "select from_vector(VectorCol) from TestVectors where IntCol = 1",
+        &[],
+    )?;
+    assert_eq!(val.as_slice(), &embedding[..]);
+    conn.rollback()?;
+    Ok(())
+}
+
 #[cfg(feature = "chrono")]
 #[allow(deprecated)]
 mod chrono {
@@ -907,6 +1025,34 @@ mod chrono {
         Ok(())
     }
 
+    #[test]
+    fn datetime_from_local_time_zone() -> Result<()> {
+        let conn = common::connect()?;
+        let sql = "select cast(TO_TIMESTAMP_TZ('2012-03-04 05:06:07 +00:00', \
+                                             'YYYY-MM-DD HH24:MI:SS TZH:TZM') \
+                               as timestamp with local time zone) from dual";
+
+        // TIMESTAMP WITH LOCAL TIME ZONE -> DateTime<FixedOffset> TZ is the session time zone.
+        conn.alter_session().time_zone("+09:00").execute()?;
+        let dttm = conn.query_row_as::<DateTime<FixedOffset>>(sql, &[])?;
+        assert_eq!(
+            dttm,
+            FixedOffset::east(9 * 3600)
+                .ymd(2012, 3, 4)
+                .and_hms(14, 6, 7)
+        );
+        assert_eq!(dttm.offset().local_minus_utc(), 9 * 3600);
+
+        // The offset follows the session time zone changed by ALTER SESSION.
+        conn.execute("alter session set time_zone = '-05:30'", &[])?;
+        let dttm = conn.query_row_as::<DateTime<FixedOffset>>(sql, &[])?;
+        assert_eq!(dttm, Utc.ymd(2012, 3, 4).and_hms(5, 6, 7));
+        assert_eq!(dttm.offset().local_minus_utc(), -(5 * 3600 + 30 * 60));
+        assert_eq!(dttm.hour(), 23);
+        assert_eq!(dttm.minute(), 36);
+        Ok(())
+    }
+
     #[test]
     fn datetime_to_sql() -> Result<()> {
         let conn = common::connect()?;
@@ -1258,4 +1404,199 @@ mod chrono {
         }
         Ok(())
     }
+
+    #[test]
+    fn duration_round_trip() -> Result<()> {
+        let conn = common::connect()?;
+        let d = Duration::hours(26);
+        let mut stmt = conn
+            .statement("select cast(:1 as interval day to second) from dual")
+            .build()?;
+        let mut rows = stmt.query_as::<Duration>(&[&d])?;
+        assert_eq!(
+            rows.column_info()[0].oracle_type(),
+            &OracleType::IntervalDS(2, 6)
+        );
+        assert_eq!(rows.next().unwrap()?, d);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "time")]
+mod time {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use oracle::{Error, Result};
+    use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn time(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Time {
+        Time::from_hms_nano(hour, minute, second, nanosecond).unwrap()
+    }
+
+    #[test]
+    fn offset_date_time_from_sql() -> Result<()> {
+        let conn = common::connect()?;
+        let offset = UtcOffset::from_hms(1, 30, 0).unwrap();
+
+        // TIMESTAMP WITH TIME ZONE -> OffsetDateTime
+        let dttm = PrimitiveDateTime::new(date(2012, Month::March, 4), time(5, 6, 7, 123456789))
+            .assume_offset(offset);
+        test_from_sql!(&conn,
+                       "TO_TIMESTAMP_TZ('2012-03-04 05:06:07.123456789 +01:30', 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+                       &OracleType::TimestampTZ(9), &dttm);
+
+        // TIMESTAMP -> OffsetDateTime  TZ is '+00:00'.
+        let dttm = PrimitiveDateTime::new(date(2012, Month::March, 4), time(5, 6, 7, 123456789))
+            .assume_utc();
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &dttm
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn offset_date_time_round_trip() -> Result<()> {
+        let conn = common::connect()?;
+        let dttm = PrimitiveDateTime::new(date(2012, Month::March, 4), time(5, 6, 7, 123456789))
+            .assume_offset(UtcOffset::from_hms(-5, -30, 0).unwrap());
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+            "2012-03-04 05:06:07.123456789 -05:30"
+        );
+        let fetched = conn.query_row_as::<OffsetDateTime>("select :1 from dual", &[&dttm])?;
+        assert_eq!(fetched, dttm);
+        assert_eq!(fetched.offset(), dttm.offset());
+        Ok(())
+    }
+
+    #[test]
+    fn primitive_date_time_and_date() -> Result<()> {
+        let conn = common::connect()?;
+
+        // PrimitiveDateTime -> TIMESTAMP
+        let dttm = PrimitiveDateTime::new(date(2012, Month::March, 4), time(5, 6, 7, 123456789));
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            "2012-03-04 05:06:07.123456789"
+        );
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &dttm
+        );
+
+        // Date -> TIMESTAMP
+        let d = date(2012, Month::March, 4);
+        test_to_sql!(
+            &conn,
+            &d,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            "2012-03-04 00:00:00.000000000"
+        );
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &d
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn duration_round_trip() -> Result<()> {
+        let conn = common::connect()?;
+
+        // INTERVAL DAY TO SECOND -> Duration
+        let d = Duration::days(1)
+            + Duration::hours(2)
+            + Duration::minutes(3)
+            + Duration::seconds(4)
+            + Duration::nanoseconds(123456789);
+        test_from_sql!(
+            &conn,
+            "INTERVAL '+1 02:03:04.123456789' DAY TO SECOND(9)",
+            &OracleType::IntervalDS(2, 9),
+            &d
+        );
+        test_from_sql!(
+            &conn,
+            "INTERVAL '-1 02:03:04.123456789' DAY TO SECOND(9)",
+            &OracleType::IntervalDS(2, 9),
+            &-d
+        );
+
+        // Duration -> INTERVAL DAY TO SECOND
+        test_to_sql!(&conn, &d, "TO_CHAR(:1)", "+000000001 02:03:04.123456789");
+        test_to_sql!(&conn, &-d, "TO_CHAR(:1)", "-000000001 02:03:04.123456789");
+
+        let fetched = conn.query_row_as::<Duration>(
+            "select cast(:1 as interval day(9) to second(9)) from dual",
+            &[&d],
+        )?;
+        assert_eq!(fetched, d);
+
+        // Overflow
+        let d = Duration::days(1000000000);
+        let mut stmt = conn.statement("begin :out := TO_CHAR(:1); end;").build()?;
+        assert!(matches!(stmt.bind(2, &d), Err(Error::OutOfRange(_))));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal {
+    use super::common;
+    use super::test_to_sql;
+    use bigdecimal::BigDecimal;
+    use oracle::{Error, Result};
+    use std::str::FromStr;
+
+    #[test]
+    fn bigdecimal_round_trip() -> Result<()> {
+        let conn = common::connect()?;
+        // 30 significant digits, more than f64 can hold exactly.
+        let d = BigDecimal::from_str("123456789012345.678901234567890").unwrap();
+        let result: BigDecimal = conn.query_row_as("select :1 from dual", &[&d])?;
+        assert_eq!(result, d);
+
+        let d = BigDecimal::from_str("-0.000123456789012345678901234567890").unwrap();
+        let result: BigDecimal = conn.query_row_as("select :1 from dual", &[&d])?;
+        assert_eq!(result, d);
+        Ok(())
+    }
+
+    #[test]
+    fn bigdecimal_to_sql() -> Result<()> {
+        let conn = common::connect()?;
+        let d = BigDecimal::from_str("123456789012345.678901234567890").unwrap();
+        test_to_sql!(&conn, &d, "TO_CHAR(:1)", "123456789012345.67890123456789");
+
+        // Trailing zeros in the integer part are not significant digits.
+        let d = BigDecimal::from_str("1e100").unwrap();
+        test_to_sql!(&conn, &d, "TO_CHAR(:1)", "1.0000000000E+100");
+
+        // 40 significant digits
+        let d = BigDecimal::from_str("1234567890123456789012345678901234567.891").unwrap();
+        let mut stmt = conn.statement("begin :out := TO_CHAR(:1); end;").build()?;
+        let bind_result = stmt.bind(2, &d);
+        if let Err(Error::OutOfRange(_)) = bind_result { /* OK */
+        } else {
+            panic!("BigDecimal with 40 digits should not be converted to number!");
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,39 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+mod common;
+
+use oracle::{Error, InitParams, Result};
+
+// This must be the only test in this file because `oracle::init` must be
+// called before the Oracle client library is initialized in the process.
+#[test]
+fn init() -> Result<()> {
+    oracle::init(InitParams::new().driver_name("rust-oracle-init-test"))?;
+
+    let conn = common::connect()?;
+    let driver_name = conn.query_row_as::<String>(
+        "select client_driver from v$session_connect_info where sid = sys_context('userenv', 'sid') and rownum = 1",
+        &[],
+    )?;
+    assert_eq!(driver_name, "rust-oracle-init-test");
+
+    // `init` fails after the library is initialized.
+    match oracle::init(&InitParams::new()) {
+        Err(Error::InvalidOperation(_)) => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+    Ok(())
+}
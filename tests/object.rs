@@ -14,7 +14,7 @@
 //-----------------------------------------------------------------------------
 
 mod common;
-use oracle::sql_type::{Collection, FromSql, Object, OracleType, Timestamp};
+use oracle::sql_type::{Collection, FromSql, IntoObject, Object, OracleType, Timestamp};
 use oracle::{Result, SqlValue};
 use std::iter::Iterator;
 
@@ -223,6 +223,82 @@ fn udt_object() -> Result<()> {
     Ok(())
 }
 
+#[derive(IntoObject)]
+#[into_object(type_name = "UDT_SUBOBJECT")]
+struct SubObjectInput {
+    #[into_object(rename = "SUBNUMBERVALUE")]
+    number: Option<i32>,
+    #[into_object(rename = "SUBSTRINGVALUE")]
+    string: String,
+}
+
+#[derive(IntoObject)]
+#[into_object(type_name = "UDT_OBJECT")]
+struct ObjectInput {
+    #[into_object(rename = "NUMBERVALUE")]
+    number_value: i32,
+    #[into_object(rename = "STRINGVALUE")]
+    string_value: &'static str,
+    #[into_object(rename = "SUBOBJECTVALUE")]
+    sub_object: SubObjectInput,
+}
+
+#[test]
+fn into_object() -> Result<()> {
+    let conn = common::connect()?;
+
+    let subobj = SubObjectInput {
+        number: Some(10),
+        string: "SUBSTRVAL:10".into(),
+    };
+    let mut stmt = conn
+        .statement(
+            "declare o udt_SubObject := :1; begin :2 := o.SubNumberValue || ',' || o.SubStringValue; end;",
+        )
+        .build()?;
+    stmt.execute(&[&subobj, &OracleType::Varchar2(100)])?;
+    assert_eq!(stmt.bind_value::<_, String>(2)?, "10,SUBSTRVAL:10");
+
+    let subobj = SubObjectInput {
+        number: None,
+        string: "SUBSTRVAL:null".into(),
+    };
+    stmt.execute(&[&subobj, &OracleType::Varchar2(100)])?;
+    assert_eq!(stmt.bind_value::<_, String>(2)?, ",SUBSTRVAL:null");
+
+    // nested object
+    let obj = ObjectInput {
+        number_value: 1,
+        string_value: "STRVAL:1",
+        sub_object: SubObjectInput {
+            number: Some(11),
+            string: "SUBSTRVAL:11".into(),
+        },
+    };
+    let mut stmt = conn
+        .statement(
+            "declare o udt_Object := :1; begin :2 := o.NumberValue || ',' || o.StringValue || ',' || o.SubObjectValue.SubNumberValue || ',' || o.SubObjectValue.SubStringValue; end;",
+        )
+        .build()?;
+    stmt.execute(&[&obj, &OracleType::Varchar2(100)])?;
+    assert_eq!(
+        stmt.bind_value::<_, String>(2)?,
+        "1,STRVAL:1,11,SUBSTRVAL:11"
+    );
+
+    // converted to an Object explicitly
+    let objtype = conn.object_type("UDT_OBJECT")?;
+    let obj = obj.to_object(&objtype)?;
+    assert_eq!(obj.get::<i32>("NUMBERVALUE")?, 1);
+    assert_eq!(obj.get::<String>("STRINGVALUE")?, "STRVAL:1");
+    assert_eq!(
+        obj.get::<Object>("SUBOBJECTVALUE")?
+            .get::<String>("SUBSTRINGVALUE")?,
+        "SUBSTRVAL:11"
+    );
+    Ok(())
+}
+
 #[test]
 fn udt_stringlist() -> Result<()> {
     let conn = common::connect()?;
@@ -494,3 +570,27 @@ fn select_objects() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn vec_number_table() -> Result<()> {
+    let conn = common::connect()?;
+    let objtype = conn.object_type("UDT_NUMBERTABLE")?;
+    let vals = vec![1i32, 2, 3, 5, 8];
+
+    conn.execute(
+        "insert into TestNumberTables values (1, :1)",
+        &[&(&vals, &OracleType::Object(objtype.clone()))],
+    )?;
+    let fetched = conn.query_row_as::<Vec<i32>>(
+        "select NumberTableCol from TestNumberTables where IntCol = 1",
+        &[],
+    )?;
+    assert_eq!(fetched, vals);
+
+    // The collection type must be specified.
+    assert!(conn
+        .execute("insert into TestNumberTables values (2, :1)", &[&vals])
+        .is_err());
+    conn.rollback()?;
+    Ok(())
+}
@@ -319,6 +319,28 @@ fn object_type_cache() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn object_type_cache_lookup_count() -> Result<()> {
+    let conn = common::connect()?;
+    conn.clear_type_cache()?;
+    let count = conn.object_type_lookup_count();
+
+    let objtype1 = conn.object_type("UDT_SUBOBJECT")?;
+    assert_eq!(conn.object_type_lookup_count(), count + 1);
+
+    // The second lookup uses the cache.
+    let objtype2 = conn.object_type("UDT_SUBOBJECT")?;
+    assert_eq!(conn.object_type_lookup_count(), count + 1);
+    assert_eq!(objtype1, objtype2);
+
+    // The cache is cleared.
+    conn.clear_type_cache()?;
+    assert_eq!(conn.object_type_cache_len(), 0);
+    conn.object_type("UDT_SUBOBJECT")?;
+    assert_eq!(conn.object_type_lookup_count(), count + 2);
+    Ok(())
+}
+
 #[test]
 fn udt_issue19() -> Result<()> {
     let conn = common::connect()?;
@@ -0,0 +1,217 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+mod common;
+
+use oracle::pool::{GetMode, PoolBuilder};
+use oracle::{Connection, Result};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn session_id(conn: &Connection) -> Result<String> {
+    conn.query_row_as("select sys_context('USERENV', 'SID') from dual", &[])
+}
+
+#[test]
+fn validation_query() -> Result<()> {
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(2)
+    .validate_on_get(true)
+    .build()?;
+    let conn = pool.get()?;
+    let sid = session_id(&conn)?;
+    conn.close()?;
+    // The validated connection is reused.
+    let conn = pool.get()?;
+    assert_eq!(session_id(&conn)?, sid);
+    Ok(())
+}
+
+#[test]
+fn failing_validation_query_causes_reconnect() -> Result<()> {
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(2)
+    // This fails when the current schema doesn't contain TestNumbers.
+    .validation_query("select IntCol from TestNumbers where rownum = 1")
+    .build()?;
+    let conn = pool.get()?;
+    let sid = session_id(&conn)?;
+    // Break the connection for the validation query and return it to the pool.
+    conn.execute("alter session set current_schema = SYS", &[])?;
+    conn.close()?;
+
+    let conn = pool.get()?;
+    assert_ne!(session_id(&conn)?, sid);
+    assert_eq!(
+        conn.query_row_as::<String>(
+            "select sys_context('USERENV', 'CURRENT_SCHEMA') from dual",
+            &[]
+        )?,
+        common::main_user().to_uppercase()
+    );
+    Ok(())
+}
+
+#[test]
+fn acquire_timeout() -> Result<()> {
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(1)
+    .acquire_timeout(Duration::from_millis(500))?
+    .build()?;
+    let conn = pool.get()?;
+
+    // The pool is saturated.
+    let now = Instant::now();
+    let err = pool.get().unwrap_err();
+    let elapsed = now.elapsed();
+    assert!(err.is_pool_timeout(), "{}", err);
+    assert!(
+        (Duration::from_millis(400)..Duration::from_secs(10)).contains(&elapsed),
+        "{:?}",
+        elapsed
+    );
+    assert!(pool.get_nowait()?.is_none());
+
+    // A connection is available after it is returned to the pool.
+    conn.close()?;
+    assert!(pool.get_nowait()?.is_some());
+    Ok(())
+}
+
+#[test]
+fn get_nowait() -> Result<()> {
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(1)
+    .get_mode(GetMode::Wait)
+    .build()?;
+    let conn = pool.get_nowait()?.unwrap();
+
+    // This returns without waiting though the pool's get mode is Wait.
+    assert!(pool.get_nowait()?.is_none());
+    assert_eq!(pool.get_mode()?, GetMode::Wait);
+
+    conn.close()?;
+    assert!(pool.get_nowait()?.is_some());
+    Ok(())
+}
+
+#[test]
+fn max_lifetime() -> Result<()> {
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(1)
+    .max_lifetime(Duration::from_millis(500))
+    .build()?;
+
+    let conn = pool.get()?;
+    assert!(conn.is_new_connection());
+    conn.close()?;
+
+    // The session is reused within its lifetime.
+    let conn = pool.get()?;
+    assert!(!conn.is_new_connection());
+    conn.close()?;
+
+    // A new session is issued after expiry.
+    std::thread::sleep(Duration::from_millis(700));
+    let conn = pool.get()?;
+    assert!(conn.is_new_connection());
+    conn.close()?;
+
+    let conn = pool.get()?;
+    assert!(!conn.is_new_connection());
+    Ok(())
+}
+
+#[test]
+fn connection_from_pool() -> Result<()> {
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(2)
+    .build()?;
+    assert_eq!(pool.busy_count()?, 0);
+
+    let conn = Connection::from_pool(&pool)?;
+    assert!(conn.pool().is_some());
+    assert_eq!(pool.busy_count()?, 1);
+    let sid = session_id(&conn)?;
+
+    let mut stmt = conn.statement("select 1 from dual").build()?;
+    assert_eq!(stmt.query_row_as::<i32>(&[])?, 1);
+    drop(stmt);
+    assert_eq!(pool.busy_count()?, 1);
+    // The session goes back to the pool on drop.
+    drop(conn);
+    assert_eq!(pool.busy_count()?, 0);
+
+    // The session is reused.
+    let conn = Connection::from_pool(&pool)?;
+    assert!(!conn.is_new_connection());
+    assert_eq!(session_id(&conn)?, sid);
+
+    // A standalone connection doesn't belong to any pool.
+    let conn = common::connect()?;
+    assert!(conn.pool().is_none());
+    Ok(())
+}
+
+#[test]
+fn session_init() -> Result<()> {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let counter_in_callback = counter.clone();
+    let pool = PoolBuilder::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    )
+    .max_connections(1)
+    .session_init(move |conn| {
+        counter_in_callback.fetch_add(1, Ordering::Relaxed);
+        conn.execute("alter session set nls_date_format = 'YYYY-MM-DD'", &[])?;
+        Ok(())
+    })
+    .build()?;
+    let sql = "select to_char(date '2021-02-03') from dual";
+    for _ in 0..3 {
+        let conn = pool.get()?;
+        assert_eq!(conn.query_row_as::<String>(sql, &[])?, "2021-02-03");
+    }
+    // called only for the first session
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    Ok(())
+}
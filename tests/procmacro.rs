@@ -1,3 +1,4 @@
+use oracle::sql_type::IntoObject;
 use oracle::RowValue;
 
 #[test]
@@ -13,4 +14,8 @@ fn procmacro_ok_shadow() {
 
     #[derive(Debug, RowValue)]
     struct Foo {}
+
+    #[allow(dead_code)]
+    #[derive(Debug, IntoObject)]
+    struct Bar {}
 }
@@ -0,0 +1,106 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2023 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+mod common;
+
+use oracle::Result;
+
+#[test]
+fn soda_insert_and_find() -> Result<()> {
+    let mut conn = common::connect()?;
+    if !common::check_oracle_version("soda_insert_and_find", &conn, 18, 0)? {
+        return Ok(());
+    }
+    conn.set_autocommit(true);
+    let soda = conn.soda_database()?;
+    if let Some(coll) = soda.open_collection("TestSodaRust")? {
+        coll.drop_collection()?;
+    }
+    assert!(conn.soda_collection("TestSodaRust")?.is_none());
+
+    let coll = soda.create_collection("TestSodaRust")?;
+    assert_eq!(coll.name(), "TestSodaRust");
+    assert!(soda
+        .collection_names()?
+        .iter()
+        .any(|name| name == "TestSodaRust"));
+
+    let doc1 = coll.insert_one(r#"{"name":"John","age":30}"#)?;
+    let doc2 = coll.insert_one(r#"{"name":"Jane","age":25}"#)?;
+    assert!(!doc1.key().is_empty());
+    assert_ne!(doc1.key(), doc2.key());
+    assert_eq!(coll.find().count()?, 2);
+
+    // find by key
+    let doc = coll.find().key(doc1.key()).get_one()?.unwrap();
+    assert_eq!(doc.key(), doc1.key());
+    assert_eq!(doc.content_as_str()?, r#"{"name":"John","age":30}"#);
+    assert_eq!(doc.media_type(), "application/json");
+    let docs = coll.find().keys([doc1.key(), doc2.key()]).get_documents()?;
+    assert_eq!(docs.len(), 2);
+
+    // find by filter
+    let docs = coll
+        .find()
+        .filter(r#"{"age": {"$lt": 28}}"#)
+        .get_documents()?;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(docs[0].key(), doc2.key());
+
+    // replace and remove
+    assert!(coll
+        .find()
+        .key(doc2.key())
+        .replace_one(r#"{"name":"Jane","age":26}"#)?);
+    let doc = coll.find().key(doc2.key()).get_one()?.unwrap();
+    assert_eq!(doc.content_as_str()?, r#"{"name":"Jane","age":26}"#);
+    assert_eq!(coll.find().key(doc1.key()).remove()?, 1);
+    assert!(coll.find().key(doc1.key()).get_one()?.is_none());
+    assert_eq!(coll.find().count()?, 1);
+
+    assert!(coll.drop_collection()?);
+    assert!(conn.soda_collection("TestSodaRust")?.is_none());
+    Ok(())
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn soda_json_documents() -> Result<()> {
+    use serde_json::json;
+
+    let mut conn = common::connect()?;
+    if !common::check_oracle_version("soda_json_documents", &conn, 18, 0)? {
+        return Ok(());
+    }
+    conn.set_autocommit(true);
+    let soda = conn.soda_database()?;
+    let coll = soda.create_collection("TestSodaRustJson")?;
+    coll.truncate()?;
+
+    let value = json!({"name": "John", "tags": ["a", "b"], "age": 30});
+    let doc = coll.insert_one_json(&value)?;
+    let doc = coll.find().key(doc.key()).get_one()?.unwrap();
+    assert_eq!(doc.to_json()?, value);
+
+    let docs = coll
+        .find()
+        .filter(&json!({"name": "John"}).to_string())
+        .get_documents()?;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(docs[0].to_json()?, value);
+
+    coll.drop_collection()?;
+    Ok(())
+}
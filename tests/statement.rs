@@ -15,8 +15,11 @@
 
 mod common;
 
-use oracle::sql_type::{IntervalDS, Timestamp};
-use oracle::{Result, StatementType};
+use oracle::sql_type::{
+    Bfile, Clob, ColumnValue, IntervalDS, Lob, OracleType, RefCursor, Timestamp,
+};
+use oracle::{Error, FromRowInPlace, Result, Row, StatementType};
+use std::io::Read;
 use std::{thread, time};
 
 #[test]
@@ -168,6 +171,30 @@ fn bind_names() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn bind_value_by_name() -> Result<()> {
+    let conn = common::connect()?;
+
+    let mut stmt = conn
+        .statement("BEGIN :result := :val1 || ' ' || :val2; END;")
+        .build()?;
+    stmt.bind("result", &OracleType::Varchar2(20))?;
+    stmt.bind("val1", &"hello")?;
+    stmt.bind("val2", &"world")?;
+    stmt.execute(&[])?;
+    let result: String = stmt.bind_value_by_name("result")?;
+    assert_eq!(result, "hello world");
+    let result: String = stmt.bind_value_by_name(":RESULT")?;
+    assert_eq!(result, "hello world");
+    let val1: String = stmt.bind_value_by_name("Val1")?;
+    assert_eq!(val1, "hello");
+    assert!(matches!(
+        stmt.bind_value_by_name::<String>("unknown"),
+        Err(oracle::Error::InvalidBindName(_))
+    ));
+    Ok(())
+}
+
 #[test]
 fn query() -> Result<()> {
     let conn = common::connect()?;
@@ -242,6 +269,149 @@ fn query_row() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn row_is_null() -> Result<()> {
+    let conn = common::connect()?;
+    let row = conn.query_row("select NULL, 1 from dual", &[])?;
+    assert_eq!(row.is_null(0)?, true);
+    assert_eq!(row.is_null(1)?, false);
+    assert!(row.is_null(2).is_err());
+
+    let row = conn.query_row("select NULL null_col, 1 not_null_col from dual", &[])?;
+    assert_eq!(row.is_null_by_name("NULL_COL")?, true);
+    assert_eq!(row.is_null_by_name("not_null_col")?, false);
+    assert!(row.is_null_by_name("no_such_col").is_err());
+    Ok(())
+}
+
+#[test]
+fn row_get_bytes() -> Result<()> {
+    let conn = common::connect()?;
+    let rows = conn
+        .query(
+            "select hextoraw('DEADBEEF'), 'abc', cast(NULL as raw(10)) from dual \
+             union all select hextoraw('BACEBA11'), 'def', NULL from dual",
+            &[],
+        )?
+        .collect::<Result<Vec<_>>>()?;
+    // The slices borrow the buffers of the rows, which remain valid
+    // after subsequent rows are fetched.
+    let expected: [&[u8]; 2] = [&[0xde, 0xad, 0xbe, 0xef], &[0xba, 0xce, 0xba, 0x11]];
+    assert_eq!(rows.len(), expected.len());
+    for (row, expected) in rows.iter().zip(expected.iter()) {
+        let bytes = row.get_bytes(0)?;
+        assert_eq!(bytes, *expected);
+        let copied = row.get::<_, Vec<u8>>(0)?;
+        assert_eq!(bytes, copied.as_slice());
+        assert_ne!(bytes.as_ptr(), copied.as_ptr());
+        // not RAW
+        assert!(matches!(
+            row.get_bytes(1),
+            Err(Error::InvalidTypeConversion(_, _))
+        ));
+        // null
+        assert!(matches!(row.get_bytes(2), Err(Error::NullValue)));
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct ReusedRow {
+    int_col: i32,
+    string_col: String,
+}
+
+impl FromRowInPlace for ReusedRow {
+    fn set_from_row(&mut self, row: &Row) -> Result<()> {
+        self.int_col = row.get(0)?;
+        row.get_string_into(1, &mut self.string_col)
+    }
+}
+
+#[test]
+fn fetch_into() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("select level, 'row ' || level from dual connect by level <= 10000")
+        .fetch_array_size(1000)
+        .build()?;
+    let mut row = ReusedRow::default();
+
+    // The query must be executed in advance.
+    assert!(stmt.fetch_into(&mut row).is_err());
+
+    stmt.query(&[])?;
+    let mut count = 0;
+    let mut num_reallocs = 0;
+    let mut capacity = row.string_col.capacity();
+    while stmt.fetch_into(&mut row)? {
+        count += 1;
+        assert_eq!(row.int_col, count);
+        assert_eq!(row.string_col, format!("row {}", count));
+        if row.string_col.capacity() != capacity {
+            capacity = row.string_col.capacity();
+            num_reallocs += 1;
+        }
+    }
+    assert_eq!(count, 10000);
+    // The buffer is reallocated only when it grows. The length of the
+    // strings grows from 5 to 9.
+    assert!(num_reallocs <= 5, "num_reallocs: {}", num_reallocs);
+    assert!(!stmt.fetch_into(&mut row)?);
+    Ok(())
+}
+
+#[test]
+fn fetch_opt() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("select level from dual connect by level <= 5")
+        .fetch_array_size(2)
+        .build()?;
+
+    // The query must be executed in advance.
+    assert!(stmt.fetch_opt().is_err());
+
+    stmt.query(&[])?;
+    let mut values = vec![];
+    while let Some(row) = stmt.fetch_opt()? {
+        values.push(row.get::<_, i32>(0)?);
+    }
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+    assert!(stmt.fetch_opt()?.is_none());
+    Ok(())
+}
+
+#[test]
+fn fetch_chunk() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("select level from dual connect by level <= 25")
+        .fetch_array_size(10)
+        .build()?;
+
+    // The query must be executed in advance.
+    assert!(stmt.fetch_chunk(10).is_err());
+
+    stmt.query(&[])?;
+    let mut chunk_sizes = vec![];
+    let mut values = vec![];
+    loop {
+        let rows = stmt.fetch_chunk(10)?;
+        if rows.is_empty() {
+            break;
+        }
+        chunk_sizes.push(rows.len());
+        for row in &rows {
+            values.push(row.get::<_, i32>(0)?);
+        }
+    }
+    assert_eq!(chunk_sizes, [10, 10, 5]);
+    assert_eq!(values, (1..=25).collect::<Vec<_>>());
+    assert!(stmt.fetch_chunk(10)?.is_empty());
+    Ok(())
+}
+
 #[test]
 fn dml_returning() -> Result<()> {
     // magic spell to prevent "ORA-00060: deadlock detected while waiting for resource' in this test.
@@ -259,7 +429,7 @@ fn dml_returning() -> Result<()> {
     // update no rows
     stmt.execute(&[&11])?;
     let updated_int_col: Vec<i32> = stmt.returned_values(2)?;
-    assert_eq!(updated_int_col, vec![]);
+    assert_eq!(updated_int_col, Vec::<i32>::new());
 
     // update one row
     stmt.execute(&[&10])?;
@@ -275,7 +445,48 @@ fn dml_returning() -> Result<()> {
     // update no rows
     stmt.execute(&[&11])?;
     let updated_int_col: Vec<i32> = stmt.returned_values(2)?;
-    assert_eq!(updated_int_col, vec![]);
+    assert_eq!(updated_int_col, Vec::<i32>::new());
+    Ok(())
+}
+
+#[test]
+fn long_and_long_raw() -> Result<()> {
+    let conn = common::connect()?;
+    // larger than the max size of VARCHAR2 and RAW in PL/SQL
+    let char_data = "0123456789abcdef".repeat(10000);
+    let raw_data = char_data.as_bytes().to_vec();
+
+    conn.execute("delete from TestLongs where IntCol = 101", &[])?;
+    conn.execute(
+        "insert into TestLongs values (:1, :2)",
+        &[&101, &(&char_data, &OracleType::Long)],
+    )?;
+    let mut stmt = conn
+        .statement("select LongCol from TestLongs where IntCol = :1")
+        .build()?;
+    assert_eq!(
+        stmt.query(&[&101])?.column_info()[0].oracle_type(),
+        &OracleType::Long
+    );
+    let val = stmt.query_row_as::<String>(&[&101])?;
+    assert_eq!(val.len(), 160000);
+    assert_eq!(val, char_data);
+
+    conn.execute("delete from TestLongRaws where IntCol = 101", &[])?;
+    conn.execute(
+        "insert into TestLongRaws values (:1, :2)",
+        &[&101, &(&raw_data, &OracleType::LongRaw)],
+    )?;
+    let mut stmt = conn
+        .statement("select LongRawCol from TestLongRaws where IntCol = :1")
+        .build()?;
+    assert_eq!(
+        stmt.query(&[&101])?.column_info()[0].oracle_type(),
+        &OracleType::LongRaw
+    );
+    let val = stmt.query_row_as::<Vec<u8>>(&[&101])?;
+    assert_eq!(val, raw_data);
+    conn.rollback()?;
     Ok(())
 }
 
@@ -455,3 +666,469 @@ fn iterate_rows_by_ref_and_check_fused() -> Result<()> {
     assert!(rows.next().is_none());
     Ok(())
 }
+
+#[test]
+fn execute_with_different_params() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("insert into TestTempTable values (:1, :2)")
+        .build()?;
+    stmt.execute(&[&9001, &"first"])?;
+    // bind values of other types
+    stmt.execute(&[&9002i64, &Some(1234)])?;
+    // the second value is NULL after clear_binds
+    stmt.clear_binds()?;
+    stmt.execute(&[&9003])?;
+
+    let rows = conn
+        .query_as::<(i32, Option<String>)>(
+            "select IntCol, StringCol from TestTempTable where IntCol between 9001 and 9003 order by IntCol",
+            &[],
+        )?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        rows,
+        vec![
+            (9001, Some("first".to_string())),
+            (9002, Some("1234".to_string())),
+            (9003, None),
+        ]
+    );
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn debug_without_bind_values() -> Result<()> {
+    let conn = common::connect()?;
+    let password = "Sup3r_S3cret";
+    let mut stmt = conn
+        .statement("select :username username, :password password from dual")
+        .build()?;
+    let row = stmt.query_row_named(&[("username", &"scott"), ("password", &password)])?;
+
+    let stmt_debug = format!("{:?}", stmt);
+    assert!(stmt_debug.contains("select :username username, :password password from dual"));
+    assert!(stmt_debug.contains("PASSWORD"));
+    assert!(!stmt_debug.contains(password), "{}", stmt_debug);
+
+    let row_debug = format!("{:?}", row);
+    assert!(row_debug.contains("USERNAME"));
+    assert!(row_debug.contains("PASSWORD"));
+    assert!(!row_debug.contains(password), "{}", row_debug);
+    Ok(())
+}
+
+#[test]
+fn last_row_id() -> Result<()> {
+    let conn = common::connect()?;
+    let expected =
+        conn.query_row_as::<String>("select rowid from TestStrings where IntCol = 1", &[])?;
+
+    // single-row update
+    let stmt = conn.execute(
+        "update TestStrings set StringCol = StringCol where IntCol = :1",
+        &[&1],
+    )?;
+    assert_eq!(stmt.row_count()?, 1);
+    assert_eq!(stmt.last_row_id()?, Some(expected));
+
+    // multi-row update
+    let stmt = conn.execute(
+        "update TestStrings set StringCol = StringCol where IntCol >= :1",
+        &[&6],
+    )?;
+    assert_eq!(stmt.row_count()?, 5);
+    assert_eq!(stmt.last_row_id()?, None);
+
+    // no rows
+    let stmt = conn.execute(
+        "update TestStrings set StringCol = StringCol where IntCol < :1",
+        &[&0],
+    )?;
+    assert_eq!(stmt.last_row_id()?, None);
+
+    // query
+    let mut stmt = conn
+        .statement("select * from TestStrings where IntCol = 1")
+        .build()?;
+    stmt.query(&[])?.next();
+    assert_eq!(stmt.last_row_id()?, None);
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn column_index() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("select IntCol id, StringCol from TestStrings where IntCol = 1")
+        .build()?;
+    assert_eq!(stmt.column_index("ID"), None); // not executed yet
+    stmt.query_row(&[])?;
+    assert_eq!(stmt.column_index("ID"), Some(0));
+    assert_eq!(stmt.column_index("id"), Some(0));
+    assert_eq!(stmt.column_index("StringCol"), Some(1));
+    assert_eq!(stmt.column_index("NoSuchCol"), None);
+
+    // exact match is preferred.
+    let mut stmt = conn
+        .statement(r#"select 1 "id", 2 "ID" from dual"#)
+        .build()?;
+    let rows = stmt.query(&[])?;
+    assert_eq!(rows.column_index("id"), Some(0));
+    assert_eq!(rows.column_index("ID"), Some(1));
+    assert_eq!(rows.column_index("Id"), Some(0));
+    Ok(())
+}
+
+#[test]
+fn bind_metadata_cache() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select :val1, :val2 from dual where 1 = :val1";
+    let count = conn.bind_metadata_derive_count();
+
+    let stmt = conn.statement(sql).build()?;
+    assert_eq!(stmt.bind_count(), 3);
+    assert_eq!(stmt.bind_names(), &["VAL1", "VAL2"]);
+    assert_eq!(conn.bind_metadata_derive_count(), count + 1);
+    drop(stmt);
+
+    // The second statement uses the cached metadata.
+    let mut stmt = conn.statement(sql).build()?;
+    assert_eq!(stmt.bind_names(), &["VAL1", "VAL2"]);
+    assert_eq!(stmt.statement_type(), StatementType::Select);
+    assert_eq!(stmt.bind_count(), 3);
+    assert_eq!(conn.bind_metadata_derive_count(), count + 1);
+    assert_eq!(
+        stmt.query_row_as_named::<(i32, i32)>(&[("val1", &1), ("val2", &2)])?,
+        (1, 2)
+    );
+    drop(stmt);
+
+    // bypass the cache
+    conn.statement(sql).exclude_from_cache().build()?;
+    assert_eq!(conn.bind_metadata_derive_count(), count + 2);
+
+    // clear the cache
+    conn.clear_bind_metadata_cache()?;
+    conn.statement(sql).build()?;
+    assert_eq!(conn.bind_metadata_derive_count(), count + 3);
+    Ok(())
+}
+
+#[test]
+fn result_set_outliving_connection() -> Result<()> {
+    let rows = {
+        let conn = common::connect()?;
+        let stmt = conn
+            .statement("select IntCol from TestNumbers order by IntCol")
+            .fetch_array_size(2)
+            .build()?;
+        stmt.into_result_set::<i32>(&[])?
+        // `conn` is dropped here.
+    };
+    // The result set keeps the session alive and fetches the rest of rows.
+    let int_cols = rows.collect::<Result<Vec<_>>>()?;
+    assert_eq!(int_cols, (1..=10).collect::<Vec<_>>());
+    // The statement handle is released before the connection handle here.
+    Ok(())
+}
+
+#[test]
+fn set_lob_prefetch_size() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("insert into TestCLOBs values (:1, :2)")
+        .build()?;
+    for i in 1..=100 {
+        stmt.execute(&[&i, &format!("CLOB data {}", i)])?;
+    }
+
+    let read_all = |lob_prefetch_size: Option<u32>| -> Result<u64> {
+        let mut stmt = conn
+            .statement("select IntCol, CLOBCol from TestCLOBs order by IntCol")
+            .lob_locator()
+            .build()?;
+        if let Some(size) = lob_prefetch_size {
+            stmt.set_lob_prefetch_size(size);
+        }
+        let before = conn.round_trip_count()?;
+        for row_result in stmt.query_as::<(i32, Clob)>(&[])? {
+            let (i, mut clob) = row_result?;
+            let mut data = String::new();
+            clob.read_to_string(&mut data).unwrap();
+            assert_eq!(data, format!("CLOB data {}", i));
+        }
+        let after = conn.round_trip_count()?;
+        Ok(after - before)
+    };
+
+    let without_prefetch = read_all(None)?;
+    let with_prefetch = read_all(Some(1024))?;
+    assert!(
+        with_prefetch < without_prefetch,
+        "with prefetch: {}, without prefetch: {}",
+        with_prefetch,
+        without_prefetch
+    );
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn row_into_values() -> Result<()> {
+    let conn = common::connect()?;
+    conn.execute("insert into TestCLOBs values (:1, :2)", &[&1, &"CLOB DATA"])?;
+    let rows = {
+        let mut stmt = conn
+            .statement("select IntCol, CLOBCol, null from TestCLOBs where IntCol = 1")
+            .lob_locator()
+            .build()?;
+        let rows = stmt
+            .query(&[])?
+            .map(|row| row?.into_values())
+            .collect::<Result<Vec<_>>>()?;
+        rows
+        // `stmt` is dropped here.
+    };
+    conn.rollback()?;
+    assert_eq!(
+        rows,
+        vec![vec![
+            ColumnValue::Number("1".into()),
+            ColumnValue::String("CLOB DATA".into()),
+            ColumnValue::Null,
+        ]]
+    );
+    Ok(())
+}
+
+#[test]
+fn nested_cursors() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "
+begin
+  open :1 for
+    select IntCol,
+           cursor(select StringCol,
+                         cursor(select IntCol from TestNumbers n where n.IntCol <= s.IntCol order by n.IntCol)
+                    from TestStrings s where s.IntCol <= t.IntCol order by s.IntCol)
+      from TestNumbers t where IntCol <= 3 order by IntCol;
+end;";
+    let mut stmt = conn.statement(sql).build()?;
+    stmt.bind_ref_cursor_out(1)?;
+    stmt.execute(&[])?;
+    let mut cursor: RefCursor = stmt.bind_value(1)?;
+    let mut rows = 0;
+    let mut sub_cursors = Vec::new();
+    for row_result in cursor.query_as::<(i32, RefCursor)>()? {
+        let (int_col, mut sub_cursor) = row_result?;
+        let mut sub_rows = 0;
+        for sub_row_result in sub_cursor.query_as::<(String, RefCursor)>()? {
+            let (string_col, mut sub_sub_cursor) = sub_row_result?;
+            sub_rows += 1;
+            assert_eq!(string_col, format!("String {}", sub_rows));
+            let ints = sub_sub_cursor
+                .query_as::<i32>()?
+                .collect::<Result<Vec<_>>>()?;
+            assert_eq!(ints, (1..=sub_rows).collect::<Vec<_>>());
+        }
+        rows += 1;
+        assert_eq!(int_col, rows);
+        assert_eq!(sub_rows, int_col);
+        sub_cursors.push(sub_cursor);
+    }
+    assert_eq!(rows, 3);
+
+    // Cursor columns are closed along with their parent.
+    drop(cursor);
+    drop(stmt);
+    for mut sub_cursor in sub_cursors {
+        assert!(sub_cursor.query().is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn returned_row() -> Result<()> {
+    let conn = common::connect()?;
+    conn.execute("delete from TestDates where IntCol in (9301, 9302)", &[])?;
+    let ts = Timestamp::new(2020, 1, 2, 3, 4, 5, 0);
+
+    let stmt = conn.execute(
+        "insert into TestDates values (9301, :1, null) returning IntCol, DateCol into :id, :dt",
+        &[&ts, &None::<i32>, &OracleType::Date],
+    )?;
+    assert_eq!(stmt.returned_row::<(i32, Timestamp)>()?, (9301, ts));
+
+    conn.execute("insert into TestDates values (9302, :1, null)", &[&ts])?;
+    let stmt = conn.execute(
+        "update TestDates set DateCol = DateCol + 1 where IntCol in (9301, 9302) \
+         returning IntCol, DateCol into :id, :dt",
+        &[&None::<i32>, &OracleType::Date],
+    )?;
+    let mut rows = stmt.returned_rows::<(i32, Timestamp)>()?;
+    rows.sort_by_key(|row| row.0);
+    let next_day = Timestamp::new(2020, 1, 3, 3, 4, 5, 0);
+    assert_eq!(rows, vec![(9301, next_day), (9302, next_day)]);
+    // more than one row
+    assert!(matches!(
+        stmt.returned_row::<(i32, Timestamp)>(),
+        Err(oracle::Error::InvalidOperation(_))
+    ));
+
+    // no rows
+    let stmt = conn.execute(
+        "delete from TestDates where IntCol = 9303 returning IntCol, DateCol into :id, :dt",
+        &[&None::<i32>, &OracleType::Date],
+    )?;
+    assert!(matches!(
+        stmt.returned_row::<(i32, Timestamp)>(),
+        Err(oracle::Error::NoDataFound)
+    ));
+    assert!(stmt.returned_rows::<(i32, Timestamp)>()?.is_empty());
+    conn.rollback()?;
+    Ok(())
+}
+
+#[test]
+fn lob_column_types() -> Result<()> {
+    let conn = common::connect()?;
+    for (sql, oratype) in &[
+        ("select * from TestCLOBs where 1 = 0", OracleType::CLOB),
+        ("select * from TestNCLOBs where 1 = 0", OracleType::NCLOB),
+        ("select * from TestBLOBs where 1 = 0", OracleType::BLOB),
+        (
+            "select 1, bfilename('NO_SUCH_DIR', 'file.txt') from dual where 1 = 0",
+            OracleType::BFILE,
+        ),
+    ] {
+        let rows = conn.query(sql, &[])?;
+        assert_eq!(rows.column_info()[1].oracle_type(), oratype, "{}", sql);
+    }
+    Ok(())
+}
+
+#[test]
+fn fetch_bfile() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select bfilename(:1, :2) from dual";
+    let mut stmt = conn.statement(sql).lob_locator().build()?;
+    let bfile = stmt.query_row_as::<Bfile>(&[&"NO_SUCH_DIR", &"file.txt"])?;
+    assert_eq!(
+        bfile.bfile_name()?,
+        ("NO_SUCH_DIR".to_string(), "file.txt".to_string())
+    );
+    assert_eq!(bfile.bfile_name()?, bfile.directory_and_file_name()?);
+
+    // The rest runs only when ODPIC_TEST_DIR_NAME and ODPIC_TEST_DIR_PATH are
+    // set to the name of a directory object readable by the test user and its
+    // path, which must be writable from the machine running tests.
+    let (dir_name, dir_path) = match (
+        std::env::var("ODPIC_TEST_DIR_NAME"),
+        std::env::var("ODPIC_TEST_DIR_PATH"),
+    ) {
+        (Ok(dir_name), Ok(dir_path)) => (dir_name, dir_path),
+        _ => return Ok(()),
+    };
+    let file_name = "rust_oracle_fetch_bfile.bin";
+    let data = (0..=255u8).cycle().take(10000).collect::<Vec<_>>();
+    let path = std::path::Path::new(&dir_path).join(file_name);
+    std::fs::write(&path, &data).unwrap();
+    let res = (|| {
+        let mut bfile = stmt.query_row_as::<Bfile>(&[&dir_name, &file_name])?;
+        assert!(bfile.file_exists()?);
+        assert_eq!(bfile.size()?, data.len() as u64);
+        let mut buf = Vec::new();
+        bfile.open_resource()?;
+        let read_res = bfile.read_to_end(&mut buf);
+        bfile.close_resource()?;
+        read_res?;
+        Ok::<_, Box<dyn std::error::Error>>(buf)
+    })();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(res.unwrap(), data);
+    Ok(())
+}
+
+#[test]
+fn duplicate_column_names() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select a.IntCol, b.IntCol, a.StringCol from TestStrings a, TestStrings b where a.IntCol = 1 and b.IntCol = 2";
+    let mut rows = conn.query(sql, &[])?;
+    let names = rows
+        .column_info()
+        .iter()
+        .map(|info| info.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["INTCOL", "INTCOL", "STRINGCOL"]);
+    let row = rows.next().unwrap()?;
+    assert_eq!(row.get::<_, i32>("IntCol")?, 1);
+    assert_eq!(row.get_all_by_name::<i32>("IntCol")?, vec![1, 2]);
+    assert_eq!(
+        row.get_all_by_name::<String>("STRINGCOL")?,
+        vec!["String 1".to_string()]
+    );
+    assert!(matches!(
+        row.get_all_by_name::<i32>("NoSuchCol"),
+        Err(Error::InvalidColumnName(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn cancel_on_drop() -> Result<()> {
+    let mut conn = common::connect()?;
+    let sql = "select /* cancel_on_drop */ level from dual connect by level <= 1000000";
+    let count_sql = "select count(*) from v$open_cursor \
+                     where sid = sys_context('userenv', 'sid') \
+                       and sql_text like 'select /* cancel_on_drop */%' \
+                       and cursor_type like 'OPEN%'";
+    let open_cursors = |conn: &oracle::Connection| conn.query_row_as::<u32>(count_sql, &[]);
+    match open_cursors(&conn) {
+        Ok(count) => assert_eq!(count, 0),
+        Err(Error::OciError(err)) if err.code() == 942 => {
+            // ORA-00942: table or view does not exist
+            println!(
+                "Skip cancel_on_drop, which requires access to v$open_cursor: {}",
+                err
+            );
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    }
+    assert!(!conn.cancel_on_drop());
+
+    // The cursor of a query in progress is kept open in the statement cache by default.
+    let mut stmt = conn.statement(sql).build()?;
+    assert_eq!(stmt.query_row_as::<i32>(&[])?, 1);
+    drop(stmt);
+    assert_eq!(open_cursors(&conn)?, 1);
+
+    // It is closed when the statement is dropped with cancel_on_drop.
+    let mut stmt = conn.statement(sql).cancel_on_drop(true).build()?;
+    assert_eq!(stmt.query_row_as::<i32>(&[])?, 1);
+    drop(stmt);
+    assert_eq!(open_cursors(&conn)?, 0);
+
+    // The connection-wide default is used by result sets owning their statements.
+    conn.set_cancel_on_drop(true);
+    assert!(conn.cancel_on_drop());
+    let mut rows = conn.query_as::<i32>(sql, &[])?;
+    assert_eq!(rows.next().transpose()?, Some(1));
+    drop(rows);
+    assert_eq!(open_cursors(&conn)?, 0);
+
+    // A statement whose rows are fetched completely is kept in the statement cache.
+    let mut stmt = conn
+        .statement("select /* cancel_on_drop */ 1 from dual")
+        .build()?;
+    assert_eq!(
+        stmt.query_as::<i32>(&[])?.collect::<Result<Vec<_>>>()?,
+        vec![1]
+    );
+    drop(stmt);
+    assert_eq!(open_cursors(&conn)?, 1);
+    Ok(())
+}